@@ -0,0 +1,44 @@
+// cargo bench -p aoc-bench -- [<filter>] [--save-baseline <name> | --baseline[-lenient] <name>]
+// cargo run -p aoc-bench -- [--baseline <name> | --write-baseline]
+//
+// "parse" times reading and parsing the input, "solve" times `solve_parsed`
+// on input parsed beforehand. The summary compares the latest run against
+// the estimates stored in aoc-bench/baseline.json, or against a criterion
+// baseline under target/criterion with `--baseline <name>`. Refresh the
+// stored estimates with `--write-baseline` after a full bench run.
+
+use std::time::Duration;
+
+use aoc::solvers::SOLVERS;
+use aoc_bench::{baseline, criterion_dir, render_table, summary};
+use criterion::{BatchSize, Criterion};
+
+fn bench_solvers(c: &mut Criterion) {
+    for solver in SOLVERS.iter() {
+        let path = solver.default_input();
+        let mut group = c.benchmark_group(solver.package);
+
+        let prepared = (solver.prepare)(&path).unwrap();
+
+        group.bench_function("parse", |b| b.iter(|| (solver.parse)(&path).unwrap()));
+        group.bench_function("solve", |b| b.iter_batched(|| prepared.job(), |job| job().unwrap(), BatchSize::SmallInput));
+        group.finish();
+    }
+}
+
+fn main() {
+    let mut c = Criterion::default()
+        .sample_size(10)
+        .warm_up_time(Duration::from_millis(500))
+        .measurement_time(Duration::from_secs(2))
+        .configure_from_args();
+
+    bench_solvers(&mut c);
+    c.final_summary();
+
+    if std::env::args().any(|arg| arg == "--bench") {
+        let baseline = baseline(std::env::args()).unwrap();
+        println!();
+        println!("{}", render_table(&summary(&criterion_dir(), SOLVERS, &baseline), baseline.name()));
+    }
+}
@@ -0,0 +1,251 @@
+use std::{collections::HashMap, fs, path::{Path, PathBuf}, time::Duration};
+
+use aoc::solvers::Solver;
+
+pub const PHASES: [&str; 2] = ["parse", "solve"];
+
+pub type Estimates = [Option<Duration>; 2];
+
+pub fn stored_path() -> PathBuf {
+    Path::new(env!("CARGO_MANIFEST_DIR")).join("baseline.json")
+}
+
+// Either the estimates checked in at `stored_path`, or a baseline saved by
+// criterion's `--save-baseline <name>`.
+#[derive(Debug)]
+pub enum Baseline {
+    Stored(HashMap<String, Estimates>),
+    Criterion(String),
+}
+
+impl Baseline {
+    pub fn name(&self) -> &str {
+        match self {
+            Baseline::Stored(_) => "stored",
+            Baseline::Criterion(name) => name,
+        }
+    }
+
+    fn estimates(&self, dir: &Path, package: &str) -> Estimates {
+        match self {
+            Baseline::Stored(packages) => packages.get(package).copied().unwrap_or_default(),
+            Baseline::Criterion(name) => PHASES.map(|phase| read_estimate(dir, package, phase, name)),
+        }
+    }
+}
+
+pub fn read_stored(path: &Path) -> Result<Baseline, Box<dyn std::error::Error>> {
+    let json = serde_json::from_str::<serde_json::Value>(&fs::read_to_string(path)?)?;
+    let Some(packages) = json.as_object() else {
+        return Err(format!("{}: expected an object of packages", path.display()).into());
+    };
+
+    let packages = packages.iter()
+        .map(|(package, phases)| {
+            let estimates = PHASES.map(|phase| phases[phase].as_f64().map(|ns| Duration::from_secs_f64(ns / 1e9)));
+            (package.clone(), estimates)
+        })
+        .collect();
+
+    Ok(Baseline::Stored(packages))
+}
+
+// Stores the latest estimates of `rows` in nanoseconds, one package per line.
+pub fn write_stored(path: &Path, rows: &[Row]) -> std::io::Result<()> {
+    let lines = rows.iter()
+        .map(|row| {
+            let phases = PHASES.iter().zip(row.new)
+                .filter_map(|(phase, d)| d.map(|d| format!("\"{}\": {}", phase, d.as_nanos())))
+                .collect::<Vec<_>>();
+            format!("  \"{}\": {{ {} }}", row.package, phases.join(", "))
+        })
+        .collect::<Vec<_>>();
+
+    fs::write(path, format!("{{\n{}\n}}\n", lines.join(",\n")))
+}
+
+pub fn criterion_dir() -> PathBuf {
+    if let Some(dir) = std::env::var_os("CRITERION_HOME") {
+        return PathBuf::from(dir);
+    }
+    if let Some(dir) = std::env::var_os("CARGO_TARGET_DIR") {
+        return PathBuf::from(dir).join("criterion");
+    }
+    Path::new(env!("CARGO_MANIFEST_DIR")).join("..").join("target").join("criterion")
+}
+
+pub fn baseline_arg<I>(args: I) -> Option<String>
+    where I: IntoIterator<Item = String>
+{
+    let mut args = args.into_iter();
+
+    while let Some(arg) = args.next() {
+        let arg = arg.replace("--baseline-lenient", "--baseline");
+        if let Some(name) = arg.strip_prefix("--baseline=") {
+            return Some(name.to_string());
+        }
+        if arg == "--baseline" {
+            return args.next();
+        }
+    }
+
+    None
+}
+
+// `--baseline <name>` picks a criterion baseline, otherwise the stored one.
+pub fn baseline<I>(args: I) -> Result<Baseline, Box<dyn std::error::Error>>
+    where I: IntoIterator<Item = String>
+{
+    match baseline_arg(args) {
+        Some(name) => Ok(Baseline::Criterion(name)),
+        None => read_stored(&stored_path()),
+    }
+}
+
+pub fn read_estimate(dir: &Path, group: &str, phase: &str, baseline: &str) -> Option<Duration> {
+    let path = dir.join(group).join(phase).join(baseline).join("estimates.json");
+    let json = serde_json::from_str::<serde_json::Value>(&fs::read_to_string(path).ok()?).ok()?;
+    let ns = json["mean"]["point_estimate"].as_f64()?;
+
+    Some(Duration::from_secs_f64(ns / 1e9))
+}
+
+#[derive(PartialEq, Debug)]
+pub struct Row {
+    pub package: String,
+    pub new: Estimates,
+    pub base: Estimates,
+}
+
+impl Row {
+    pub fn read(dir: &Path, package: &str, baseline: &Baseline) -> Self {
+        Self {
+            package: package.to_string(),
+            new: PHASES.map(|phase| read_estimate(dir, package, phase, "new")),
+            base: baseline.estimates(dir, package),
+        }
+    }
+}
+
+pub fn summary(dir: &Path, solvers: &[Solver], baseline: &Baseline) -> Vec<Row> {
+    solvers.iter()
+        .map(|solver| Row::read(dir, solver.package, baseline))
+        .filter(|row| row.new.iter().chain(&row.base).any(Option::is_some))
+        .collect()
+}
+
+fn format_duration(d: Option<Duration>) -> String {
+    d.map_or("-".to_string(), |d| format!("{:.2?}", d))
+}
+
+fn format_change(new: Option<Duration>, base: Option<Duration>) -> String {
+    match (new, base) {
+        (Some(new), Some(base)) if !base.is_zero() => {
+            format!("{:+.1}%", (new.as_secs_f64() / base.as_secs_f64() - 1.0) * 100.0)
+        }
+        _ => "-".to_string(),
+    }
+}
+
+pub fn render_table(rows: &[Row], baseline: &str) -> String {
+    let mut header = [&["package"], PHASES.as_slice()].concat().into_iter().map(String::from).collect::<Vec<_>>();
+    header.extend(PHASES.map(|phase| format!("{} vs {}", phase, baseline)));
+
+    let mut table = vec![header];
+    for row in rows {
+        let mut cells = vec![row.package.clone()];
+        cells.extend(row.new.map(format_duration));
+        cells.extend((0..PHASES.len()).map(|i| format_change(row.new[i], row.base[i])));
+        table.push(cells);
+    }
+
+    let widths = (0..table[0].len())
+        .map(|i| table.iter().map(|cells| cells[i].chars().count()).max().unwrap_or(0))
+        .collect::<Vec<_>>();
+
+    table.iter()
+        .map(|cells| {
+            cells.iter().zip(&widths).enumerate()
+                .map(|(i, (cell, width))| match i {
+                    0 => format!("{:<width$}", cell, width = width),
+                    _ => format!("{:>width$}", cell, width = width),
+                })
+                .collect::<Vec<_>>()
+                .join("  ")
+                .trim_end()
+                .to_string()
+        })
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::*;
+
+    #[test]
+    fn read_estimates() -> Result<(), Box<dyn std::error::Error>> {
+        let dir = std::env::temp_dir().join(format!("aoc-bench-test-{}", std::process::id()));
+        for (phase, baseline, ns) in [("parse", "new", 1500.0), ("solve", "new", 2.5e6), ("solve", "main", 5e6)] {
+            let path = dir.join("day01_1").join(phase).join(baseline);
+            fs::create_dir_all(&path)?;
+            fs::write(path.join("estimates.json"), format!("{{\"mean\":{{\"point_estimate\":{}}}}}", ns))?;
+        }
+
+        let row = Row::read(&dir, "day01_1", &Baseline::Criterion("main".to_string()));
+        let stored = dir.join("baseline.json");
+        write_stored(&stored, std::slice::from_ref(&row))?;
+        let Baseline::Stored(packages) = read_stored(&stored)? else {
+            panic!("read_stored should return a stored baseline");
+        };
+        fs::remove_dir_all(&dir)?;
+
+        assert_eq!([Some(Duration::from_nanos(1500)), Some(Duration::from_micros(2500))], row.new);
+        assert_eq!([None, Some(Duration::from_millis(5))], row.base);
+        assert_eq!(HashMap::from([("day01_1".to_string(), row.new)]), packages);
+        Ok(())
+    }
+
+    #[test]
+    fn stored_baseline_covers_solvers() -> Result<(), Box<dyn std::error::Error>> {
+        let Baseline::Stored(packages) = read_stored(&stored_path())? else {
+            panic!("read_stored should return a stored baseline");
+        };
+
+        for solver in aoc::solvers::SOLVERS {
+            assert!(packages.get(solver.package).is_some_and(|estimates| estimates.iter().all(Option::is_some)), "{}", solver.package);
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn render_example() {
+        let rows = [
+            Row {
+                package: "day01_1".to_string(),
+                new: [Some(Duration::from_micros(15)), Some(Duration::from_micros(250))],
+                base: [None, Some(Duration::from_micros(500))],
+            },
+            Row {
+                package: "day05_2b".to_string(),
+                new: [None, None],
+                base: [None, None],
+            },
+        ];
+
+        assert_eq!("\
+package     parse     solve  parse vs main  solve vs main
+day01_1   15.00µs  250.00µs              -         -50.0%
+day05_2b        -         -              -              -", render_table(&rows, "main"));
+    }
+
+    #[test]
+    fn parse_baseline_arg() {
+        let args = |s: &str| s.split_whitespace().map(String::from).collect::<Vec<_>>();
+
+        assert_eq!(Some("main".to_string()), baseline_arg(args("--bench --baseline main day01")));
+        assert_eq!(Some("main".to_string()), baseline_arg(args("--baseline=main")));
+        assert_eq!(Some("main".to_string()), baseline_arg(args("--baseline-lenient main")));
+        assert_eq!(None, baseline_arg(args("--bench --save-baseline main")));
+    }
+}
@@ -0,0 +1,17 @@
+use aoc::solvers::SOLVERS;
+use aoc_bench::{baseline, criterion_dir, render_table, stored_path, summary, write_stored, Baseline};
+
+fn main() -> Result<(), Box<dyn std::error::Error>> {
+    let args = std::env::args().skip(1).collect::<Vec<_>>();
+
+    if args.iter().any(|arg| arg == "--write-baseline") {
+        let rows = summary(&criterion_dir(), SOLVERS, &Baseline::Stored(Default::default()));
+        write_stored(&stored_path(), &rows)?;
+        println!("wrote {} packages to {}", rows.len(), stored_path().display());
+        return Ok(());
+    }
+
+    let baseline = baseline(args)?;
+    println!("{}", render_table(&summary(&criterion_dir(), SOLVERS, &baseline), baseline.name()));
+    Ok(())
+}
@@ -0,0 +1,419 @@
+use std::{ops::{Index, IndexMut}, path::Path};
+
+use aoc_input::{Input, Lines, ParseError, ParseErrorKind};
+
+pub type Point = (usize, usize);
+
+#[derive(PartialEq, Eq, PartialOrd, Ord, Hash, Clone, Copy, Debug)]
+pub enum Direction {
+    N, E, S, W,
+}
+
+impl Direction {
+    pub fn iter() -> [Direction; 4] {
+        [Direction::N, Direction::E, Direction::S, Direction::W]
+    }
+
+    pub fn offset(&self) -> (isize, isize) {
+        match self {
+            Direction::N => (0, -1),
+            Direction::E => (1, 0),
+            Direction::S => (0, 1),
+            Direction::W => (-1, 0),
+        }
+    }
+
+    pub fn turn_right(&self) -> Direction {
+        match self {
+            Direction::N => Direction::E,
+            Direction::E => Direction::S,
+            Direction::S => Direction::W,
+            Direction::W => Direction::N,
+        }
+    }
+
+    pub fn turn_left(&self) -> Direction {
+        match self {
+            Direction::N => Direction::W,
+            Direction::E => Direction::N,
+            Direction::S => Direction::E,
+            Direction::W => Direction::S,
+        }
+    }
+
+    pub fn rev(&self) -> Direction {
+        match self {
+            Direction::N => Direction::S,
+            Direction::E => Direction::W,
+            Direction::S => Direction::N,
+            Direction::W => Direction::E,
+        }
+    }
+
+    pub fn is_vertical(&self) -> bool {
+        matches!(self, Direction::N | Direction::S)
+    }
+}
+
+pub const NEIGHBORS_8: [(isize, isize); 8] = [
+    (-1, -1), (0, -1), (1, -1),
+    (-1, 0), (1, 0),
+    (-1, 1), (0, 1), (1, 1),
+];
+
+#[derive(PartialEq, Eq, Hash, Clone, Debug)]
+pub struct Grid<T> {
+    pub width: usize,
+    pub height: usize,
+    pub cells: Vec<T>,
+}
+
+impl<T> Grid<T> {
+    pub fn new(width: usize, height: usize, cells: Vec<T>) -> Result<Self, GridError> {
+        if cells.len() != width * height {
+            return Err(GridError::Size { expected: width * height, actual: cells.len() });
+        }
+
+        Ok(Self { width, height, cells })
+    }
+
+    pub fn filled(width: usize, height: usize, value: T) -> Self
+        where T: Clone
+    {
+        Self { width, height, cells: vec![value; width * height] }
+    }
+
+    pub fn len(&self) -> usize {
+        self.cells.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.cells.is_empty()
+    }
+
+    pub fn to_index(&self, (x, y): Point) -> usize {
+        x + y * self.width
+    }
+
+    pub fn from_index(&self, index: usize) -> Point {
+        (index % self.width, index / self.width)
+    }
+
+    pub fn contains(&self, (x, y): Point) -> bool {
+        x < self.width && y < self.height
+    }
+
+    pub fn offset(&self, (x, y): Point, (dx, dy): (isize, isize)) -> Option<Point> {
+        let x = x.checked_add_signed(dx)?;
+        let y = y.checked_add_signed(dy)?;
+
+        self.contains((x, y)).then_some((x, y))
+    }
+
+    pub fn next(&self, p: Point, d: Direction) -> Option<Point> {
+        self.offset(p, d.offset())
+    }
+
+    pub fn neighbors4(&self, p: Point) -> impl Iterator<Item = (Direction, Point)> + '_ {
+        Direction::iter().into_iter()
+            .filter_map(move |d| self.next(p, d).map(|p| (d, p)))
+    }
+
+    pub fn neighbors8(&self, p: Point) -> impl Iterator<Item = Point> + '_ {
+        NEIGHBORS_8.iter()
+            .filter_map(move |diff| self.offset(p, *diff))
+    }
+
+    pub fn get(&self, p: Point) -> Option<&T> {
+        if !self.contains(p) { return None; }
+        self.cells.get(self.to_index(p))
+    }
+
+    pub fn get_mut(&mut self, p: Point) -> Option<&mut T> {
+        if !self.contains(p) { return None; }
+        let index = self.to_index(p);
+        self.cells.get_mut(index)
+    }
+
+    pub fn swap(&mut self, p1: Point, p2: Point) {
+        let (i1, i2) = (self.to_index(p1), self.to_index(p2));
+        self.cells.swap(i1, i2);
+    }
+
+    pub fn row(&self, y: usize) -> &[T] {
+        &self.cells[y * self.width..(y + 1) * self.width]
+    }
+
+    pub fn rows(&self) -> impl Iterator<Item = &[T]> + '_ {
+        self.cells.chunks(self.width.max(1))
+    }
+
+    pub fn column(&self, x: usize) -> impl Iterator<Item = &T> + '_ {
+        self.cells.iter().skip(x).step_by(self.width.max(1))
+    }
+
+    pub fn columns(&self) -> impl Iterator<Item = impl Iterator<Item = &T> + '_> + '_ {
+        (0..self.width).map(move |x| self.column(x))
+    }
+
+    pub fn points(&self) -> impl Iterator<Item = Point> + '_ {
+        (0..self.height).flat_map(move |y| (0..self.width).map(move |x| (x, y)))
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = (Point, &T)> + '_ {
+        self.cells.iter().enumerate()
+            .map(|(i, cell)| (self.from_index(i), cell))
+    }
+
+    pub fn position<F>(&self, f: F) -> Option<Point>
+        where F: FnMut(&T) -> bool
+    {
+        self.cells.iter().position(f).map(|i| self.from_index(i))
+    }
+
+    pub fn map<U, F>(&self, f: F) -> Grid<U>
+        where F: FnMut(&T) -> U
+    {
+        Grid {
+            width: self.width,
+            height: self.height,
+            cells: self.cells.iter().map(f).collect(),
+        }
+    }
+
+    pub fn render<F>(&self, mut f: F) -> String
+        where F: FnMut(Point, &T) -> char
+    {
+        let mut s = String::with_capacity((self.width + 1) * self.height);
+
+        for (i, cell) in self.cells.iter().enumerate() {
+            s.push(f(self.from_index(i), cell));
+            if (i + 1) % self.width == 0 {
+                s.push('\n');
+            }
+        }
+
+        s
+    }
+
+    pub fn from_lines<I, S, F>(lines: I, mut f: F) -> Result<Self, GridError>
+        where I: IntoIterator<Item = S>, S: AsRef<str>, F: FnMut(Point, char) -> Option<T>
+    {
+        let mut width = None;
+        let mut height = 0;
+        let mut cells = vec![];
+
+        for (y, line) in lines.into_iter().enumerate() {
+            let line = line.as_ref();
+            let mut len = 0;
+
+            for (x, ch) in line.chars().enumerate() {
+                let Some(cell) = f((x, y), ch) else {
+                    return Err(GridError::UnexpectedChar { point: (x, y), ch });
+                };
+                cells.push(cell);
+                len += 1;
+            }
+
+            match width {
+                None => width = Some(len),
+                Some(expected) if expected != len => {
+                    return Err(GridError::Ragged { row: y, expected, actual: len });
+                }
+                _ => {}
+            }
+            height += 1;
+        }
+
+        match width {
+            Some(width) if width > 0 => Ok(Self { width, height, cells }),
+            _ => Err(GridError::Empty),
+        }
+    }
+
+    pub fn parse_with<F>(s: &str, f: F) -> Result<Self, GridError>
+        where F: FnMut(Point, char) -> Option<T>
+    {
+        Self::from_lines(s.lines().take_while(|line| !line.is_empty()), f)
+    }
+
+    pub fn read_lines<F>(lines: &mut Lines<'_>, f: F) -> Result<Self, ParseError>
+        where F: FnMut(Point, char) -> Option<T>
+    {
+        let rows = lines.block().collect::<Vec<_>>();
+
+        Self::from_lines(rows.iter().map(|line| line.text), f).map_err(|err| match err {
+            GridError::UnexpectedChar { point: (x, y), ch } => {
+                rows[y].error_at(x, 1, ParseErrorKind::UnexpectedChar(ch))
+            }
+            GridError::Ragged { row, expected, actual } => {
+                let kind = ParseErrorKind::Invalid(format!("expected row width {}, got {}", expected, actual));
+                rows[row].error_at(expected.min(actual), expected.abs_diff(actual), kind)
+            }
+            GridError::Empty | GridError::Size { .. } => {
+                lines.eof(ParseErrorKind::Expected("grid".to_string()))
+            }
+        })
+    }
+
+    pub fn read_file_with<P, F>(path: P, f: F) -> Result<Self, ParseError>
+        where P: AsRef<Path>, F: FnMut(Point, char) -> Option<T>
+    {
+        let input = Input::read_file(path)?;
+        Self::read_lines(&mut input.lines(), f)
+    }
+}
+
+impl Grid<char> {
+    pub fn parse(s: &str) -> Result<Self, GridError> {
+        Self::parse_with(s, |_, ch| Some(ch))
+    }
+
+    pub fn read_file<P>(path: P) -> Result<Self, ParseError>
+        where P: AsRef<Path>
+    {
+        Self::read_file_with(path, |_, ch| Some(ch))
+    }
+}
+
+impl<T> Index<Point> for Grid<T> {
+    type Output = T;
+
+    fn index(&self, p: Point) -> &Self::Output {
+        &self.cells[self.to_index(p)]
+    }
+}
+
+impl<T> IndexMut<Point> for Grid<T> {
+    fn index_mut(&mut self, p: Point) -> &mut Self::Output {
+        let index = self.to_index(p);
+        &mut self.cells[index]
+    }
+}
+
+impl std::fmt::Display for Grid<char> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.render(|_, ch| *ch))
+    }
+}
+
+impl std::fmt::Display for Grid<u8> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.render(|_, ch| *ch as char))
+    }
+}
+
+#[derive(PartialEq, Debug)]
+pub enum GridError {
+    Empty,
+    Size { expected: usize, actual: usize },
+    Ragged { row: usize, expected: usize, actual: usize },
+    UnexpectedChar { point: Point, ch: char },
+}
+impl std::fmt::Display for GridError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            GridError::Empty => write!(f, "Grid is empty"),
+            GridError::Size { expected, actual } => write!(f, "Grid size mismatch: expected {} cells, got {}", expected, actual),
+            GridError::Ragged { row, expected, actual } => write!(f, "Ragged row {}: expected width {}, got {}", row, expected, actual),
+            GridError::UnexpectedChar { point: (x, y), ch } => write!(f, "Unexpected char {:?} at ({}, {})", ch, x, y),
+        }
+    }
+}
+impl std::error::Error for GridError {}
+
+#[cfg(test)]
+mod tests {
+    use crate::*;
+
+    const EXAMPLE: &str = "\
+#.#.
+..#.
+#...
+";
+
+    #[test]
+    fn parse_example() -> Result<(), Box<dyn std::error::Error>> {
+        let grid = Grid::parse(EXAMPLE)?;
+
+        assert_eq!((4, 3), (grid.width, grid.height));
+        assert_eq!('#', grid[(2, 1)]);
+        assert_eq!(Some(&'.'), grid.get((3, 2)));
+        assert_eq!(None, grid.get((4, 0)));
+        assert_eq!(EXAMPLE, grid.to_string());
+        Ok(())
+    }
+
+    #[test]
+    fn parse_error() {
+        assert_eq!(Err(GridError::Empty), Grid::parse(""));
+        assert_eq!(Err(GridError::Ragged { row: 1, expected: 2, actual: 3 }), Grid::parse("..\n...\n"));
+        assert_eq!(
+            Err(GridError::UnexpectedChar { point: (1, 0), ch: 'x' }),
+            Grid::parse_with(".x", |_, ch| (ch == '.').then_some(0))
+        );
+    }
+
+    #[test]
+    fn read_lines_stops_at_blank_line() -> Result<(), Box<dyn std::error::Error>> {
+        let input = Input::new("example.txt", "ab\ncd\n\n<>^v\n");
+        let mut lines = input.lines();
+        let grid = Grid::read_lines(&mut lines, |_, ch| Some(ch))?;
+
+        assert_eq!(vec!['a', 'b', 'c', 'd'], grid.cells);
+        assert_eq!(Some("<>^v"), lines.next().map(|line| line.text));
+        Ok(())
+    }
+
+    #[test]
+    fn read_lines_error_location() {
+        let input = Input::new("example.txt", "..\n.x\n");
+        let err = Grid::read_lines(&mut input.lines(), |_, ch| (ch == '.').then_some(0)).unwrap_err();
+
+        assert_eq!((2, 2), (err.line, err.col));
+        assert_eq!(".x", err.snippet);
+
+        let input = Input::new("example.txt", "..\n...\n");
+        let err = Grid::read_lines(&mut input.lines(), |_, ch| Some(ch)).unwrap_err();
+
+        assert_eq!((2, 3, 1), (err.line, err.col, err.len));
+    }
+
+    #[test]
+    fn neighbors() -> Result<(), Box<dyn std::error::Error>> {
+        let grid = Grid::parse(EXAMPLE)?;
+
+        assert_eq!(
+            vec![(Direction::E, (1, 0)), (Direction::S, (0, 1))],
+            grid.neighbors4((0, 0)).collect::<Vec<_>>()
+        );
+        assert_eq!(
+            vec![(Direction::N, (3, 1)), (Direction::W, (2, 2))],
+            grid.neighbors4((3, 2)).collect::<Vec<_>>()
+        );
+        assert_eq!(8, grid.neighbors8((1, 1)).count());
+        assert_eq!(vec![(1, 0), (0, 1), (1, 1)], grid.neighbors8((0, 0)).collect::<Vec<_>>());
+        Ok(())
+    }
+
+    #[test]
+    fn rows_and_columns() -> Result<(), Box<dyn std::error::Error>> {
+        let grid = Grid::parse(EXAMPLE)?;
+
+        assert_eq!(&['.', '.', '#', '.'], grid.row(1));
+        assert_eq!(3, grid.rows().count());
+        assert_eq!(vec![&'#', &'#', &'.'], grid.column(2).collect::<Vec<_>>());
+        assert_eq!(4, grid.columns().count());
+        assert_eq!(Some((0, 2)), grid.points().find(|p| grid[*p] == '#' && p.1 > 0 && p.0 == 0));
+        Ok(())
+    }
+
+    #[test]
+    fn direction_turns() {
+        for d in Direction::iter() {
+            assert_eq!(d, d.turn_right().turn_left());
+            assert_eq!(d.rev(), d.turn_right().turn_right());
+        }
+        assert_eq!(Direction::E, Direction::N.turn_right());
+    }
+}
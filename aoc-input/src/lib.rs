@@ -0,0 +1,333 @@
+use std::{collections::HashMap, fmt::Display, path::Path, str::FromStr};
+
+#[derive(Debug)]
+pub struct Input {
+    pub name: String,
+    pub text: String,
+}
+
+impl Input {
+    pub fn new(name: &str, text: &str) -> Self {
+        Self {
+            name: name.to_string(),
+            text: text.to_string(),
+        }
+    }
+
+    pub fn read_file<P>(path: P) -> Result<Self, ParseError>
+        where P: AsRef<Path>
+    {
+        let name = path.as_ref().display().to_string();
+
+        match std::fs::read_to_string(path) {
+            Ok(text) => Ok(Self { name, text }),
+            Err(err) => Err(ParseError::new(&name, ParseErrorKind::Io(err))),
+        }
+    }
+
+    pub fn lines(&self) -> Lines<'_> {
+        Lines {
+            input: self,
+            iter: self.text.lines().enumerate(),
+            last: 0,
+        }
+    }
+
+    pub fn error(&self, kind: ParseErrorKind) -> ParseError {
+        ParseError::new(&self.name, kind)
+    }
+}
+
+#[derive(Clone, Debug)]
+pub struct Lines<'a> {
+    input: &'a Input,
+    iter: std::iter::Enumerate<std::str::Lines<'a>>,
+    last: usize,
+}
+
+impl<'a> Iterator for Lines<'a> {
+    type Item = Line<'a>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let (i, text) = self.iter.next()?;
+        self.last = i + 1;
+
+        Some(Line { input: self.input, no: i + 1, text })
+    }
+}
+
+impl<'a> Lines<'a> {
+    pub fn expect(&mut self, what: &str) -> Result<Line<'a>, ParseError> {
+        match self.next() {
+            Some(line) => Ok(line),
+            None => Err(self.eof(ParseErrorKind::Expected(what.to_string()))),
+        }
+    }
+
+    pub fn block(&mut self) -> impl Iterator<Item = Line<'a>> + '_ {
+        self.by_ref().take_while(|line| !line.is_empty())
+    }
+
+    pub fn error(&self, kind: ParseErrorKind) -> ParseError {
+        self.input.error(kind)
+    }
+
+    pub fn eof(&self, kind: ParseErrorKind) -> ParseError {
+        ParseError {
+            line: self.last + 1,
+            col: 1,
+            len: 1,
+            ..self.input.error(kind)
+        }
+    }
+}
+
+#[derive(Clone, Copy, Debug)]
+pub struct Line<'a> {
+    input: &'a Input,
+    pub no: usize,
+    pub text: &'a str,
+}
+
+impl<'a> Line<'a> {
+    pub fn is_empty(&self) -> bool {
+        self.text.trim().is_empty()
+    }
+
+    pub fn error_at(&self, col: usize, len: usize, kind: ParseErrorKind) -> ParseError {
+        ParseError {
+            line: self.no,
+            col: col + 1,
+            len: len.max(1),
+            snippet: self.text.to_string(),
+            ..self.input.error(kind)
+        }
+    }
+
+    pub fn error(&self, at: &str, kind: ParseErrorKind) -> ParseError {
+        let (col, len) = self.span(at);
+        self.error_at(col, len, kind)
+    }
+
+    pub fn parse<T>(&self, s: &'a str) -> Result<T, ParseError>
+        where T: FromStr, T::Err: Display
+    {
+        s.parse::<T>().map_err(|err| {
+            self.error(s, ParseErrorKind::InvalidValue { value: s.to_string(), reason: err.to_string() })
+        })
+    }
+
+    pub fn split_once(&self, s: &'a str, sep: &str) -> Result<(&'a str, &'a str), ParseError> {
+        s.split_once(sep).ok_or_else(|| self.expected_after(s, sep))
+    }
+
+    pub fn strip_prefix(&self, s: &'a str, prefix: &str) -> Result<&'a str, ParseError> {
+        s.strip_prefix(prefix).ok_or_else(|| {
+            let (col, _) = self.span(s);
+            self.error_at(col, prefix.len(), ParseErrorKind::Expected(format!("{:?}", prefix)))
+        })
+    }
+
+    pub fn expected_after(&self, s: &str, what: &str) -> ParseError {
+        let (col, len) = self.span(s);
+        self.error_at(col + len, 1, ParseErrorKind::Expected(format!("{:?}", what)))
+    }
+
+    fn span(&self, s: &str) -> (usize, usize) {
+        let base = self.text.as_ptr() as usize;
+        let start = s.as_ptr() as usize;
+
+        if start < base || start + s.len() > base + self.text.len() {
+            return (0, self.text.chars().count());
+        }
+
+        let offset = start - base;
+        (self.text[..offset].chars().count(), s.chars().count())
+    }
+}
+
+#[derive(Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord, Debug)]
+pub struct Symbol(pub u32);
+
+#[derive(Clone, Debug, Default)]
+pub struct Interner<'a> {
+    ids: HashMap<&'a str, Symbol>,
+    names: Vec<&'a str>,
+}
+
+impl<'a> Interner<'a> {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn intern(&mut self, name: &'a str) -> Symbol {
+        if let Some(id) = self.ids.get(name) {
+            return *id;
+        }
+
+        let id = Symbol(self.names.len() as u32);
+        self.ids.insert(name, id);
+        self.names.push(name);
+        id
+    }
+
+    pub fn get(&self, name: &str) -> Option<Symbol> {
+        self.ids.get(name).copied()
+    }
+
+    pub fn name(&self, id: Symbol) -> &'a str {
+        self.names[id.0 as usize]
+    }
+
+    pub fn len(&self) -> usize {
+        self.names.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.names.is_empty()
+    }
+}
+
+#[derive(Debug)]
+pub enum ParseErrorKind {
+    Io(std::io::Error),
+    Expected(String),
+    UnexpectedChar(char),
+    InvalidValue { value: String, reason: String },
+    Invalid(String),
+}
+impl std::fmt::Display for ParseErrorKind {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ParseErrorKind::Io(err) => write!(f, "{}", err),
+            ParseErrorKind::Expected(what) => write!(f, "expected {}", what),
+            ParseErrorKind::UnexpectedChar(ch) => write!(f, "unexpected char {:?}", ch),
+            ParseErrorKind::InvalidValue { value, reason } => write!(f, "invalid value {:?}: {}", value, reason),
+            ParseErrorKind::Invalid(msg) => write!(f, "{}", msg),
+        }
+    }
+}
+
+#[derive(Debug)]
+pub struct ParseError {
+    pub kind: ParseErrorKind,
+    pub file: String,
+    pub line: usize,
+    pub col: usize,
+    pub len: usize,
+    pub snippet: String,
+}
+
+impl ParseError {
+    pub fn new(file: &str, kind: ParseErrorKind) -> Self {
+        Self {
+            kind,
+            file: file.to_string(),
+            line: 0,
+            col: 0,
+            len: 0,
+            snippet: String::new(),
+        }
+    }
+}
+
+impl std::fmt::Display for ParseError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        writeln!(f, "ParseError: {}", self.kind)?;
+
+        if self.line == 0 {
+            return write!(f, " --> {}", self.file);
+        }
+
+        let gutter = " ".repeat(self.line.to_string().len());
+
+        writeln!(f, "{}--> {}:{}:{}", gutter, self.file, self.line, self.col)?;
+        writeln!(f, "{} |", gutter)?;
+        writeln!(f, "{} | {}", self.line, self.snippet)?;
+        write!(f, "{} | {}{}", gutter, " ".repeat(self.col - 1), "^".repeat(self.len))
+    }
+}
+impl std::error::Error for ParseError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match &self.kind {
+            ParseErrorKind::Io(err) => Some(err),
+            _ => None,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::*;
+
+    #[test]
+    fn parse_error_caret() {
+        let input = Input::new("example.txt", "Button A: X+94, Y+34\nButton B: X+2a, Y+67\n");
+        let line = input.lines().nth(1).unwrap();
+
+        let (_, rhs) = line.split_once(line.text, ":").unwrap();
+        let (x, _) = line.split_once(rhs, ",").unwrap();
+        let err = line.parse::<usize>(line.strip_prefix(x.trim(), "X+").unwrap()).unwrap_err();
+
+        assert_eq!((2, 13, 2), (err.line, err.col, err.len));
+        assert_eq!("\
+ParseError: invalid value \"2a\": invalid digit found in string
+ --> example.txt:2:13
+  |
+2 | Button B: X+2a, Y+67
+  |             ^^", err.to_string());
+    }
+
+    #[test]
+    fn parse_error_expected() {
+        let input = Input::new("example.txt", "p=0,4 v=3,-3\n");
+        let line = input.lines().next().unwrap();
+
+        let err = line.split_once(line.text, ";").unwrap_err();
+        assert_eq!((1, 13), (err.line, err.col));
+        assert_eq!("expected \";\"", err.kind.to_string());
+
+        let err = line.strip_prefix(line.text, "v=").unwrap_err();
+        assert_eq!((1, 1, 2), (err.line, err.col, err.len));
+    }
+
+    #[test]
+    fn lines_block_and_eof() -> Result<(), Box<dyn std::error::Error>> {
+        let input = Input::new("example.txt", "a\nb\n\nc\n");
+        let mut lines = input.lines();
+
+        assert_eq!(vec!["a", "b"], lines.block().map(|line| line.text).collect::<Vec<_>>());
+        assert_eq!(4, lines.expect("c")?.no);
+
+        let err = lines.expect("program").unwrap_err();
+        assert_eq!((5, 1), (err.line, err.col));
+        assert_eq!("expected program", err.kind.to_string());
+        Ok(())
+    }
+
+    #[test]
+    fn interner_borrows_names() {
+        let input = Input::new("example.txt", "kh-tc\nqp-kh\n");
+        let mut names = Interner::new();
+
+        let pairs = input.lines()
+            .filter_map(|line| line.text.split_once('-'))
+            .map(|(lhs, rhs)| (names.intern(lhs), names.intern(rhs)))
+            .collect::<Vec<_>>();
+
+        assert_eq!(vec![(Symbol(0), Symbol(1)), (Symbol(2), Symbol(0))], pairs);
+        assert_eq!(3, names.len());
+        assert_eq!(Some(Symbol(2)), names.get("qp"));
+        assert_eq!(None, names.get("de"));
+        assert_eq!("tc", names.name(Symbol(1)));
+        assert_eq!(input.text.as_ptr(), names.name(Symbol(0)).as_ptr());
+    }
+
+    #[test]
+    fn read_file_not_found() {
+        let err = Input::read_file("./no_such_file.txt").err().unwrap();
+
+        assert!(matches!(err.kind, ParseErrorKind::Io(_)));
+        assert!(err.to_string().ends_with(" --> ./no_such_file.txt"));
+    }
+}
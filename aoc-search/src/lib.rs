@@ -0,0 +1,297 @@
+use std::{cmp::Reverse, collections::{BinaryHeap, HashMap, HashSet, VecDeque}, hash::Hash};
+
+pub type Cost = u64;
+
+#[derive(Clone, Debug)]
+pub struct SearchResult<N> {
+    pub dist: HashMap<N, Cost>,
+    pub preds: HashMap<N, Vec<N>>,
+}
+
+impl<N> SearchResult<N>
+    where N: Clone + Eq + Hash
+{
+    pub fn new() -> Self {
+        Self {
+            dist: HashMap::new(),
+            preds: HashMap::new(),
+        }
+    }
+
+    pub fn distance(&self, n: &N) -> Option<Cost> {
+        self.dist.get(n).copied()
+    }
+
+    pub fn contains(&self, n: &N) -> bool {
+        self.dist.contains_key(n)
+    }
+
+    pub fn predecessors(&self, n: &N) -> &[N] {
+        self.preds.get(n).map_or(&[], |preds| preds.as_slice())
+    }
+
+    pub fn nearest<I>(&self, goals: I) -> Option<(N, Cost)>
+        where I: IntoIterator<Item = N>
+    {
+        goals.into_iter()
+            .filter_map(|n| self.distance(&n).map(|cost| (n, cost)))
+            .min_by_key(|(_, cost)| *cost)
+    }
+
+    pub fn path(&self, goal: &N) -> Option<Vec<N>> {
+        if !self.contains(goal) {
+            return None;
+        }
+
+        let mut path = vec![goal.clone()];
+        while let Some(n) = self.predecessors(path.last().unwrap()).first() {
+            path.push(n.clone());
+        }
+        path.reverse();
+
+        Some(path)
+    }
+
+    pub fn paths(&self, goal: &N) -> Vec<Vec<N>> {
+        if !self.contains(goal) {
+            return vec![];
+        }
+
+        self.paths_through(goal, &mut HashSet::new())
+    }
+
+    // Zero-cost edges can make nodes each other's predecessors, so
+    // predecessors already on the path being built are skipped.
+    fn paths_through(&self, goal: &N, on_path: &mut HashSet<N>) -> Vec<Vec<N>> {
+        let preds = self.predecessors(goal);
+        if preds.is_empty() {
+            return vec![vec![goal.clone()]];
+        }
+
+        on_path.insert(goal.clone());
+        let mut paths = vec![];
+        for n in preds {
+            if on_path.contains(n) {
+                continue;
+            }
+            paths.extend(self.paths_through(n, on_path).into_iter().map(|mut path| {
+                path.push(goal.clone());
+                path
+            }));
+        }
+        on_path.remove(goal);
+
+        paths
+    }
+
+    pub fn dag<I>(&self, goals: I) -> HashSet<N>
+        where I: IntoIterator<Item = N>
+    {
+        let mut visited = HashSet::<N>::new();
+        let mut q = goals.into_iter()
+            .filter(|n| self.contains(n))
+            .collect::<Vec<_>>()
+        ;
+
+        while let Some(n) = q.pop() {
+            if !visited.insert(n.clone()) {
+                continue;
+            }
+            q.extend(self.predecessors(&n).iter().cloned());
+        }
+
+        visited
+    }
+
+    fn relax(&mut self, from: Option<&N>, to: &N, cost: Cost) -> bool {
+        match self.dist.get(to) {
+            Some(dist) if *dist < cost => false,
+            Some(dist) if *dist == cost => {
+                if let Some(from) = from.filter(|from| *from != to) {
+                    self.preds.entry(to.clone()).or_default().push(from.clone());
+                }
+                false
+            }
+            _ => {
+                self.dist.insert(to.clone(), cost);
+                self.preds.insert(to.clone(), from.into_iter().cloned().collect());
+                true
+            }
+        }
+    }
+}
+
+impl<N> Default for SearchResult<N>
+    where N: Clone + Eq + Hash
+{
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+pub fn bfs<N, S, F, I>(starts: S, mut neighbors: F) -> SearchResult<N>
+    where N: Clone + Eq + Hash, S: IntoIterator<Item = N>, F: FnMut(&N) -> I, I: IntoIterator<Item = N>
+{
+    let mut result = SearchResult::new();
+    let mut q = VecDeque::<N>::new();
+
+    for n in starts {
+        if result.relax(None, &n, 0) {
+            q.push_back(n);
+        }
+    }
+
+    while let Some(n0) = q.pop_front() {
+        let cost = result.dist[&n0] + 1;
+
+        for n in neighbors(&n0) {
+            if result.relax(Some(&n0), &n, cost) {
+                q.push_back(n);
+            }
+        }
+    }
+
+    result
+}
+
+pub fn dijkstra<N, S, F, I>(starts: S, neighbors: F) -> SearchResult<N>
+    where N: Clone + Eq + Hash, S: IntoIterator<Item = N>, F: FnMut(&N) -> I, I: IntoIterator<Item = (N, Cost)>
+{
+    let (result, _) = best_first(starts, neighbors, |_| false, |_| 0);
+    result
+}
+
+pub fn astar<N, S, F, I, G, H>(starts: S, neighbors: F, is_goal: G, heuristic: H) -> Option<(Cost, Vec<N>)>
+    where N: Clone + Eq + Hash, S: IntoIterator<Item = N>, F: FnMut(&N) -> I, I: IntoIterator<Item = (N, Cost)>,
+          G: FnMut(&N) -> bool, H: FnMut(&N) -> Cost
+{
+    let (result, goal) = best_first(starts, neighbors, is_goal, heuristic);
+    let goal = goal?;
+
+    Some((result.dist[&goal], result.path(&goal)?))
+}
+
+fn best_first<N, S, F, I, G, H>(starts: S, mut neighbors: F, mut is_goal: G, mut heuristic: H) -> (SearchResult<N>, Option<N>)
+    where N: Clone + Eq + Hash, S: IntoIterator<Item = N>, F: FnMut(&N) -> I, I: IntoIterator<Item = (N, Cost)>,
+          G: FnMut(&N) -> bool, H: FnMut(&N) -> Cost
+{
+    let mut result = SearchResult::new();
+    let mut nodes = Vec::<N>::new();
+    let mut q = BinaryHeap::<Reverse<(Cost, Cost, usize)>>::new();
+
+    for n in starts {
+        if result.relax(None, &n, 0) {
+            q.push(Reverse((heuristic(&n), 0, nodes.len())));
+            nodes.push(n);
+        }
+    }
+
+    while let Some(Reverse((_, cost0, id))) = q.pop() {
+        let n0 = nodes[id].clone();
+        if result.dist[&n0] < cost0 {
+            continue;
+        }
+        if is_goal(&n0) {
+            return (result, Some(n0));
+        }
+
+        for (n, cost) in neighbors(&n0) {
+            let cost = cost0 + cost;
+            if result.relax(Some(&n0), &n, cost) {
+                q.push(Reverse((cost + heuristic(&n), cost, nodes.len())));
+                nodes.push(n);
+            }
+        }
+    }
+
+    (result, None)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::*;
+
+    //   0 -1-> 1 -1-> 3
+    //   0 -1-> 2 -1-> 3 -5-> 4
+    //   0 ----------7-------> 4
+    fn diamond(n: &u32) -> Vec<(u32, Cost)> {
+        match n {
+            0 => vec![(1, 1), (2, 1), (4, 7)],
+            1 | 2 => vec![(3, 1)],
+            3 => vec![(4, 5)],
+            _ => vec![],
+        }
+    }
+
+    fn open_grid((x, y): &(i32, i32)) -> Vec<(i32, i32)> {
+        [(0, -1), (1, 0), (0, 1), (-1, 0)].into_iter()
+            .map(|(dx, dy)| (x + dx, y + dy))
+            .filter(|(x, y)| (0..5).contains(x) && (0..5).contains(y))
+            .collect()
+    }
+
+    #[test]
+    fn bfs_example() {
+        let result = bfs([(0, 0)], open_grid);
+
+        assert_eq!(Some(0), result.distance(&(0, 0)));
+        assert_eq!(Some(8), result.distance(&(4, 4)));
+        assert_eq!(25, result.dist.len());
+        assert_eq!(2, result.predecessors(&(1, 1)).len());
+        assert_eq!(70, result.paths(&(4, 4)).len());
+        assert_eq!(9, result.path(&(4, 4)).unwrap().len());
+    }
+
+    #[test]
+    fn dijkstra_example() {
+        let result = dijkstra([0], diamond);
+
+        assert_eq!(Some(2), result.distance(&3));
+        assert_eq!(Some(7), result.distance(&4));
+        assert_eq!(None, result.distance(&5));
+
+        assert_eq!(&[1, 2], result.predecessors(&3));
+        assert_eq!(&[0, 3], result.predecessors(&4));
+        assert_eq!(Some(vec![0, 4]), result.path(&4));
+        assert_eq!(vec![vec![0, 4], vec![0, 1, 3, 4], vec![0, 2, 3, 4]], result.paths(&4));
+        assert_eq!(HashSet::from([0, 1, 2, 3]), result.dag([3]));
+        assert_eq!(Some((3, 2)), result.nearest([4, 3, 5]));
+    }
+
+    #[test]
+    fn zero_cost_cycle() {
+        //   0 -1-> 1 <-0-> 2 -1-> 3, and 0 -1-> 2
+        let result = dijkstra([0], |n: &u32| match n {
+            0 => vec![(1, 1), (2, 1)],
+            1 => vec![(2, 0)],
+            2 => vec![(1, 0), (3, 1)],
+            _ => vec![],
+        });
+
+        assert_eq!(Some(2), result.distance(&3));
+        assert_eq!(&[0, 2], result.predecessors(&1));
+        assert_eq!(&[0, 1], result.predecessors(&2));
+        assert_eq!(Some(vec![0, 2, 3]), result.path(&3));
+        assert_eq!(vec![vec![0, 2, 3], vec![0, 1, 2, 3]], result.paths(&3));
+        assert_eq!(HashSet::from([0, 1, 2, 3]), result.dag([3]));
+    }
+
+    #[test]
+    fn astar_example() {
+        let goal = (4, 4);
+        let found = astar(
+            [(0, 0)],
+            |p| open_grid(p).into_iter().map(|p| (p, 1)).collect::<Vec<_>>(),
+            |p| *p == goal,
+            |(x, y)| (x.abs_diff(goal.0) + y.abs_diff(goal.1)) as Cost,
+        );
+
+        let (cost, path) = found.unwrap();
+        assert_eq!(8, cost);
+        assert_eq!(Some(&(0, 0)), path.first());
+        assert_eq!(Some(&goal), path.last());
+
+        assert_eq!(Some((7, vec![0, 4])), astar([0], diamond, |n| *n == 4, |_| 0));
+        assert_eq!(None, astar([0], diamond, |n| *n == 5, |_| 0));
+    }
+}
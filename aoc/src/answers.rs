@@ -0,0 +1,111 @@
+use std::{collections::BTreeMap, path::{Path, PathBuf}};
+
+use aoc_input::{Input, ParseError, ParseErrorKind};
+
+pub fn default_path() -> PathBuf {
+    Path::new(env!("CARGO_MANIFEST_DIR")).join("..").join("answers.toml")
+}
+
+#[derive(PartialEq, Debug, Default)]
+pub struct Answers {
+    pub entries: BTreeMap<(u8, String), String>,
+}
+
+impl Answers {
+    pub fn get(&self, day: u8, part: &str) -> Option<&str> {
+        self.entries.get(&(day, part.to_string())).map(|answer| answer.as_str())
+    }
+
+    pub fn read_file<P>(path: P) -> Result<Self, ParseError>
+        where P: AsRef<Path>
+    {
+        Self::parse(&Input::read_file(path)?)
+    }
+
+    pub fn parse(input: &Input) -> Result<Self, ParseError> {
+        let mut answers = Answers::default();
+        let mut day = None;
+
+        for line in input.lines() {
+            let s = line.text.trim();
+            if s.is_empty() || s.starts_with('#') {
+                continue;
+            }
+
+            if let Some(header) = s.strip_prefix('[') {
+                let (name, _) = line.split_once(header, "]")?;
+                day = Some(line.parse::<u8>(line.strip_prefix(name.trim(), "day")?)?);
+                continue;
+            }
+
+            let Some(day) = day else {
+                return Err(line.error(s, ParseErrorKind::Expected("\"[dayNN]\" before answers".to_string())));
+            };
+
+            let (part, value) = line.split_once(s, "=")?;
+            let (answer, _) = line.split_once(line.strip_prefix(value.trim(), "\"")?, "\"")?;
+
+            let part = part.trim();
+            if answers.entries.insert((day, part.to_string()), answer.to_string()).is_some() {
+                return Err(line.error(part, ParseErrorKind::Invalid(format!("duplicate answer for day{:02} part{}", day, part))));
+            }
+        }
+
+        Ok(answers)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::answers::*;
+
+    #[test]
+    fn parse_example() -> Result<(), Box<dyn std::error::Error>> {
+        let input = Input::new("answers.toml", "\
+# comment
+[day05]
+1 = \"6242\"
+2b = \"5169\"
+
+[day17]
+1 = \"6,7,5\"
+");
+        let answers = Answers::parse(&input)?;
+
+        assert_eq!(3, answers.entries.len());
+        assert_eq!(Some("5169"), answers.get(5, "2b"));
+        assert_eq!(Some("6,7,5"), answers.get(17, "1"));
+        assert_eq!(None, answers.get(5, "2"));
+        Ok(())
+    }
+
+    #[test]
+    fn parse_error() {
+        let input = Input::new("answers.toml", "1 = \"6242\"\n");
+        let err = Answers::parse(&input).unwrap_err();
+        assert_eq!((1, 1), (err.line, err.col));
+
+        let input = Input::new("answers.toml", "[day05]\n1 = 6242\n");
+        let err = Answers::parse(&input).unwrap_err();
+        assert_eq!((2, 5), (err.line, err.col));
+        assert_eq!("expected \"\\\"\"", err.kind.to_string());
+
+        let input = Input::new("answers.toml", "[day05]\n1 = \"1\"\n1 = \"2\"\n");
+        let err = Answers::parse(&input).unwrap_err();
+        assert_eq!((3, 1), (err.line, err.col));
+
+        let input = Input::new("answers.toml", "[dayxx]\n");
+        let err = Answers::parse(&input).unwrap_err();
+        assert_eq!((1, 5), (err.line, err.col));
+    }
+
+    #[test]
+    fn default_answers_cover_every_solver() -> Result<(), Box<dyn std::error::Error>> {
+        let answers = Answers::read_file(default_path())?;
+
+        for solver in crate::solvers::SOLVERS.iter() {
+            assert!(answers.get(solver.day, solver.part).is_some(), "day{:02} part{}", solver.day, solver.part);
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,235 @@
+use std::{env, fs, io, path::PathBuf};
+
+pub const BASE_URL: &str = "https://adventofcode.com";
+const USER_AGENT: &str = concat!("aoc-runner/", env!("CARGO_PKG_VERSION"));
+
+#[derive(Debug)]
+pub enum FetchError {
+    NoSession,
+    NoCacheDir,
+    Status(u16, String),
+    Transport(String),
+    Io(io::Error),
+}
+impl std::fmt::Display for FetchError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            FetchError::NoSession => write!(f, "No session cookie: set AOC_SESSION or write it to ~/.config/aoc/session"),
+            FetchError::NoCacheDir => write!(f, "No cache directory: set AOC_CACHE_DIR or HOME"),
+            FetchError::Status(code, body) => write!(f, "HTTP {}: {}", code, body),
+            FetchError::Transport(msg) => write!(f, "{}", msg),
+            FetchError::Io(err) => write!(f, "{}", err),
+        }
+    }
+}
+impl std::error::Error for FetchError {}
+
+impl From<io::Error> for FetchError {
+    fn from(err: io::Error) -> Self {
+        FetchError::Io(err)
+    }
+}
+
+fn home_dir(var: &str, fallback: &str) -> Option<PathBuf> {
+    match env::var_os(var) {
+        Some(dir) => Some(PathBuf::from(dir)),
+        None => env::var_os("HOME").map(|home| PathBuf::from(home).join(fallback)),
+    }
+}
+
+pub fn session_path() -> Option<PathBuf> {
+    home_dir("XDG_CONFIG_HOME", ".config").map(|dir| dir.join("aoc").join("session"))
+}
+
+pub fn session() -> Result<String, FetchError> {
+    let session = match env::var("AOC_SESSION") {
+        Ok(session) => session,
+        Err(_) => session_path()
+            .and_then(|path| fs::read_to_string(path).ok())
+            .ok_or(FetchError::NoSession)?,
+    };
+
+    match session.trim() {
+        "" => Err(FetchError::NoSession),
+        session => Ok(session.to_string()),
+    }
+}
+
+#[derive(Clone, Debug)]
+pub struct Cache {
+    pub dir: PathBuf,
+}
+
+impl Cache {
+    pub fn new<P>(dir: P) -> Self
+        where P: Into<PathBuf>
+    {
+        Self { dir: dir.into() }
+    }
+
+    pub fn from_env() -> Option<Self> {
+        if let Some(dir) = env::var_os("AOC_CACHE_DIR") {
+            return Some(Self::new(dir));
+        }
+        home_dir("XDG_CACHE_HOME", ".cache").map(|dir| Self::new(dir.join("aoc")))
+    }
+
+    pub fn path(&self, year: u16, day: u8) -> PathBuf {
+        self.dir.join(year.to_string()).join(format!("day{:02}.txt", day))
+    }
+
+    pub fn get(&self, year: u16, day: u8) -> Option<PathBuf> {
+        Some(self.path(year, day)).filter(|path| path.is_file())
+    }
+
+    pub fn store(&self, year: u16, day: u8, text: &str) -> io::Result<PathBuf> {
+        let path = self.path(year, day);
+        let tmp = path.with_extension("tmp");
+
+        fs::create_dir_all(self.dir.join(year.to_string()))?;
+        fs::write(&tmp, text)?;
+        fs::rename(&tmp, &path)?;
+
+        Ok(path)
+    }
+}
+
+#[derive(Clone, Debug)]
+pub struct Client {
+    pub base_url: String,
+    pub session: String,
+}
+
+impl Client {
+    pub fn new(base_url: &str, session: &str) -> Self {
+        Self {
+            base_url: base_url.trim_end_matches('/').to_string(),
+            session: session.to_string(),
+        }
+    }
+
+    pub fn from_env() -> Result<Self, FetchError> {
+        let base_url = env::var("AOC_BASE_URL").unwrap_or_else(|_| BASE_URL.to_string());
+        Ok(Self::new(&base_url, &session()?))
+    }
+
+    pub fn input_url(&self, year: u16, day: u8) -> String {
+        format!("{}/{}/day/{}/input", self.base_url, year, day)
+    }
+
+    pub fn get_input(&self, year: u16, day: u8) -> Result<String, FetchError> {
+        let response = ureq::get(&self.input_url(year, day))
+            .set("Cookie", &format!("session={}", self.session))
+            .set("User-Agent", USER_AGENT)
+            .call();
+
+        match response {
+            Ok(response) => Ok(response.into_string()?),
+            Err(ureq::Error::Status(code, response)) => {
+                let body = response.into_string().unwrap_or_default();
+                Err(FetchError::Status(code, body.trim().to_string()))
+            }
+            Err(err) => Err(FetchError::Transport(err.to_string())),
+        }
+    }
+}
+
+pub fn fetch(client: &Client, cache: &Cache, year: u16, day: u8, force: bool) -> Result<PathBuf, FetchError> {
+    if let Some(path) = cache.get(year, day).filter(|_| !force) {
+        return Ok(path);
+    }
+
+    let text = client.get_input(year, day)?;
+    Ok(cache.store(year, day, &text)?)
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{sync::{Arc, atomic::{AtomicUsize, Ordering}}, thread};
+
+    use crate::fetch::*;
+
+    struct MockServer {
+        url: String,
+        hits: Arc<AtomicUsize>,
+    }
+
+    impl MockServer {
+        fn start(session: &'static str, body: &'static str) -> Self {
+            let server = tiny_http::Server::http("127.0.0.1:0").unwrap();
+            let url = format!("http://{}", server.server_addr().to_ip().unwrap());
+            let hits = Arc::new(AtomicUsize::new(0));
+
+            let counter = hits.clone();
+            thread::spawn(move || {
+                for request in server.incoming_requests() {
+                    counter.fetch_add(1, Ordering::SeqCst);
+
+                    let cookie = request.headers().iter()
+                        .find(|header| header.field.equiv("Cookie"))
+                        .map(|header| header.value.to_string());
+
+                    let response = match (request.url(), cookie) {
+                        ("/2024/day/1/input", Some(cookie)) if cookie == format!("session={}", session) => {
+                            tiny_http::Response::from_string(body)
+                        }
+                        ("/2024/day/1/input", _) => {
+                            tiny_http::Response::from_string("Puzzle inputs differ by user.  Please log in to get your puzzle input.\n")
+                                .with_status_code(400)
+                        }
+                        _ => tiny_http::Response::from_string("404 Not Found\n").with_status_code(404),
+                    };
+                    let _ = request.respond(response);
+                }
+            });
+
+            Self { url, hits }
+        }
+
+        fn hits(&self) -> usize {
+            self.hits.load(Ordering::SeqCst)
+        }
+    }
+
+    fn temp_cache(name: &str) -> Cache {
+        let dir = env::temp_dir().join(format!("aoc-fetch-{}-{}", name, std::process::id()));
+        let _ = fs::remove_dir_all(&dir);
+        Cache::new(dir)
+    }
+
+    #[test]
+    fn fetch_and_cache() -> Result<(), Box<dyn std::error::Error>> {
+        let server = MockServer::start("secret", "3   4\n4   3\n");
+        let client = Client::new(&server.url, "secret");
+        let cache = temp_cache("cache");
+
+        let path = fetch(&client, &cache, 2024, 1, false)?;
+        assert_eq!(cache.path(2024, 1), path);
+        assert_eq!("3   4\n4   3\n", fs::read_to_string(&path)?);
+        assert_eq!(1, server.hits());
+
+        fetch(&client, &cache, 2024, 1, false)?;
+        assert_eq!(1, server.hits());
+
+        fetch(&client, &cache, 2024, 1, true)?;
+        assert_eq!(2, server.hits());
+
+        fs::remove_dir_all(&cache.dir)?;
+        Ok(())
+    }
+
+    #[test]
+    fn fetch_errors() {
+        let server = MockServer::start("secret", "");
+        let cache = temp_cache("errors");
+
+        let err = fetch(&Client::new(&server.url, "wrong"), &cache, 2024, 1, false).unwrap_err();
+        assert!(matches!(err, FetchError::Status(400, ref body) if body.starts_with("Puzzle inputs differ")), "{}", err);
+
+        let err = fetch(&Client::new(&server.url, "secret"), &cache, 2024, 2, false).unwrap_err();
+        assert!(matches!(err, FetchError::Status(404, _)), "{}", err);
+
+        assert_eq!(None, cache.get(2024, 1));
+        assert_eq!(2, server.hits());
+    }
+}
@@ -0,0 +1,4 @@
+pub mod answers;
+pub mod fetch;
+pub mod scaffold;
+pub mod solvers;
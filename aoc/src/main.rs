@@ -0,0 +1,381 @@
+use std::{path::{Path, PathBuf}, time::Instant};
+
+use aoc::{answers::{self, Answers}, fetch::{self, Cache, Client, FetchError}, scaffold, solvers::{self, Solver}};
+use aoc_input::ParseError;
+
+const YEAR: u16 = 2024;
+const USAGE: &str = "\
+usage: aoc run <year> [<day> [<part>]] [--input <path>]
+       aoc verify <year> [<day> [<part>]] [--answers <path>]
+       aoc fetch <year> <day> [--force]
+       aoc new <day> [<part>]";
+
+fn main() -> Result<(), Box<dyn std::error::Error>> {
+    let args = std::env::args().skip(1).collect::<Vec<_>>();
+
+    match args.first().map(|s| s.as_str()) {
+        Some("run") => run(&parse_run_args(&args[1..])?),
+        Some("verify") => verify(&parse_run_args(&args[1..])?),
+        Some("fetch") => fetch(&parse_fetch_args(&args[1..])?),
+        Some("new") => new(&parse_new_args(&args[1..])?),
+        _ => Err(Box::new(CommandError::Usage(USAGE.into()))),
+    }
+}
+
+#[derive(Debug)]
+enum CommandError {
+    Usage(String),
+    UnknownYear(u16),
+    SolverNotFound(String),
+    Failed(usize),
+    Mismatch(usize),
+}
+impl std::fmt::Display for CommandError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            CommandError::Usage(msg) => write!(f, "{}", msg),
+            CommandError::UnknownYear(year) => write!(f, "No solvers for year: {}", year),
+            CommandError::SolverNotFound(target) => write!(f, "No solvers for: {}", target),
+            CommandError::Failed(count) => write!(f, "{} solver(s) failed", count),
+            CommandError::Mismatch(count) => write!(f, "{} solver(s) did not match the recorded answers", count),
+        }
+    }
+}
+impl std::error::Error for CommandError {}
+
+#[derive(PartialEq, Debug)]
+struct RunArgs {
+    year: u16,
+    day: Option<u8>,
+    part: Option<String>,
+    input: Option<PathBuf>,
+    answers: Option<PathBuf>,
+}
+
+fn parse_run_args(args: &[String]) -> Result<RunArgs, Box<dyn std::error::Error>> {
+    let mut positionals = vec![];
+    let mut input = None;
+    let mut answers = None;
+    let mut iter = args.iter();
+
+    while let Some(arg) = iter.next() {
+        match arg.as_str() {
+            "--input" => {
+                let Some(path) = iter.next() else {
+                    return Err(Box::new(CommandError::Usage(USAGE.into())));
+                };
+                input = Some(PathBuf::from(path));
+            }
+            "--answers" => {
+                let Some(path) = iter.next() else {
+                    return Err(Box::new(CommandError::Usage(USAGE.into())));
+                };
+                answers = Some(PathBuf::from(path));
+            }
+            _ => positionals.push(arg.as_str()),
+        }
+    }
+
+    let (year, day, part) = match positionals[..] {
+        [year] => (year, None, None),
+        [year, day] => (year, Some(day), None),
+        [year, day, part] => (year, Some(day), Some(part)),
+        _ => return Err(Box::new(CommandError::Usage(USAGE.into()))),
+    };
+
+    let day = day.map(|day| day.parse::<u8>()).transpose()?;
+    if input.is_some() && day.is_none() {
+        return Err(Box::new(CommandError::Usage("--input requires a day".into())));
+    }
+
+    Ok(RunArgs {
+        year: year.parse::<u16>()?,
+        day,
+        part: part.map(|part| part.to_string()),
+        input,
+        answers,
+    })
+}
+
+#[derive(PartialEq, Debug)]
+struct FetchArgs {
+    year: u16,
+    day: u8,
+    force: bool,
+}
+
+fn parse_fetch_args(args: &[String]) -> Result<FetchArgs, Box<dyn std::error::Error>> {
+    let force = args.iter().any(|arg| arg == "--force");
+    let positionals = args.iter().filter(|arg| *arg != "--force").collect::<Vec<_>>();
+
+    let [year, day] = positionals[..] else {
+        return Err(Box::new(CommandError::Usage(USAGE.into())));
+    };
+
+    Ok(FetchArgs {
+        year: year.parse::<u16>()?,
+        day: day.parse::<u8>()?,
+        force,
+    })
+}
+
+#[derive(PartialEq, Debug)]
+struct NewArgs {
+    day: u8,
+    part: String,
+}
+
+fn parse_new_args(args: &[String]) -> Result<NewArgs, Box<dyn std::error::Error>> {
+    let (day, part) = match args {
+        [day] => (day, "1"),
+        [day, part] => (day, part.as_str()),
+        _ => return Err(Box::new(CommandError::Usage(USAGE.into()))),
+    };
+
+    Ok(NewArgs {
+        day: day.parse::<u8>()?,
+        part: part.to_string(),
+    })
+}
+
+fn select_solvers(args: &RunArgs) -> Result<Vec<&'static Solver>, Box<dyn std::error::Error>> {
+    if args.year != YEAR {
+        return Err(Box::new(CommandError::UnknownYear(args.year)));
+    }
+
+    let solvers = solvers::select(args.day, args.part.as_deref());
+    if solvers.is_empty() {
+        let target = match (args.day, &args.part) {
+            (Some(day), Some(part)) => format!("day {} part {}", day, part),
+            (Some(day), None) => format!("day {}", day),
+            _ => format!("year {}", args.year),
+        };
+        return Err(Box::new(CommandError::SolverNotFound(target)));
+    }
+
+    Ok(solvers)
+}
+
+fn report_error(solver: &Solver, path: &Path, err: &(dyn std::error::Error + 'static)) {
+    match err.is::<ParseError>() {
+        true => eprintln!("day{:02} part{}: {}", solver.day, solver.part, err),
+        false => eprintln!("day{:02} part{}: {} ({})", solver.day, solver.part, err, path.display()),
+    }
+}
+
+fn input_path(args: &RunArgs, solver: &Solver, cache: Option<&Cache>) -> PathBuf {
+    if let Some(path) = &args.input {
+        return path.clone();
+    }
+
+    cache.and_then(|cache| cache.get(args.year, solver.day))
+        .unwrap_or_else(|| solver.default_input())
+}
+
+fn run(args: &RunArgs) -> Result<(), Box<dyn std::error::Error>> {
+    if args.answers.is_some() {
+        return Err(Box::new(CommandError::Usage("--answers is only valid for verify".into())));
+    }
+
+    let solvers = select_solvers(args)?;
+    let cache = Cache::from_env();
+    let mut failed = 0;
+
+    for solver in solvers {
+        let path = input_path(args, solver, cache.as_ref());
+
+        let started = Instant::now();
+        let answer = (solver.solve)(&path);
+        let elapsed = started.elapsed();
+
+        match answer {
+            Ok(answer) => println!("day{:02} part{}: {} ({:.2?})", solver.day, solver.part, answer, elapsed),
+            Err(err) => {
+                failed += 1;
+                report_error(solver, &path, err.as_ref());
+            }
+        }
+    }
+
+    if failed > 0 {
+        return Err(Box::new(CommandError::Failed(failed)));
+    }
+
+    Ok(())
+}
+
+fn verify(args: &RunArgs) -> Result<(), Box<dyn std::error::Error>> {
+    if args.input.is_some() {
+        return Err(Box::new(CommandError::Usage("--input is only valid for run".into())));
+    }
+
+    let solvers = select_solvers(args)?;
+    let answers = Answers::read_file(args.answers.clone().unwrap_or_else(answers::default_path))?;
+
+    let mismatched = check(&solvers, &answers);
+    if mismatched > 0 {
+        return Err(Box::new(CommandError::Mismatch(mismatched)));
+    }
+
+    Ok(())
+}
+
+fn fetch(args: &FetchArgs) -> Result<(), Box<dyn std::error::Error>> {
+    if !(1..=25).contains(&args.day) {
+        return Err(Box::new(CommandError::Usage(format!("No puzzle for day: {}", args.day))));
+    }
+
+    let cache = Cache::from_env().ok_or(FetchError::NoCacheDir)?;
+    let path = fetch::fetch(&Client::from_env()?, &cache, args.year, args.day, args.force)?;
+    println!("{}", path.display());
+
+    Ok(())
+}
+
+fn new(args: &NewArgs) -> Result<(), Box<dyn std::error::Error>> {
+    if !(1..=25).contains(&args.day) {
+        return Err(Box::new(CommandError::Usage(format!("No puzzle for day: {}", args.day))));
+    }
+
+    let dir = scaffold::create(&scaffold::workspace_root(), args.day, &args.part)?;
+    println!("created {}", dir.display());
+    println!("register it in aoc/Cargo.toml and aoc/src/solvers.rs to run it with `aoc run`");
+
+    Ok(())
+}
+
+fn check(solvers: &[&Solver], answers: &Answers) -> usize {
+    let mut mismatched = 0;
+
+    for solver in solvers {
+        let path = solver.default_input();
+
+        let started = Instant::now();
+        let answer = (solver.solve)(&path);
+        let elapsed = started.elapsed();
+
+        match (answer, answers.get(solver.day, solver.part)) {
+            (Ok(answer), Some(expected)) if answer == expected => {
+                println!("day{:02} part{}: ok ({:.2?})", solver.day, solver.part, elapsed);
+            }
+            (Ok(answer), Some(expected)) => {
+                mismatched += 1;
+                eprintln!("day{:02} part{}: expected {}, got {}", solver.day, solver.part, expected, answer);
+            }
+            (Ok(answer), None) => {
+                println!("day{:02} part{}: {} (no recorded answer)", solver.day, solver.part, answer);
+            }
+            (Err(err), _) => {
+                mismatched += 1;
+                report_error(solver, &path, err.as_ref());
+            }
+        }
+    }
+
+    mismatched
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::*;
+
+    fn to_args(args: &[&str]) -> Vec<String> {
+        args.iter().map(|arg| arg.to_string()).collect()
+    }
+
+    #[test]
+    fn parse_run_args_example() -> Result<(), Box<dyn std::error::Error>> {
+        assert_eq!(
+            RunArgs{ year: 2024, day: Some(16), part: Some("2".into()), input: Some("path".into()), answers: None },
+            parse_run_args(&to_args(&["2024", "16", "2", "--input", "path"]))?
+        );
+        assert_eq!(
+            RunArgs{ year: 2024, day: Some(5), part: None, input: None, answers: None },
+            parse_run_args(&to_args(&["2024", "5"]))?
+        );
+        assert_eq!(
+            RunArgs{ year: 2024, day: None, part: None, input: None, answers: None },
+            parse_run_args(&to_args(&["2024"]))?
+        );
+
+        assert!(parse_run_args(&to_args(&[])).is_err());
+        assert!(parse_run_args(&to_args(&["2024", "--input"])).is_err());
+        assert!(parse_run_args(&to_args(&["2024", "--input", "path"])).is_err());
+        assert!(parse_run_args(&to_args(&["2024", "x"])).is_err());
+
+        assert_eq!(
+            RunArgs{ year: 2024, day: None, part: None, input: None, answers: Some("answers.toml".into()) },
+            parse_run_args(&to_args(&["2024", "--answers", "answers.toml"]))?
+        );
+        assert!(parse_run_args(&to_args(&["2024", "--answers"])).is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn parse_fetch_args_example() -> Result<(), Box<dyn std::error::Error>> {
+        assert_eq!(
+            FetchArgs{ year: 2024, day: 13, force: false },
+            parse_fetch_args(&to_args(&["2024", "13"]))?
+        );
+        assert_eq!(
+            FetchArgs{ year: 2024, day: 1, force: true },
+            parse_fetch_args(&to_args(&["2024", "--force", "1"]))?
+        );
+
+        assert!(parse_fetch_args(&to_args(&["2024"])).is_err());
+        assert!(parse_fetch_args(&to_args(&["2024", "1", "2"])).is_err());
+        assert!(fetch(&parse_fetch_args(&to_args(&["2024", "26"]))?).is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn parse_new_args_example() -> Result<(), Box<dyn std::error::Error>> {
+        assert_eq!(NewArgs{ day: 7, part: "1".into() }, parse_new_args(&to_args(&["7"]))?);
+        assert_eq!(NewArgs{ day: 5, part: "2b".into() }, parse_new_args(&to_args(&["5", "2b"]))?);
+
+        assert!(parse_new_args(&to_args(&[])).is_err());
+        assert!(parse_new_args(&to_args(&["x"])).is_err());
+        assert!(new(&parse_new_args(&to_args(&["0"]))?).is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn input_path_falls_back_to_cache() -> Result<(), Box<dyn std::error::Error>> {
+        let cache = Cache::new(std::env::temp_dir().join(format!("aoc-input-path-{}", std::process::id())));
+        let solver = solvers::select(Some(1), Some("1"))[0];
+
+        let args = parse_run_args(&to_args(&["2024", "1"]))?;
+        assert_eq!(solver.default_input(), input_path(&args, solver, Some(&cache)));
+
+        let cached = cache.store(2024, 1, "3   4\n")?;
+        assert_eq!(cached, input_path(&args, solver, Some(&cache)));
+        assert_eq!(solver.default_input(), input_path(&args, solver, None));
+
+        let args = parse_run_args(&to_args(&["2024", "1", "--input", "path"]))?;
+        assert_eq!(PathBuf::from("path"), input_path(&args, solver, Some(&cache)));
+
+        std::fs::remove_dir_all(&cache.dir)?;
+        Ok(())
+    }
+
+    #[test]
+    fn run_unknown_target() -> Result<(), Box<dyn std::error::Error>> {
+        assert!(run(&parse_run_args(&to_args(&["2023", "1"]))?).is_err());
+        assert!(run(&parse_run_args(&to_args(&["2024", "25", "2"]))?).is_err());
+        assert!(run(&parse_run_args(&to_args(&["2024", "1", "--answers", "answers.toml"]))?).is_err());
+        assert!(verify(&parse_run_args(&to_args(&["2024", "1", "--input", "path"]))?).is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn check_example() -> Result<(), Box<dyn std::error::Error>> {
+        let solvers = solvers::select(Some(1), None);
+
+        let answers = Answers::parse(&aoc_input::Input::new("answers.toml", "[day01]\n1 = \"1223326\"\n2 = \"0\"\n"))?;
+        assert_eq!(1, check(&solvers, &answers));
+
+        let answers = Answers::parse(&aoc_input::Input::new("answers.toml", "[day01]\n1 = \"1223326\"\n"))?;
+        assert_eq!(0, check(&solvers, &answers));
+        Ok(())
+    }
+}
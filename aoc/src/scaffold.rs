@@ -0,0 +1,136 @@
+use std::{fs, io, path::{Path, PathBuf}};
+
+const TEMPLATES: &[(&str, &str)] = &[
+    ("Cargo.toml", include_str!("../templates/Cargo.toml.in")),
+    ("src/lib.rs", include_str!("../templates/lib.rs.in")),
+    ("src/main.rs", include_str!("../templates/main.rs.in")),
+    ("aoc_input.txt", ""),
+    ("aoc_input_example.txt", ""),
+];
+
+#[derive(Debug)]
+pub enum ScaffoldError {
+    AlreadyExists(PathBuf),
+    NoMembers(PathBuf),
+    Io(io::Error),
+}
+impl std::fmt::Display for ScaffoldError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ScaffoldError::AlreadyExists(path) => write!(f, "Already exists: {}", path.display()),
+            ScaffoldError::NoMembers(path) => write!(f, "No workspace members list in {}", path.display()),
+            ScaffoldError::Io(err) => write!(f, "{}", err),
+        }
+    }
+}
+impl std::error::Error for ScaffoldError {}
+
+impl From<io::Error> for ScaffoldError {
+    fn from(err: io::Error) -> Self {
+        ScaffoldError::Io(err)
+    }
+}
+
+pub fn workspace_root() -> PathBuf {
+    Path::new(env!("CARGO_MANIFEST_DIR")).parent().unwrap().to_path_buf()
+}
+
+pub fn package_name(day: u8, part: &str) -> String {
+    format!("day{:02}_{}", day, part)
+}
+
+pub fn register_member(manifest: &str, package: &str) -> Option<String> {
+    let start = manifest.find("members = [")? + "members = [".len();
+    let end = start + manifest[start..].find(']')?;
+
+    let mut members = manifest[start..end].split(',')
+        .map(|member| member.trim().trim_matches('"'))
+        .filter(|member| !member.is_empty())
+        .collect::<Vec<_>>();
+    if !members.contains(&package) {
+        members.push(package);
+    }
+    members.sort();
+
+    let (days, tools): (Vec<_>, Vec<_>) = members.into_iter().partition(|member| member.starts_with("day"));
+    let lines = [tools, days].into_iter()
+        .filter(|group| !group.is_empty())
+        .map(|group| {
+            let names = group.iter().map(|member| format!("\"{}\",", member)).collect::<Vec<_>>();
+            format!("\n    {}", names.join(" "))
+        })
+        .collect::<String>();
+
+    Some(format!("{}{}\n{}", &manifest[..start], lines, &manifest[end..]))
+}
+
+pub fn create(root: &Path, day: u8, part: &str) -> Result<PathBuf, ScaffoldError> {
+    let package = package_name(day, part);
+    let dir = root.join(&package);
+    if dir.exists() {
+        return Err(ScaffoldError::AlreadyExists(dir));
+    }
+
+    let manifest_path = root.join("Cargo.toml");
+    let manifest = fs::read_to_string(&manifest_path)?;
+    let Some(manifest) = register_member(&manifest, &package) else {
+        return Err(ScaffoldError::NoMembers(manifest_path));
+    };
+
+    for (name, template) in TEMPLATES {
+        let path = dir.join(name);
+        if let Some(parent) = path.parent() {
+            fs::create_dir_all(parent)?;
+        }
+        fs::write(path, template.replace("{package}", &package))?;
+    }
+    fs::write(manifest_path, manifest)?;
+
+    Ok(dir)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::scaffold::*;
+
+    const MANIFEST: &str = "\
+[workspace]
+members = [
+    \"aoc\", \"aoc-input\",
+    \"day01_1\", \"day01_2\", \"day25_1\",
+]
+";
+
+    #[test]
+    fn register_member_example() {
+        assert_eq!(Some("\
+[workspace]
+members = [
+    \"aoc\", \"aoc-input\",
+    \"day01_1\", \"day01_2\", \"day02_1\", \"day25_1\",
+]
+".to_string()), register_member(MANIFEST, "day02_1"));
+
+        assert_eq!(Some(MANIFEST.to_string()), register_member(MANIFEST, "day01_2"));
+        assert_eq!(None, register_member("[workspace]\n", "day02_1"));
+    }
+
+    #[test]
+    fn create_example() -> Result<(), Box<dyn std::error::Error>> {
+        let root = std::env::temp_dir().join(format!("aoc-scaffold-{}", std::process::id()));
+        fs::create_dir_all(&root)?;
+        fs::write(root.join("Cargo.toml"), MANIFEST)?;
+
+        let dir = create(&root, 7, "2")?;
+        assert_eq!(root.join("day07_2"), dir);
+        assert!(fs::read_to_string(root.join("Cargo.toml"))?.contains("\"day01_2\", \"day07_2\", \"day25_1\","));
+        assert!(fs::read_to_string(dir.join("Cargo.toml"))?.contains("name = \"day07_2\""));
+        assert!(fs::read_to_string(dir.join("src/main.rs"))?.starts_with("use day07_2::solve;"));
+        assert_eq!("", fs::read_to_string(dir.join("aoc_input.txt"))?);
+
+        assert!(matches!(create(&root, 7, "2"), Err(ScaffoldError::AlreadyExists(_))));
+
+        fs::remove_dir_all(&root)?;
+        Ok(())
+    }
+}
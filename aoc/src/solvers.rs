@@ -0,0 +1,214 @@
+use std::path::{Path, PathBuf};
+
+use aoc_input::{Input, ParseError};
+
+pub type SolveFn = fn(&Path) -> Result<String, Box<dyn std::error::Error>>;
+pub type ParseFn = fn(&Path) -> Result<(), ParseError>;
+pub type PrepareFn = fn(&Path) -> Result<Prepared, Box<dyn std::error::Error>>;
+pub type Job = Box<dyn FnOnce() -> Result<String, Box<dyn std::error::Error>>>;
+
+// Parsed input paired with the day's `solve_parsed`, so solving can be
+// timed without parsing. Each job works on its own copy of the input.
+pub struct Prepared(Box<dyn Fn() -> Job>);
+
+impl Prepared {
+    pub fn new<T>(input: T, solve: fn(T) -> Result<String, Box<dyn std::error::Error>>) -> Self
+        where T: Clone + 'static
+    {
+        Self(Box::new(move || {
+            let input = input.clone();
+            Box::new(move || solve(input))
+        }))
+    }
+
+    pub fn job(&self) -> Job {
+        (self.0)()
+    }
+}
+
+pub struct Solver {
+    pub day: u8,
+    pub part: &'static str,
+    pub package: &'static str,
+    pub solve: SolveFn,
+    pub parse: ParseFn,
+    pub prepare: PrepareFn,
+}
+
+impl Solver {
+    pub fn package_dir(&self) -> PathBuf {
+        Path::new(env!("CARGO_MANIFEST_DIR")).join("..").join(self.package)
+    }
+
+    pub fn default_input(&self) -> PathBuf {
+        self.package_dir().join("aoc_input.txt")
+    }
+}
+
+pub trait Answer {
+    fn render(&self) -> String;
+}
+
+macro_rules! impl_answer_display {
+    ($($ty:ty),*) => {
+        $(
+            impl Answer for $ty {
+                fn render(&self) -> String {
+                    self.to_string()
+                }
+            }
+        )*
+    };
+}
+impl_answer_display!(i32, i64, u32, u64, usize, String);
+
+impl<T: Answer> Answer for Option<T> {
+    fn render(&self) -> String {
+        match self {
+            Some(answer) => answer.render(),
+            None => "none".into(),
+        }
+    }
+}
+
+impl Answer for (usize, usize) {
+    fn render(&self) -> String {
+        format!("{},{}", self.0, self.1)
+    }
+}
+
+macro_rules! solver {
+    (@ $day:expr, $part:expr, $package:ident, $parse:expr, $read:expr $(, $arg:expr)*) => {
+        Solver {
+            day: $day,
+            part: $part,
+            package: stringify!($package),
+            solve: |path: &Path| $package::solve(path $(, $arg)*).map(|answer| answer.render()),
+            parse: $parse,
+            prepare: |path: &Path| {
+                let input = ($read)(path)?;
+                Ok(Prepared::new(input, |input| $package::solve_parsed(input $(, $arg)*).map(|answer| answer.render())))
+            },
+        }
+    };
+    // `parse` borrows from the `Input`, which is leaked to keep the
+    // prepared input alive for the whole benchmark.
+    ($day:expr, $part:expr, &$package:ident $(, $arg:expr)*) => {
+        solver!(@ $day, $part, $package,
+            |path: &Path| Input::read_file(path).and_then(|input| $package::parse(&input).map(|_| ())),
+            |path: &Path| Input::read_file(path).and_then(|input| $package::parse(Box::leak(Box::new(input))))
+            $(, $arg)*)
+    };
+    ($day:expr, $part:expr, $package:ident = $read:expr $(, $arg:expr)*) => {
+        solver!(@ $day, $part, $package, |path: &Path| ($read)(path).map(|_| ()), $read $(, $arg)*)
+    };
+    ($day:expr, $part:expr, $package:ident $(, $arg:expr)*) => {
+        solver!(@ $day, $part, $package, |path: &Path| $package::read_file(path).map(|_| ()), $package::read_file $(, $arg)*)
+    };
+}
+
+pub static SOLVERS: &[Solver] = &[
+    solver!(1, "1", day01_1),
+    solver!(1, "2", day01_2),
+    solver!(2, "1", day02_1),
+    solver!(2, "2", day02_2),
+    solver!(3, "1", day03_1),
+    solver!(3, "2", day03_2),
+    solver!(4, "1", day04_1),
+    solver!(4, "2", day04_2),
+    solver!(5, "1", day05_1),
+    solver!(5, "2", day05_2),
+    solver!(5, "2b", day05_2b),
+    solver!(6, "1", day06_1),
+    solver!(6, "2", day06_2),
+    solver!(7, "1", day07_1),
+    solver!(7, "2", day07_2),
+    solver!(8, "1", day08_1),
+    solver!(8, "2", day08_2),
+    solver!(9, "1", day09_1),
+    solver!(9, "2", day09_2),
+    solver!(10, "1", day10_1),
+    solver!(10, "2", day10_2),
+    solver!(11, "1", day11_1),
+    solver!(11, "2", day11_2),
+    solver!(12, "1", day12_1),
+    solver!(12, "2", day12_2),
+    solver!(13, "1", day13_1 = |path: &Path| day13_1::read_machines(path, 0)),
+    solver!(13, "2", day13_2 = |path: &Path| day13_2::read_machines(path, 0)),
+    solver!(14, "1", day14_1, day14_1::Board { width: 101, height: 103 }, 100),
+    solver!(14, "2", day14_2, day14_2::Board { width: 101, height: 103 }),
+    solver!(15, "1", day15_1),
+    solver!(15, "2", day15_2),
+    solver!(16, "1", day16_1),
+    solver!(16, "2", day16_2),
+    solver!(17, "1", day17_1),
+    solver!(17, "2", day17_2),
+    solver!(18, "1", day18_1, (71, 71), 1024),
+    solver!(18, "2", day18_2, (71, 71)),
+    solver!(19, "1", &day19_1),
+    solver!(19, "2", &day19_2),
+    solver!(20, "1", day20_1, 100),
+    solver!(20, "2", day20_2, 100),
+    solver!(21, "1", day21_1),
+    solver!(21, "2", day21_2),
+    solver!(22, "1", day22_1),
+    solver!(22, "2", day22_2),
+    solver!(23, "1", &day23_1),
+    solver!(23, "2", &day23_2),
+    solver!(24, "1", day24_1),
+    solver!(24, "2", day24_2),
+    solver!(25, "1", day25_1),
+];
+
+pub fn select(day: Option<u8>, part: Option<&str>) -> Vec<&'static Solver> {
+    SOLVERS.iter()
+        .filter(|solver| day.is_none_or(|day| solver.day == day))
+        .filter(|solver| part.is_none_or(|part| solver.part == part))
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::solvers::*;
+
+    #[test]
+    fn select_example() {
+        let parts = select(Some(5), None).into_iter()
+            .map(|solver| solver.package)
+            .collect::<Vec<_>>()
+        ;
+        assert_eq!(vec!["day05_1", "day05_2", "day05_2b"], parts);
+
+        let parts = select(Some(16), Some("2")).into_iter()
+            .map(|solver| solver.package)
+            .collect::<Vec<_>>()
+        ;
+        assert_eq!(vec!["day16_2"], parts);
+
+        assert!(select(Some(25), Some("2")).is_empty());
+        assert_eq!(50, select(None, None).len());
+    }
+
+    #[test]
+    fn solve_example() -> Result<(), Box<dyn std::error::Error>> {
+        let solver = select(Some(1), Some("1"))[0];
+        let answer = (solver.solve)(&solver.package_dir().join("aoc_input_example.txt"))?;
+
+        assert_eq!("11", answer);
+
+        (solver.parse)(&solver.package_dir().join("aoc_input_example.txt"))?;
+        assert!((solver.parse)(&solver.package_dir().join("no_such_input.txt")).is_err());
+
+        let prepared = (solver.prepare)(&solver.package_dir().join("aoc_input_example.txt"))?;
+        assert_eq!("11", prepared.job()()?);
+        assert_eq!("11", prepared.job()()?);
+        Ok(())
+    }
+
+    #[test]
+    fn render_example() {
+        assert_eq!("none", None::<u64>.render());
+        assert_eq!("38,63", Some((38, 63)).render());
+        assert_eq!("6,7,5", String::from("6,7,5").render());
+    }
+}
@@ -0,0 +1,60 @@
+use std::path::Path;
+
+use aoc_input::{Input, ParseError};
+
+pub fn solve<P>(path: P) -> Result<i32, Box<dyn std::error::Error>>
+    where P: AsRef<Path>
+{
+    solve_parsed(read_file(path)?)
+}
+
+pub fn solve_parsed((mut left_entries, mut right_entries): (Vec<i32>, Vec<i32>)) -> Result<i32, Box<dyn std::error::Error>> {
+    left_entries.sort();
+    right_entries.sort();
+
+    let total = left_entries.into_iter()
+        .zip(right_entries)
+        .map(|(lhs, rhs)| (lhs - rhs).abs())
+        .sum::<i32>()
+    ;
+
+    Ok(total)
+}
+
+pub fn read_file<P>(path: P) -> Result<(Vec<i32>, Vec<i32>), ParseError> 
+    where P: AsRef<Path>
+{
+    let input = Input::read_file(path)?;
+
+    let mut left_entries = vec![];
+    let mut right_entries = vec![];
+
+    for line in input.lines() {
+        let mut iter = line.text.split_ascii_whitespace();
+
+        if let (Some(lhs), Some(rhs)) = (iter.next(), iter.next()) {
+            left_entries.push(line.parse::<i32>(lhs)?);
+            right_entries.push(line.parse::<i32>(rhs)?);
+        }
+    }
+
+    Ok((left_entries, right_entries))
+}
+
+#[cfg(test)]
+mod tests {
+    #[test]
+    fn solve_example() -> Result<(), Box<dyn std::error::Error>> {
+        let actual = crate::solve("./aoc_input_example.txt")?;
+        assert_eq!(11, actual);
+        Ok(())
+    }
+
+    #[test]
+    fn read_file_example() -> Result<(), Box<dyn std::error::Error>> {
+        let (left_entries, right_entries) = crate::read_file("./aoc_input_example.txt")?;
+        assert_eq!(vec![3, 4, 2, 1, 3, 3], left_entries);
+        assert_eq!(vec![4, 3, 5, 3, 9, 3], right_entries);
+        Ok(())
+    }
+}
\ No newline at end of file
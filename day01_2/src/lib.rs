@@ -0,0 +1,84 @@
+use std::path::Path;
+use std::collections::HashMap;
+
+use aoc_input::{Input, ParseError};
+use itertools::Itertools;
+
+#[derive(Debug)]
+pub struct GroupItem {
+    pub left_value: i32,
+    pub left_count: usize,
+    pub right_count: usize,
+}
+
+pub fn solve<P>(path: P) -> Result<i32, Box<dyn std::error::Error>>
+    where P: AsRef<Path>
+{
+    solve_parsed(read_file(path)?)
+}
+
+pub fn solve_parsed(entries: Vec<(i32, i32)>) -> Result<i32, Box<dyn std::error::Error>> {
+    let (left_entries, right_entries): (Vec<i32>, Vec<i32>) = entries.into_iter().unzip();
+
+    for (key, g) in &left_entries.iter().chunk_by(|v| *v) {
+        eprintln!("left/ k: {:?}, g: {:?}", *key, g.map(|x| *x).collect::<Vec<i32>>());
+    }
+
+    let mut groups = <HashMap::<i32, GroupItem>>::new();
+
+    for (key, g) in &left_entries.into_iter().chunk_by(|v| *v) {
+        match groups.get_mut(&key) {
+            Some(e) => {
+                e.left_count += g.count();
+            }
+            None => {
+                groups.insert(key, GroupItem { left_value: key, left_count: g.count(), right_count: 0 });
+            }
+        }
+    }
+
+    for v in right_entries {
+        if let Some(e) = groups.get_mut(&v) {
+            e.right_count += 1;
+        }
+    }
+
+    for (_, e) in &groups {
+        eprintln!("{:?}", e);
+    }
+
+    let total: i32 = groups.values()
+        .map(|e| e.left_value * (e.left_count as i32) * (e.right_count as i32))
+        .sum()
+    ;
+
+    Ok(total)
+}
+
+pub fn read_file<P>(path: P) -> Result<Vec<(i32, i32)>, ParseError>
+    where P: AsRef<Path>
+{
+    let input = Input::read_file(path)?;
+
+    let mut entries = vec![];
+    
+    for line in input.lines() {
+        let mut iter = line.text.split_ascii_whitespace();
+
+        if let (Some(lhs), Some(rhs)) = (iter.next(), iter.next()) {
+            entries.push((line.parse::<i32>(lhs)?, line.parse::<i32>(rhs)?));
+        }
+    }
+
+    Ok(entries)
+}
+
+#[cfg(test)]
+mod tests {
+    #[test]
+    fn solve_example() -> Result<(), Box<dyn std::error::Error>> {
+        let actual = crate::solve("./aoc_input_example.txt")?;
+        assert_eq!(31, actual);
+        Ok(())
+    }
+}
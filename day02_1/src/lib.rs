@@ -0,0 +1,90 @@
+use std::{mem::discriminant, path::Path};
+
+use aoc_input::{Input, ParseError};
+
+#[allow(dead_code)]
+#[derive(Debug)]
+pub enum Comparison {
+    Inv,
+    Inc(i32),
+    Dec(i32),
+}
+
+pub fn solve<P>(path: P) -> Result<i32, Box<dyn std::error::Error>>
+    where P: AsRef<Path>
+{
+    solve_parsed(read_file(path)?)
+}
+
+pub fn solve_parsed(reports: Vec<Vec<i32>>) -> Result<i32, Box<dyn std::error::Error>> {
+    let mut safe_count = 0;
+    
+    for levels in reports {
+        let adjacents = levels
+            .windows(2)
+            .map(|w| (w[0], w[1]))
+            .collect::<Vec<_>>()
+        ;
+
+        let report = adjacents.iter()
+            .map(|(lhs, rhs)| match lhs - rhs {
+                v if (1..=3).contains(&v) => Comparison::Dec(v),
+                v if (-3..=-1).contains(&v) => Comparison::Inc(v.abs()),
+                _ => Comparison::Inv,
+            })
+            .collect::<Vec<_>>()
+        ;
+        
+        if let Some(rep0) = report.first() {
+            let desc = discriminant(rep0);
+            match report.iter().skip(1).all(|rep| discriminant(rep) == desc) {
+                true if desc != discriminant(&Comparison::Inv) => {
+                    eprintln!("Safe: {:?} /judge: {:?}", adjacents, report);
+                    safe_count += 1;
+                }
+                _ => {
+                    eprintln!("Unsafe: {:?} /judge: {:?}", adjacents, report);
+                }
+            }
+        }
+    }
+
+    Ok(safe_count)
+}
+
+pub fn read_file<P>(path: P) -> Result<Vec<Vec<i32>>, ParseError> 
+    where P: AsRef<Path>
+{
+    let input = Input::read_file(path)?;
+
+    let mut reports = vec![];
+    
+    for line in input.lines() {
+        let levels = line.text.split_ascii_whitespace()
+            .map(|s| line.parse::<i32>(s))
+            .collect::<Result<Vec<_>, _>>()?
+        ;
+        reports.push(levels);
+    }
+
+    Ok(reports)
+}
+
+#[cfg(test)]
+mod tests {
+    #[test]
+    fn solve_example() -> Result<(), Box<dyn std::error::Error>> {
+        let actual = crate::solve("./aoc_input_example.txt")?;
+        assert_eq!(2, actual);
+        Ok(())
+    }
+
+    #[test]
+    fn read_file_example() -> Result<(), Box<dyn std::error::Error>> {
+        let reports = crate::read_file("./aoc_input_example.txt")?;
+        assert_eq!(6, reports.len());
+        assert_eq!(vec![7, 6, 4, 2, 1], reports[0]);
+        assert_eq!(vec![1, 3, 6, 7, 9], reports[5]);
+        Ok(())
+    }
+}
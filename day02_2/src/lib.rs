@@ -0,0 +1,103 @@
+use std::path::Path;
+
+use aoc_input::{Input, ParseError};
+
+#[derive(Debug, Hash, PartialEq, Eq, Clone)]
+pub enum Comparison {
+    Inv,
+    Inc,
+    Dec,
+}
+
+pub fn solve<P>(path: P) -> Result<i32, Box<dyn std::error::Error>>
+    where P: AsRef<Path>
+{
+    solve_parsed(read_file(path)?)
+}
+
+pub fn solve_parsed(reports: Vec<Vec<i32>>) -> Result<i32, Box<dyn std::error::Error>> {
+    let mut safe_count = 0;
+    
+    for levels in reports {
+        if judge(&levels) {
+            safe_count += 1;
+        }
+    }
+
+    Ok(safe_count)
+}
+
+pub fn read_file<P>(path: P) -> Result<Vec<Vec<i32>>, ParseError> 
+    where P: AsRef<Path>
+{
+    let input = Input::read_file(path)?;
+
+    let mut reports = vec![];
+    
+    for line in input.lines() {
+        let levels = line.text.split_ascii_whitespace()
+            .map(|s| line.parse::<i32>(s))
+            .collect::<Result<Vec<_>, _>>()?
+        ;
+        reports.push(levels);
+    }
+
+    Ok(reports)
+}
+
+pub fn judge(levels: &Vec<i32>) -> bool {
+    if judge_internal(levels) {
+        eprintln!("Safe: {:?}", &levels);
+        return true;
+    }
+
+    for i in 0..levels.len() {
+        let new_levels =
+            levels.iter().enumerate()
+            .filter_map(|(j, lv)| match i != j {
+                true => Some(*lv),
+                false => None,
+            })
+            .collect::<Vec<_>>()
+        ;
+
+        if judge_internal(&new_levels) {
+            eprintln!("Safe: {:?}", &new_levels);
+            return true;
+        }
+    }
+
+    eprintln!("Unsafe: {:?}", &levels);
+    return false;
+}
+
+pub fn judge_internal(levels: &Vec<i32>) -> bool {
+    let report = levels
+        .windows(2)
+        .map(|w| (w[0], w[1]))
+        .map(|(lhs, rhs)| match lhs - rhs {
+            v if (1..=3).contains(&v) => Comparison::Dec,
+            v if (-3..=-1).contains(&v) => Comparison::Inc,
+            _ => Comparison::Inv,
+        })
+        .collect::<Vec<_>>()
+    ;
+
+    if let Some(rep0) = report.first() {
+        if *rep0 != Comparison::Inv { 
+            return report.iter().skip(1).all(|rep| rep == rep0);
+        }
+    }
+
+    false
+}
+
+#[cfg(test)]
+mod tests {
+    #[test]
+    fn solve_example() -> Result<(), Box<dyn std::error::Error>> {
+        let actual = crate::solve("./aoc_input_example.txt")?;
+        assert_eq!(4, actual);
+        Ok(())
+    }
+}
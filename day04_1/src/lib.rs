@@ -0,0 +1,135 @@
+use std::{collections::HashSet, path::Path};
+
+use aoc_input::{Input, ParseError, ParseErrorKind};
+
+pub fn solve<P>(path: P) -> Result<usize, Box<dyn std::error::Error>>
+    where P: AsRef<Path>
+{
+    solve_parsed(read_file(path)?)
+}
+
+pub fn solve_parsed((buf, width, height): (Vec<u8>, i32, i32)) -> Result<usize, Box<dyn std::error::Error>> {
+    let mut results = HashSet::<(i32, Direction)>::new();
+
+    eprintln!("width: {}", width);
+
+    let mut i: i32 = 0;
+
+    for c in &buf {
+        if *c == b'X' { 
+            solve_internal(&mut results, &buf, width, height, i);
+        }
+        i += 1;
+    }
+
+    Ok(results.len() / 2)
+}
+
+pub fn read_file<P>(path: P) -> Result<(Vec<u8>, i32, i32), ParseError>
+    where P: AsRef<Path>
+{
+    let input = Input::read_file(path)?;
+    let mut lines = input.lines();
+
+    let first = lines.expect("puzzle")?;
+    let width = first.text.trim_end().len();
+    let mut buf = first.text.trim_end().as_bytes().to_vec();
+
+    for line in lines {
+        let s = line.text.trim_end();
+        if s.len() != width {
+            let kind = ParseErrorKind::Invalid(format!("expected row width {}, got {}", width, s.len()));
+            return Err(line.error_at(width.min(s.len()), width.abs_diff(s.len()), kind));
+        }
+        buf.extend_from_slice(s.as_bytes());
+    }
+
+    let height = buf.len() / width;
+
+    Ok((buf, width as i32, height as i32))
+}
+
+#[derive(PartialEq, Eq, Hash, Clone, Copy, Debug)]
+pub enum Direction {
+    N, S, W, E,
+    NW, NE, SW, SE,
+}
+
+impl Direction {
+    pub fn iter() -> impl Iterator<Item = Direction> {
+        [Direction::N, Direction::S, Direction::W, Direction::E, Direction::NW, Direction::NE, Direction::SW, Direction::SE].into_iter()
+    }
+
+    pub fn rev(self) -> Self {
+        match self {
+            Direction::N => Direction::S,
+            Direction::S => Direction::N,
+            Direction::W => Direction::E, 
+            Direction::E => Direction::W,
+            Direction::NW => Direction::SE,
+            Direction::NE => Direction::SW,
+            Direction::SW => Direction::NE,
+            Direction::SE => Direction::NW,        
+        }
+    }
+
+    pub fn judge(self, buf: &[u8], width: i32, height: i32, p0: i32, needle: &str) -> Option<i32> {
+        let mut p = (p0 % width, p0 / width);
+        let mut index = p0 as usize;
+
+        for c0 in needle.as_bytes().iter().skip(1) {
+            match self {
+                Direction::N => p.1 -= 1,
+                Direction::S => p.1 += 1,
+                Direction::W => p.0 -= 1, 
+                Direction::E => p.0 += 1,
+                Direction::NW => p = (p.0 - 1, p.1 - 1),
+                Direction::NE => p = (p.0 + 1, p.1 - 1),
+                Direction::SW => p = (p.0 - 1, p.1 + 1),
+                Direction::SE => p = (p.0 + 1, p.1 + 1),
+            }
+            if (p.0 < 0) || (p.1 < 0) || (p.0 >= width) || (p.1 >= height) { return None; }
+            
+            index = (p.1 * width + p.0) as usize;
+
+            if let Some(c) = buf.get(index) {
+                if c != c0 { return None; }
+            }
+        }
+
+        Some(index as i32)
+    }
+}
+
+pub fn solve_internal(results: &mut HashSet::<(i32, Direction)>, buf: &[u8], width: i32, height: i32, p0: i32) {
+    for d in Direction::iter() {
+        if results.contains(&(p0, d)) {
+            continue;
+        }
+
+        if let Some(end) = d.judge(buf, width, height, p0, "XMAS") {
+            results.insert((p0, d));
+            results.insert((end, d.rev()));
+
+            eprintln!("({}, {}) - ({}, {}) [{:?}], {}", p0 % width, p0 / width, end % width, end / width, d, p0);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    #[test]
+    fn solve_example() -> Result<(), Box<dyn std::error::Error>> {
+        let actual = crate::solve("./aoc_input_example.txt")?;
+        assert_eq!(18, actual);
+        Ok(())
+    }
+
+    #[test]
+    fn read_file_example() -> Result<(), Box<dyn std::error::Error>> {
+        let (buf, width, height) = crate::read_file("./aoc_input_example.txt")?;
+        assert_eq!((10, 10), (width, height));
+        assert_eq!(b"MMMSXXMASM", &buf[..10]);
+        Ok(())
+    }
+}
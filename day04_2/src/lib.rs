@@ -0,0 +1,111 @@
+use std::{collections::HashSet, path::Path};
+
+use aoc_input::{Input, ParseError, ParseErrorKind};
+
+pub struct Point {
+    pub x: i32,
+    pub y: i32,
+    pub width: i32,
+}
+
+impl Point {
+    pub fn from(index: i32, width: i32) -> Self {
+        Self {
+            x: index % width,
+            y: index / width,
+            width,
+        }
+    }
+
+    pub fn index(&self) -> i32 {
+        self.x + self.y * self.width
+    }
+
+    pub fn offset(&self, diff_x: i32, diff_y: i32) -> Self {
+        Self {
+            x: self.x + diff_x,
+            y: self.y + diff_y,
+            width: self.width,
+        }
+    }
+
+    pub fn clone(&self) -> Self {
+        self.offset(0, 0)
+    }
+
+    pub fn out_of_bound(&self, height: i32) -> bool {
+        self.x < 0 || self.x >= self.width || self.y < 0 || self.y >= height
+    }
+}
+
+pub fn solve<P>(path: P) -> Result<usize, Box<dyn std::error::Error>>
+    where P: AsRef<Path>
+{
+    solve_parsed(read_file(path)?)
+}
+
+pub fn solve_parsed((buf, width, height): (Vec<u8>, i32, i32)) -> Result<usize, Box<dyn std::error::Error>> {
+    let mut results = HashSet::<i32>::new();
+
+    let mut i: i32 = 0;
+
+    for c in &buf {
+        if *c == b'A' { 
+            solve_internal(&mut results, &buf, height, &Point::from(i, width));
+        }
+        i += 1;
+    }
+
+    Ok(results.len())
+}
+
+pub fn read_file<P>(path: P) -> Result<(Vec<u8>, i32, i32), ParseError>
+    where P: AsRef<Path>
+{
+    let input = Input::read_file(path)?;
+    let mut lines = input.lines();
+
+    let first = lines.expect("puzzle")?;
+    let width = first.text.trim_end().len();
+    let mut buf = first.text.trim_end().as_bytes().to_vec();
+
+    for line in lines {
+        let s = line.text.trim_end();
+        if s.len() != width {
+            let kind = ParseErrorKind::Invalid(format!("expected row width {}, got {}", width, s.len()));
+            return Err(line.error_at(width.min(s.len()), width.abs_diff(s.len()), kind));
+        }
+        buf.extend_from_slice(s.as_bytes());
+    }
+
+    let height = buf.len() / width;
+
+    Ok((buf, width as i32, height as i32))
+}
+
+pub fn solve_internal(results: &mut HashSet::<i32>, buf: &[u8], height: i32, p0: &Point) {
+    let j1 = judge(buf, height, p0.offset(-1, -1), p0.clone(), p0.offset(1, 1));
+    let j2 = judge(buf, height, p0.offset(1, -1), p0.clone(), p0.offset(-1, 1));
+
+    if j1 && j2 {
+        results.insert(p0.index());
+    }
+}
+
+pub fn judge(buf: &[u8], height: i32, p0: Point, p1: Point, p2: Point) -> bool {
+    if p0.out_of_bound(height) || p2.out_of_bound(height) { return false; }
+
+    let s = [buf[p0.index() as usize], buf[p1.index() as usize], buf[p2.index() as usize]];
+    
+    s.iter().eq("MAS".as_bytes()) || s.iter().rev().eq("MAS".as_bytes())
+}
+
+#[cfg(test)]
+mod tests {
+    #[test]
+    fn solve_example() -> Result<(), Box<dyn std::error::Error>> {
+        let actual = crate::solve("./aoc_input_example.txt")?;
+        assert_eq!(9, actual);
+        Ok(())
+    }
+}
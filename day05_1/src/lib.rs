@@ -0,0 +1,106 @@
+use std::{collections::HashSet, path::Path};
+
+use aoc_input::{Input, ParseError};
+
+pub fn solve<P>(path: P) -> Result<u32, Box<dyn std::error::Error>>
+    where P: AsRef<Path>
+{
+    solve_parsed(read_file(path)?)
+}
+
+pub fn solve_parsed((rules, pages): (HashSet<OrderingRule>, Vec<Page>)) -> Result<u32, Box<dyn std::error::Error>> {
+    let total = pages.into_iter()
+        .filter(|p| {
+            p.pages.iter().all(|pair| rules.contains(&pair))
+        })
+        .map(|p| p.middle)
+        .sum::<u32>()
+    ;
+
+    Ok(total)
+}
+
+pub type OrderingRule = (u32, u32);
+
+#[derive(Clone)]
+pub struct Page {
+    pub pages: Vec<OrderingRule>,
+    pub middle: u32,
+}
+
+pub fn read_file<P>(path: P) -> Result<(HashSet<OrderingRule>, Vec<Page>), ParseError> 
+    where P: AsRef<Path>
+{
+    let input = Input::read_file(path)?;
+    let mut lines = input.lines();
+
+    let mut rules = HashSet::<OrderingRule>::new();
+
+    for line in lines.block() {
+        let (lhs, rhs) = line.split_once(line.text.trim_end(), "|")?;
+
+        rules.insert((line.parse::<u32>(lhs)?, line.parse::<u32>(rhs)?));
+    }
+
+    let mut pages = vec![];
+
+    for line in lines {
+        let numbers = line.text.trim_end().split(',')
+            .map(|x| line.parse::<u32>(x))
+            .collect::<Result<Vec<_>, _>>()?
+        ;
+
+        pages.push(Page {
+            pages: numbers.windows(2).map(|x| (x[0], x[1])).collect::<Vec<OrderingRule>>(),
+            middle: numbers[numbers.len() / 2],
+        });
+    }
+
+    Ok((rules, pages))
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashSet;
+    use crate::*;
+
+    #[test]
+    fn solve_example() -> Result<(), Box<dyn std::error::Error>> {
+        let actual = crate::solve("./aoc_input_example.txt")?;
+        assert_eq!(143, actual);
+        Ok(())
+    }
+
+    #[test]
+    fn read_example_file() -> Result<(), Box<dyn std::error::Error>> {
+        let (rules, pages): (HashSet<OrderingRule>, Vec<Page>) = crate::read_file("./aoc_input_example.txt")?;
+
+        let expect_rules = vec![
+            (47, 53), (97, 13), (97, 61), 
+            (97, 47), (75, 29), (61, 13), 
+            (75, 53), (29, 13), (97, 29), 
+            (53, 29), (61, 53), (97, 53), 
+            (61, 29), (47, 13), (75, 47), 
+            (97, 75), (47, 61), (75, 61), 
+            (47, 29), (75, 13), (53, 13), 
+        ];
+        let expect_pages = vec![
+            vec![(75,47), (47,61), (61,53), (53,29)],
+            vec![(97,61), (61,53), (53,29), (29,13)],
+            vec![(75,29), (29,13)],
+            vec![(75,97), (97,47), (47,61), (61,53)],
+            vec![(61,13), (13,29)],
+            vec![(97,13), (13,75), (75,29), (29,47)],
+        ];
+        let expect_middles = vec![61, 53, 29, 47, 13, 75];
+
+        assert_eq!(expect_rules.len(), rules.len());
+        assert_eq!(expect_rules.into_iter().collect::<HashSet<OrderingRule>>(), rules);
+
+        assert_eq!(expect_pages.len(), pages.len());
+        assert_eq!(expect_pages, pages.iter().map(|p| p.pages.clone()).collect::<Vec<_>>());
+        assert_eq!(expect_middles, pages.iter().map(|p| p.middle).collect::<Vec<_>>());
+        Ok(())
+    }
+}
+
@@ -0,0 +1,66 @@
+use std::{cmp::Ordering, collections::HashSet, path::Path};
+
+use aoc_input::{Input, ParseError};
+
+pub fn solve<P>(path: P) -> Result<u32, Box<dyn std::error::Error>>
+    where P: AsRef<Path>
+{
+    solve_parsed(read_file(path)?)
+}
+
+pub fn solve_parsed((rules, pages): (HashSet<OrderingRule>, Vec<Page>)) -> Result<u32, Box<dyn std::error::Error>> {
+    let total = pages.into_iter()
+        .filter_map(|p| {
+            let mut sorted_numbers = p.clone();
+            sorted_numbers.sort_by(|lhs, rhs| {
+                if rules.contains(&(*lhs, *rhs)) {
+                    return Ordering::Less;
+                }
+                if rules.contains(&(*rhs, *lhs)) {
+                    return Ordering::Greater;
+                }
+
+                Ordering::Equal
+            });
+
+            match p != sorted_numbers {
+                true => Some(sorted_numbers),
+                false => None,
+            }
+        })
+        .map(|p| p[p.len() / 2])
+        .sum::<u32>()
+    ;
+
+    Ok(total)
+}
+
+pub type OrderingRule = (u32, u32);
+pub type Page  = Vec<u32>;
+
+pub fn read_file<P>(path: P) -> Result<(HashSet<OrderingRule>, Vec<Page>), ParseError> 
+    where P: AsRef<Path>
+{
+    let input = Input::read_file(path)?;
+    let mut lines = input.lines();
+
+    let mut rules = HashSet::<OrderingRule>::new();
+
+    for line in lines.block() {
+        let (lhs, rhs) = line.split_once(line.text.trim_end(), "|")?;
+
+        rules.insert((line.parse::<u32>(lhs)?, line.parse::<u32>(rhs)?));
+    }
+
+    let mut pages = vec![];
+
+    for line in lines {
+        let numbers = line.text.trim_end().split(',')
+            .map(|x| line.parse::<u32>(x))
+            .collect::<Result<Vec<_>, _>>()?
+        ;
+        pages.push(numbers);
+    }
+
+    Ok((rules, pages))
+}
@@ -0,0 +1,177 @@
+use std::path::Path;
+
+use aoc_grid::{Direction, Grid, Point};
+use aoc_input::ParseError;
+
+pub fn solve<P>(path: P) -> Result<usize, Box<dyn std::error::Error>>
+    where P: AsRef<Path>
+{
+    solve_parsed(read_file(path)?)
+}
+
+pub fn solve_parsed(map: Map) -> Result<usize, Box<dyn std::error::Error>> {
+    let mut guard = find_guard(&map)?;
+
+    let mut trace = Trace::new(map.width, map.height, (guard.x, guard.y));
+
+    loop {
+        let (next_guard, next_trace) = guard.patrol(&map, &trace);
+        trace = next_trace;
+
+        if let Some(g) = next_guard {
+            guard = g;
+        }
+        else {
+            break;
+        }
+    }
+
+    Ok(trace.count())
+}
+
+pub type Map = Grid<u8>;
+
+#[derive(Clone, Debug)]
+pub struct Trace {
+    pub marks: Grid<bool>,
+}
+
+impl Trace {
+    pub fn new(width: usize, height: usize, p: Point) -> Self {
+        let mut this = Self {
+            marks: Grid::filled(width, height, false),
+        };
+        this.mark(p);
+
+        this
+    }
+    pub fn mark(&mut self, p: Point) {
+        self.marks[p] = true;
+    }
+
+    pub fn count(&self) -> usize {
+        self.marks.cells.iter().filter(|m| **m).count()
+    }
+}
+
+#[derive(Debug)]
+pub struct Guard {
+    pub direction: Direction,
+    pub x: usize,
+    pub y: usize,
+}
+
+impl Guard {
+    pub fn patrol(&mut self, map: &Map, trace: &Trace) -> (Option<Self>, Trace) {
+        let mut next_trace = trace.clone();
+
+        let direction = self.direction.turn_right();
+
+        loop { 
+            let (x, y) = (self.x, self.y);
+            let Some((x1, y1)) = map.next((x, y), direction) else {
+                return (None, next_trace);
+            };
+
+            if map[(x1, y1)] == b'#' {
+                return (Some(Self {direction, x, y}), next_trace);
+            }
+
+            self.x = x1;
+            self.y = y1;
+            next_trace.mark((x1, y1));
+        }
+    }
+}
+
+pub fn read_file<P>(path: P) -> Result<Map, ParseError> 
+    where P: AsRef<Path>
+{
+    Grid::read_file_with(path, |_, ch| u8::try_from(ch).ok())
+}
+
+pub fn find_guard(map: &Map) -> Result<Guard, Box<dyn std::error::Error>> {
+    match map.position(|ch| *ch == b'^') {
+        Some((x, y)) => Ok(Guard { direction: Direction::W, x, y }),
+        None => Err("Guard not found".to_string().into()),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::*;
+
+    #[test]
+    fn solve_example() -> Result<(), Box<dyn std::error::Error>> {
+        let actual = crate::solve("./aoc_input_example.txt")?;
+        assert_eq!(41, actual);
+        Ok(())
+    }
+
+    #[test]
+    fn read_file_example() -> Result<(), Box<dyn std::error::Error>> {
+        let map = read_file("./aoc_input_example.txt")?;
+
+        let expect_map = vec![
+            vec![b'.', b'.', b'.', b'.', b'#', b'.', b'.', b'.', b'.', b'.', ],
+            vec![b'.', b'.', b'.', b'.', b'.', b'.', b'.', b'.', b'.', b'#', ],
+            vec![b'.', b'.', b'.', b'.', b'.', b'.', b'.', b'.', b'.', b'.', ],
+            vec![b'.', b'.', b'#', b'.', b'.', b'.', b'.', b'.', b'.', b'.', ],
+            vec![b'.', b'.', b'.', b'.', b'.', b'.', b'.', b'#', b'.', b'.', ],
+            vec![b'.', b'.', b'.', b'.', b'.', b'.', b'.', b'.', b'.', b'.', ],
+            vec![b'.', b'#', b'.', b'.', b'^', b'.', b'.', b'.', b'.', b'.', ],
+            vec![b'.', b'.', b'.', b'.', b'.', b'.', b'.', b'.', b'#', b'.', ],
+            vec![b'#', b'.', b'.', b'.', b'.', b'.', b'.', b'.', b'.', b'.', ],
+            vec![b'.', b'.', b'.', b'.', b'.', b'.', b'#', b'.', b'.', b'.', ],
+        ];
+
+        assert_eq!(expect_map, map.rows().map(|row| row.to_vec()).collect::<Vec<_>>());
+        assert_eq!(10, map.width);
+
+        let g = find_guard(&map)?;
+        assert_eq!(Direction::W, g.direction);
+        assert_eq!((4, 6), (g.x, g.y));
+        Ok(())
+    }
+
+    #[test]
+    fn next_direction() -> Result<(), Box<dyn std::error::Error>> {
+        let d = Direction::N;
+        
+        let d = d.turn_right();
+        assert_eq!(Direction::E, d);
+        let d = d.turn_right();
+        assert_eq!(Direction::S, d);
+        let d = d.turn_right();
+        assert_eq!(Direction::W, d);
+        let d = d.turn_right();
+        assert_eq!(Direction::N, d);
+
+        Ok(())
+    }
+
+    #[test]
+    fn move_guard() -> Result<(), Box<dyn std::error::Error>> {
+        let map = read_file("./aoc_input_example.txt")?;
+        let mut g = find_guard(&map)?;
+        let mut trace = Trace::new(map.width, map.height, (g.x, g.y));
+        trace.mark((g.x, g.y));
+
+        assert_eq!(Direction::W, g.direction);
+
+        let (next_g, trace) = g.patrol(&map, &trace);
+        assert!(next_g.is_some());
+        let mut g = next_g.unwrap();
+        assert_eq!(Direction::N, g.direction);
+        assert_eq!((4, 1), (g.x, g.y));
+
+        let (next_g, trace) = g.patrol(&map, &trace);
+        assert!(next_g.is_some());
+        let g = next_g.unwrap();
+        assert_eq!(Direction::E, g.direction);
+        assert_eq!((8, 1), (g.x, g.y));
+
+        assert_eq!(10, trace.count());
+        Ok(())
+    }
+}
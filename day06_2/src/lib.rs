@@ -0,0 +1,216 @@
+use std::{collections::HashSet, path::Path};
+
+use aoc_grid::{Direction, Grid, Point};
+use aoc_input::ParseError;
+
+pub fn solve<P>(path: P) -> Result<usize, Box<dyn std::error::Error>>
+    where P: AsRef<Path>
+{
+    solve_parsed(read_file(path)?)
+}
+
+pub fn solve_parsed(map: Map) -> Result<usize, Box<dyn std::error::Error>> {
+    let guard = find_guard(&map)?;
+    let routes = record_route(guard.clone(), &map);
+
+    let mut map = map;
+
+    let total = routes.into_iter()
+        .filter_map(|p| {
+            map[p] = b'#';
+            let result = replay(guard.clone(), &map);
+            map[p] = b'.';
+            
+            match result {
+                Status::Escape => None,
+                Status::Stack => {
+                    // eprintln!("(x, y) = ({}, {})", x, y);
+                    Some(1)
+                },
+            }
+        })
+        .count()
+    ;
+
+    Ok(total)
+}
+
+pub type Map = Grid<u8>;
+
+#[derive(Clone, Debug)]
+pub struct Trace {
+    pub marks: Grid<bool>,
+    pub start: Point,
+}
+
+impl Trace {
+    pub fn new(width: usize, height: usize, start: Point) -> Self {
+        Self {
+            marks: Grid::filled(width, height, false),
+            start,
+        }
+    }
+
+    pub fn mark(&mut self, p: Point) {
+        if p != self.start {
+            self.marks[p] = true;
+        }
+    }
+}
+
+#[derive(Clone, Debug)]
+pub struct Guard {
+    pub direction: Direction,
+    pub x: usize,
+    pub y: usize,
+}
+
+impl Guard {
+    pub fn patrol(&mut self, map: &Map, trace: &Trace) -> (Option<Self>, Trace) {
+        let mut next_trace = trace.clone();
+
+        let direction = self.direction.turn_right();
+
+        loop { 
+            let (x, y) = (self.x, self.y);
+            let Some((x1, y1)) = map.next((x, y), direction) else {
+                return (None, next_trace);
+            };
+
+            if map[(x1, y1)] == b'#' {
+                return (Some(Self {direction, x, y}), next_trace);
+            }
+
+            self.x = x1;
+            self.y = y1;
+            next_trace.mark((x1, y1));
+        }
+    }
+}
+
+#[derive(PartialEq, Eq, Debug)]
+pub enum Status {
+    Escape,
+    Stack,
+}
+
+pub fn read_file<P>(path: P) -> Result<Map, ParseError> 
+    where P: AsRef<Path>
+{
+    Grid::read_file_with(path, |_, ch| u8::try_from(ch).ok())
+}
+
+pub fn find_guard(map: &Map) -> Result<Guard, Box<dyn std::error::Error>> {
+    match map.position(|ch| *ch == b'^') {
+        Some((x, y)) => Ok(Guard { direction: Direction::W, x, y }),
+        None => Err("Guard not found".to_string().into()),
+    }
+}
+
+pub fn record_route(mut guard: Guard, map: &Map) -> Vec<Point> {
+    let mut trace = Trace::new(map.width, map.height, (guard.x, guard.y));
+
+    loop {
+        let (next_guard, next_trace) = guard.patrol(map, &trace);
+        trace = next_trace;
+
+        if let Some(g) = next_guard {
+            guard = g;
+        }
+        else {
+            break;
+        }
+    }
+
+    trace.marks.iter()
+        .filter(|(_, m)| **m)
+        .map(|(p, _)| p)
+        .collect()
+}
+
+pub fn replay(mut guard: Guard, map: &Map) -> Status {
+    let mut trace = Trace::new(map.width, map.height, (guard.x, guard.y));
+    let mut check_point = HashSet::<Point>::new();
+    loop {
+        let (next_guard, next_trace) = guard.clone().patrol(map, &trace);
+        trace = next_trace;
+
+        if let Some(g) = next_guard {
+            if ((g.x, g.y) != (guard.x, guard.y)) && check_point.contains(&(g.x, g.y)) {
+                return Status::Stack;
+            }
+            guard = g;
+            check_point.insert((guard.x, guard.y));
+        }
+        else {
+            return Status::Escape;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::*;
+
+    #[test]
+    fn solve_example() -> Result<(), Box<dyn std::error::Error>> {
+        let actual = solve("./aoc_input_example.txt")?;
+        assert_eq!(6, actual);
+        Ok(())
+    }
+
+    #[test]
+    fn route_example() -> Result<(), Box<dyn std::error::Error>> {
+        let map = read_file("./aoc_input_example.txt")?;
+        let guard = find_guard(&map)?;
+        let routes = record_route(guard.clone(), &map);
+        
+        let expect_routes = vec![
+            (4, 1), (5, 1), (6, 1), (7, 1), (8, 1), 
+            (4, 2), (8, 2), 
+            (4, 3), (8, 3), 
+            (2, 4), (3, 4), (4, 4), (5, 4), (6, 4), (8, 4), 
+            (2, 5), (4, 5), (6, 5), (8, 5), 
+            (2, 6), (3, 6), (5, 6), (6, 6), (7, 6), (8, 6), 
+            (1, 7), (2, 7), (3, 7), (4, 7), (5, 7), (6, 7), (7, 7), 
+            (1, 8), (2, 8), (3, 8), (4, 8), (5, 8), (6, 8), (7, 8), 
+            (7, 9), 
+        ];
+        assert_eq!(expect_routes, routes);
+        // ...#.....
+        // ....XXXXX#
+        // ....X...X.
+        // ..#.X...X.
+        // ..XXXXX#X.
+        // ..X.X.X.X.
+        // .#XX*XXXX.
+        // .XXXXXXX#.
+        // #XXXXXXX..
+        // ......#X..
+
+        Ok(())
+    }
+
+    #[test]
+    fn replay_example() -> Result<(), Box<dyn std::error::Error>> {
+        let mut map = read_file("./aoc_input_example.txt")?;
+        let guard = find_guard(&map)?;
+
+        let (x, y) = (5, 1);
+        map[(x, y)] = b'#';
+        assert_eq!(Status::Escape, replay(guard.clone(), &map));
+        map[(x, y)] = b'.';
+
+        let (x, y) = (4, 5);
+        map[(x, y)] = b'#';
+        assert_eq!(Status::Escape, replay(guard.clone(), &map));
+        map[(x, y)] = b'.';
+
+        let (x, y) = (7, 9);
+        map[(x, y)] = b'#';
+        assert_eq!(Status::Stack, replay(guard.clone(), &map));
+        map[(x, y)] = b'.';
+
+        Ok(())
+    }
+}
\ No newline at end of file
@@ -0,0 +1,136 @@
+use std::{collections::{HashMap, HashSet}, path::Path};
+
+use aoc_grid::{Grid, Point};
+use aoc_input::ParseError;
+
+pub fn solve<P>(path: P) -> Result<usize, Box<dyn std::error::Error>>
+    where P: AsRef<Path>
+{
+    solve_parsed(read_file(path)?)
+}
+
+pub fn solve_parsed((board, anntenas): (Board, HashMap<char, Vec<Point>>)) -> Result<usize, Box<dyn std::error::Error>> {
+    let antinodes = anntenas.values()
+        .flat_map(|xs| {
+            make_anntena_pair(xs).into_iter()
+            .flat_map(|(a1, a2)| put_antinode(a1, a2, &board))
+        })
+        .collect::<HashSet<Point>>()
+    ;
+    
+    Ok(antinodes.len())
+}
+
+#[allow(unused)]
+pub fn result_board(board: &Board, antinodes: &[&Point]) {
+    let marks = antinodes.iter().map(|an| **an).collect::<HashSet<Point>>();
+
+    eprint!("{}", board.render(|p, _| if marks.contains(&p) { '#' } else { '.' }));
+
+    for an in antinodes {
+        eprintln!("{:?}", an);
+    }
+}
+
+pub type Board = Grid<char>;
+
+pub fn read_file<P>(path: P) -> Result<(Board, HashMap<char, Vec<Point>>), ParseError> 
+where P: AsRef<Path>
+{
+    let board = Grid::read_file(path)?;
+    let mut anntenas = HashMap::<char, Vec<Point>>::new();
+
+    for (p, cell) in board.iter() {
+        if *cell != '.' {
+            anntenas.entry(*cell).or_insert_with(|| vec![]).push(p);
+        }
+    }
+
+    Ok((board, anntenas))
+}
+
+pub fn make_anntena_pair(anntenas: &[Point]) -> HashSet<(Point, Point)> {
+    let mut pairs = HashSet::<(Point, Point)>::new();
+
+    for a1 in anntenas {
+        for a2 in anntenas {
+            if (a1 != a2) && (!pairs.contains(&(*a2, *a1))) {
+                pairs.insert((*a1, *a2));
+            }
+        }
+    }
+    
+    pairs
+}
+
+pub fn put_antinode(a1: Point, a2: Point, board: &Board) -> Vec<Point> {
+    let distance = (a2.0 as isize - a1.0 as isize, a2.1 as isize - a1.1 as isize);
+
+    let antinodes = [
+        put_antinode_internal(a2, board, distance),
+        put_antinode_internal(a2, board, (-distance.0, -distance.1)),
+        put_antinode_internal(a1, board, distance),
+        put_antinode_internal(a1, board, (-distance.0, -distance.1)),
+    ];
+
+    antinodes.into_iter().flat_map(std::convert::identity).collect()
+}
+pub fn put_antinode_internal(anntena: Point, board: &Board, distance: (isize, isize)) -> Vec<Point> {
+    let mut last_an = board.offset(anntena, distance);
+
+    std::iter::from_fn(move || {
+        match last_an {
+            Some(an) => {
+                last_an = board.offset(an, distance);
+                Some(an)
+            }
+            None => None,
+        }
+    })
+    .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashSet;
+
+    use crate::*;
+
+    #[test]
+    fn solve_example() -> Result<(), Box<dyn std::error::Error>> {
+        let actual = solve("./aoc_input_example.txt")?;
+        assert_eq!(34, actual);
+        Ok(())
+    }
+
+    #[test]
+    fn put_antinode_example() -> Result<(), Box<dyn std::error::Error>> {
+        let pairs = vec![
+            ((8, 1), (5, 2)), // (-3, 1)
+            ((8, 1), (7, 3)), // (-1, 2)
+            ((8, 1), (4, 4)), // (-4, 3)
+        ];
+        let board = Board::filled(12, 12, '.');
+        
+        let expect_antinodes: Vec<Vec<Point>> = vec![
+            vec![(11, 0), (5, 2), (2, 3), (8, 1)], 
+            vec![(7, 3), (6, 5), (5, 7), (4, 9), (3, 11), (6, 5), (5, 7), (4, 9), (3, 11), (8, 1)], 
+            vec![(4, 4), (0, 7), (0, 7), (8, 1)], 
+        ];
+
+        assert_eq!(
+            HashSet::<Point>::from_iter(expect_antinodes[0].clone()), 
+            HashSet::<Point>::from_iter(put_antinode(pairs[0].0, pairs[0].1, &board))
+        );
+        assert_eq!(
+            HashSet::<Point>::from_iter(expect_antinodes[1].clone()), 
+            HashSet::<Point>::from_iter(put_antinode(pairs[1].0, pairs[1].1, &board))
+        );
+        assert_eq!(
+            HashSet::<Point>::from_iter(expect_antinodes[2].clone()), 
+            HashSet::<Point>::from_iter(put_antinode(pairs[2].0, pairs[2].1, &board))
+        );
+
+        Ok(())
+    }
+}
\ No newline at end of file
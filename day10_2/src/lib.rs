@@ -0,0 +1,103 @@
+use std::path::Path;
+
+use aoc_grid::{Direction, Grid, Point};
+use aoc_input::ParseError;
+
+pub fn solve<P>(path: P) -> Result<usize, Box<dyn std::error::Error>>
+    where P: AsRef<Path>
+{
+    solve_parsed(read_file(path)?)
+}
+
+pub fn solve_parsed(board: Board) -> Result<usize, Box<dyn std::error::Error>> {
+    let total = board.map.iter()
+        .filter_map(|(p, x)| match *x {
+            0 => Some(board.trail(p)),
+            _ => None,
+        })
+        .sum::<usize>()
+    ;
+
+    Ok(total)
+}
+
+#[derive(Clone)]
+pub struct Board {
+    pub map: Grid<u8>,
+}
+
+impl Board {
+    pub fn trail(&self, p0: Point) -> usize {
+        let mut total = 0;
+        trail_internal(self, 9, p0, 0, &mut total);
+
+        total
+    }
+
+    pub fn peek(&self, p: Point) -> u8 {
+        self.map[p]
+    }
+}
+
+pub fn trail_internal(board: &Board, top_peak: u8, p0: Point, current_peak: u8, total: &mut usize) {
+    for d in Direction::iter() {
+        if let Some(p) = board.map.next(p0, d) {
+            match board.peek(p) {
+                peek if (peek == top_peak) && (peek == current_peak + 1) => {
+                    *total += 1;
+                } 
+                peek if peek == current_peak + 1 => {
+                    trail_internal(board, top_peak, p, current_peak + 1, total);
+                }
+                _ => {}
+            }
+        }
+    }
+}
+
+pub fn read_file<P>(path: P) -> Result<Board, ParseError> 
+    where P: AsRef<Path>
+{
+    let map = Grid::read_file_with(path, |_, ch| ch.to_digit(10).map(|h| h as u8))?;
+
+    Ok(Board { map })
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::*;
+
+    #[test]
+    fn solve_example() -> Result<(), Box<dyn std::error::Error>> {
+        let actual = solve("./aoc_input_example.txt")?;
+        assert_eq!(81, actual);
+        Ok(())
+    }
+
+    #[test]
+    fn trail_example() -> Result<(), Box<dyn std::error::Error>> {
+        let board = Board {
+            map: Grid::new(8, 8, vec![
+                8, 9, 0, 1, 0, 1, 2, 3, 
+                7, 8, 1, 2, 1, 8, 7, 4, 
+                8, 7, 4, 3, 0, 9, 6, 5, 
+                9, 6, 5, 4, 9, 8, 7, 4, 
+                4, 5, 6, 7, 8, 9, 0, 3, 
+                3, 2, 0, 1, 9, 0, 1, 2, 
+                0, 1, 3, 2, 9, 8, 0, 1, 
+                1, 0, 4, 5, 6, 7, 3, 2, 
+            ])?,
+        };
+
+        assert_eq!(20, board.trail((2, 0)));
+        assert_eq!(24, board.trail((4, 0)));
+        assert_eq!(10, board.trail((4, 2)));
+        assert_eq!(4, board.trail((6, 4)));
+        assert_eq!(1, board.trail((2, 5)));
+        assert_eq!(4, board.trail((5, 5)));
+        assert_eq!(5, board.trail((0, 6)));
+        assert_eq!(8, board.trail((6, 6)));
+        assert_eq!(5, board.trail((1, 7)));
+        Ok(())
+    }
+}
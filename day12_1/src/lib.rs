@@ -0,0 +1,147 @@
+use std::path::Path;
+
+use aoc_grid::{Direction, Grid, Point};
+use aoc_input::ParseError;
+
+pub fn solve<P>(path: P) -> Result<usize, Box<dyn std::error::Error>>
+    where P: AsRef<Path>
+{
+    solve_parsed(read_file(path)?)
+}
+
+pub fn solve_parsed(board: Board) -> Result<usize, Box<dyn std::error::Error>> {
+    let total = eval_region(board).into_iter()
+        .map(|r| r.area * r.fence)
+        .sum::<usize>()
+    ;
+
+    Ok(total)
+}
+
+pub type Board = Grid<char>;
+
+#[derive(PartialEq, Debug)]
+pub struct Region {
+    pub area: usize,
+    pub fence: usize,
+}
+
+pub fn read_file<P>(path: P) -> Result<Board, ParseError> 
+    where P: AsRef<Path>
+{
+    Grid::read_file(path)
+}
+
+pub fn eval_region(board: Board) -> Vec<Region> {
+    let mut trace = std::iter::repeat(false).take(board.width * board.height).collect::<Vec<_>>();
+
+    board.cells.iter().enumerate()
+        .filter_map(|(i, _)| match trace[i] {
+            true => None,
+            false => Some(eval_region_internal(&board, i, &mut trace))
+        })
+        .collect()
+}
+
+pub fn eval_region_internal(board: &Board, p0: usize, trace: &mut Vec<bool>) -> Region {
+    let mut area: usize = 0;
+    let mut fence: usize = 0;
+
+    eval_region_rec(board, board.from_index(p0), trace, &mut area, &mut fence);
+
+    Region { area, fence }
+}
+
+pub fn eval_region_rec(board: &Board, p0: Point, trace: &mut Vec<bool>, acc_area: &mut usize, acc_fence: &mut usize) {
+    let index = board.to_index(p0);
+
+    let ch = board.cells[index];
+    trace[index] = true;
+    *acc_area += 1;
+
+    for d in Direction::iter() {
+        match board.next(p0, d) {
+            Some(next) if (ch == board.cells[board.to_index(next)]) => {
+                if !trace[board.to_index(next)] {
+                    eval_region_rec(board, next, trace, acc_area, acc_fence);
+                }
+            }
+            _ => {
+                *acc_fence += 1;
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::*;
+
+    #[test]
+    fn solve_example() -> Result<(), Box<dyn std::error::Error>> {
+        assert_eq!(140, solve("./aoc_input_example_1.txt")?);
+        assert_eq!(772, solve("./aoc_input_example_2.txt")?);
+        assert_eq!(1930, solve("./aoc_input_example_3.txt")?);
+        Ok(())
+    }
+
+    #[test]
+    fn read_file_example() -> Result<(), Box<dyn std::error::Error>> {
+        let board = read_file("./aoc_input_example_1.txt")?;
+        assert_eq!(4, board.width);
+        assert_eq!(4, board.height);
+        assert_eq!("AAAABBCDBBCCEEEC".chars().collect::<Vec<_>>(), board.cells);
+        Ok(())
+    }
+
+    #[test]
+    fn eval_fence_example_1() -> Result<(), Box<dyn std::error::Error>> {
+        let board = read_file("./aoc_input_example_1.txt")?;
+        let regions = eval_region(board);
+        let expect_regions = vec![
+            Region{area: 4, fence: 10},
+            Region{area: 4, fence: 8},
+            Region{area: 4, fence:  10},
+            Region{area: 1, fence: 4},
+            Region{area: 3, fence: 8},
+        ];
+        assert_eq!(expect_regions, regions);
+        Ok(())
+    }
+
+    #[test]
+    fn eval_fence_example_2() -> Result<(), Box<dyn std::error::Error>> {
+        let board = read_file("./aoc_input_example_2.txt")?;
+        let regions = eval_region(board);
+        let expect_regions = vec![
+            Region{area: 21, fence: 36},
+            Region{area: 1, fence: 4},
+            Region{area: 1, fence: 4},
+            Region{area: 1, fence: 4},
+            Region{area: 1, fence: 4},
+        ];
+        assert_eq!(expect_regions, regions);
+        Ok(())
+    }
+
+    #[test]
+    fn eval_fence_example_3() -> Result<(), Box<dyn std::error::Error>> {
+        let board = read_file("./aoc_input_example_3.txt")?;
+        let regions = eval_region(board);
+        let expect_regions = vec![
+            Region{area: 12, fence: 18},
+            Region{area: 4, fence: 8},
+            Region{area: 14, fence: 28},
+            Region{area: 10, fence: 18},
+            Region{area: 13, fence: 20},
+            Region{area: 11, fence: 20},
+            Region{area: 1, fence: 4},
+            Region{area: 13, fence: 18},
+            Region{area: 14, fence: 22},
+            Region{area: 5, fence: 12},
+            Region{area: 3, fence: 8},
+        ];
+        assert_eq!(expect_regions, regions);
+        Ok(())
+    }
+}
\ No newline at end of file
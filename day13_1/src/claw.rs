@@ -0,0 +1,596 @@
+use crate::{Button, Machine, Point, Question};
+
+#[derive(PartialEq, Debug)]
+pub struct Overflow;
+impl std::fmt::Display for Overflow {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "arithmetic overflow")
+    }
+}
+impl std::error::Error for Overflow {}
+
+#[derive(PartialEq, Debug)]
+pub enum ClawError {
+    Overflow,
+    TooManyCombinations(u128),
+}
+impl std::fmt::Display for ClawError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ClawError::Overflow => write!(f, "{}", Overflow),
+            ClawError::TooManyCombinations(count) => write!(f, "{} press combinations are too many to search, set max_presses", count),
+        }
+    }
+}
+impl std::error::Error for ClawError {}
+
+impl From<Overflow> for ClawError {
+    fn from(_: Overflow) -> Self {
+        ClawError::Overflow
+    }
+}
+
+// Upper bound on the press combinations `cheapest` enumerates.
+pub const MAX_COMBINATIONS: u128 = 1 << 24;
+
+fn mul(lhs: i128, rhs: i128) -> Result<i128, Overflow> {
+    lhs.checked_mul(rhs).ok_or(Overflow)
+}
+
+fn sub(lhs: i128, rhs: i128) -> Result<i128, Overflow> {
+    lhs.checked_sub(rhs).ok_or(Overflow)
+}
+
+fn div_ceil(n: i128, d: i128) -> i128 {
+    -(-n).div_euclid(d)
+}
+
+fn cost((count_a, count_b): Point, (cost_a, cost_b): Point) -> Result<usize, Overflow> {
+    count_a.checked_mul(cost_a)
+        .zip(count_b.checked_mul(cost_b))
+        .and_then(|(a, b)| a.checked_add(b))
+        .ok_or(Overflow)
+}
+
+// Returns `(g, x)` with `a * x ≡ g (mod b)`, `g = gcd(a, b)`.
+fn ext_gcd(a: i128, b: i128) -> (i128, i128) {
+    let (mut r0, mut r1) = (a, b);
+    let (mut x0, mut x1) = (1, 0);
+
+    while r1 != 0 {
+        let q = r0 / r1;
+        (r0, r1) = (r1, r0 - q * r1);
+        (x0, x1) = (x1, x0 - q * x1);
+    }
+
+    (r0, x0)
+}
+
+// The `t` with `k * t ≡ n (mod d)`, as `(r, m)` meaning `t ≡ r (mod m)`.
+fn congruence(k: i128, n: i128, d: i128) -> Option<(i128, i128)> {
+    let (g, x) = ext_gcd(k.rem_euclid(d), d);
+    if n % g != 0 {
+        return None;
+    }
+    let m = d / g;
+
+    Some(((x.rem_euclid(m) * (n / g).rem_euclid(m)).rem_euclid(m), m))
+}
+
+// Chinese remaindering of `t ≡ r1 (mod m1)` and `t ≡ r2 (mod m2)`.
+fn crt((r1, m1): (i128, i128), (r2, m2): (i128, i128)) -> Option<(i128, i128)> {
+    let (g, x) = ext_gcd(m1, m2);
+    if (r2 - r1) % g != 0 {
+        return None;
+    }
+    let m = m1 / g * m2;
+    let k = ((r2 - r1) / g).rem_euclid(m2 / g) * x.rem_euclid(m2 / g) % (m2 / g);
+
+    Some(((r1 + m1 * k).rem_euclid(m), m))
+}
+
+// Narrows `(lo, hi)` to the `t` with `0 <= u + v * t <= cap`.
+fn bound((lo, hi): (i128, i128), u: i128, v: i128, cap: Option<i128>) -> (i128, i128) {
+    let (min, max) = match v.signum() {
+        0 if u >= 0 && cap.is_none_or(|cap| u <= cap) => (lo, hi),
+        0 => (1, 0),
+        1 => (div_ceil(-u, v), cap.map_or(hi, |cap| (cap - u).div_euclid(v))),
+        _ => (cap.map_or(lo, |cap| div_ceil(u - cap, -v)), u.div_euclid(-v)),
+    };
+
+    (lo.max(min), hi.min(max))
+}
+
+fn most_presses((px, py): Point, (sx, sy): Point) -> usize {
+    [(px, sx), (py, sy)].into_iter()
+        .filter(|(_, step)| *step > 0)
+        .map(|(p, step)| p / step)
+        .min()
+        .unwrap_or(0)
+}
+
+fn independent((ax, ay): Point, (bx, by): Point) -> bool {
+    ax as u128 * by as u128 != ay as u128 * bx as u128
+}
+
+// Buttons from 2 up to the returned index are enumerated, the rest solved
+// exactly. A third button is exact only next to an independent pair.
+fn enumerated_end(buttons: &[&Button]) -> usize {
+    match buttons {
+        [a, b, _, ..] if independent(a.step, b.step) => buttons.len() - 1,
+        _ => buttons.len(),
+    }
+}
+
+// The independent pair of buttons at the cheapest vertex of the LP
+// relaxation, where the two reach the prize with real, non-negative
+// presses. `None` when no pair does, so no integer presses do either.
+fn vertex(buttons: &[Button], prize: Point) -> Result<Option<(usize, usize)>, Overflow> {
+    let mut cheapest = None::<((i128, i128), (usize, usize))>;
+
+    for i in 0..buttons.len() {
+        for j in i + 1..buttons.len() {
+            let (a, b) = (&buttons[i], &buttons[j]);
+            let [ax, ay, bx, by, px, py] = [a.step.0, a.step.1, b.step.0, b.step.1, prize.0, prize.1].map(|n| n as i128);
+
+            let det = sub(mul(ax, by)?, mul(ay, bx)?)?;
+            if det == 0 {
+                continue;
+            }
+            let count_a = mul(sub(mul(px, by)?, mul(py, bx)?)?, det.signum())?;
+            let count_b = mul(sub(mul(ax, py)?, mul(ay, px)?)?, det.signum())?;
+            if count_a < 0 || count_b < 0 {
+                continue;
+            }
+
+            // tokens as a fraction over |det|
+            let tokens = mul(count_a, a.cost as i128)?.checked_add(mul(count_b, b.cost as i128)?).ok_or(Overflow)?;
+            let cheaper = match cheapest {
+                Some(((fewest, d), _)) => mul(tokens, d)? < mul(fewest, det.abs())?,
+                None => true,
+            };
+            if cheaper {
+                cheapest = Some(((tokens, det.abs()), (i, j)));
+            }
+        }
+    }
+
+    Ok(cheapest.map(|(_, pair)| pair))
+}
+
+// Eisenbrand and Weismantel's proximity bound: some optimal solution lies
+// within m * (2 * m * Δ + 1)^m presses, in l1, of an optimal LP vertex, for
+// m = 2 axes and Δ the largest step. Buttons off the vertex press at most
+// that many times.
+fn proximity(buttons: &[Button]) -> usize {
+    let delta = buttons.iter().map(|button| button.step.0.max(button.step.1)).max().unwrap_or(0) as u128;
+    let side = delta.saturating_mul(4).saturating_add(1);
+
+    usize::try_from(side.saturating_mul(side).saturating_mul(2)).unwrap_or(usize::MAX)
+}
+
+// Depth-first over the presses of the enumerated buttons, each up to
+// `limit`, keeping the cheapest way to win in `best`.
+struct Search<'a> {
+    claw: &'a ClawMachine,
+    buttons: Vec<&'a Button>,
+    limit: usize,
+    presses: Vec<usize>,
+    best: Option<(usize, Vec<usize>)>,
+}
+
+impl Search<'_> {
+    fn branch(&mut self, i: usize, (px, py): Point, tokens: usize) -> Result<(), Overflow> {
+        if self.best.as_ref().is_some_and(|(fewest, _)| tokens >= *fewest) {
+            return Ok(());
+        }
+
+        let buttons = &self.buttons;
+        let end = enumerated_end(buttons);
+        if i >= end {
+            let found = match buttons.get(end) {
+                Some(c) => self.claw.triple([buttons[0], buttons[1], c], (px, py))?,
+                None => {
+                    let [(a, cost_a), (b, cost_b)] = [0, 1]
+                        .map(|i| buttons.get(i).map_or(((0, 0), 0), |button| (button.step, button.cost)));
+                    match self.claw.pair(a, b, (cost_a, cost_b), (px, py))? {
+                        Some(counts) => Some((cost(counts, (cost_a, cost_b))?, vec![counts.0, counts.1])),
+                        None => None,
+                    }
+                }
+            };
+
+            if let Some((cost, counts)) = found {
+                let tokens = tokens.checked_add(cost).ok_or(Overflow)?;
+                if self.best.as_ref().is_none_or(|(fewest, _)| tokens < *fewest) {
+                    self.presses.iter_mut().zip(&counts[..2]).for_each(|(press, count)| *press = *count);
+                    if let Some(count) = counts.get(2) {
+                        self.presses[end] = *count;
+                    }
+                    self.best = Some((tokens, self.presses.clone()));
+                }
+            }
+            return Ok(());
+        }
+
+        let button = buttons[i];
+        let (sx, sy) = button.step;
+        let most = most_presses((px, py), button.step).min(self.limit);
+
+        for count in 0..=most {
+            let tokens = count.checked_mul(button.cost).and_then(|cost| cost.checked_add(tokens)).ok_or(Overflow)?;
+            self.presses[i] = count;
+            self.branch(i + 1, (px - count * sx, py - count * sy), tokens)?;
+        }
+        self.presses[i] = 0;
+
+        Ok(())
+    }
+}
+
+// Button A and B costs, in tokens, and an optional limit on presses of
+// each button.
+#[derive(Clone, Debug)]
+pub struct ClawMachine {
+    pub costs: Point,
+    pub max_presses: Option<usize>,
+}
+
+impl Default for ClawMachine {
+    fn default() -> Self {
+        Self { costs: (3, 1), max_presses: None }
+    }
+}
+
+impl ClawMachine {
+    // The cheapest `(a, b)` press counts that land exactly on the prize.
+    pub fn presses(&self, question: &Question) -> Result<Option<Point>, Overflow> {
+        self.pair(question.a, question.b, self.costs, question.prize)
+    }
+
+    // The fewest tokens, and the presses of each button, that win the prize
+    // of a machine with any number of buttons. Two independent buttons and
+    // one more are solved exactly for every combination of presses of the
+    // others, and more than `MAX_COMBINATIONS` of those is an error. Without
+    // `max_presses`, the pair is taken from the LP optimum and the others
+    // are capped by its proximity bound, which stays small for small steps
+    // however far away the prize is.
+    pub fn cheapest(&self, machine: &Machine) -> Result<Option<(usize, Vec<usize>)>, ClawError> {
+        let mut order = (0..machine.buttons.len()).collect::<Vec<_>>();
+        let mut pair = (0..order.len())
+            .flat_map(|i| (i + 1..order.len()).map(move |j| (i, j)))
+            .find(|(i, j)| independent(machine.buttons[*i].step, machine.buttons[*j].step));
+        let mut limit = self.max_presses.unwrap_or(usize::MAX);
+        if pair.is_some() && self.max_presses.is_none() && order.len() > 3 {
+            pair = vertex(&machine.buttons, machine.prize)?;
+            if pair.is_none() {
+                return Ok(None);
+            }
+            limit = proximity(&machine.buttons);
+        }
+        if let Some((i, j)) = pair {
+            order.swap(0, i);
+            order.swap(1, j);
+        }
+
+        let buttons = order.iter().map(|i| &machine.buttons[*i]).collect::<Vec<_>>();
+        let combinations = buttons.get(2..enumerated_end(&buttons)).unwrap_or(&[]).iter()
+            .map(|button| most_presses(machine.prize, button.step).min(limit) as u128 + 1)
+            .fold(1u128, |acc, count| acc.saturating_mul(count));
+        if combinations > MAX_COMBINATIONS {
+            return Err(ClawError::TooManyCombinations(combinations));
+        }
+
+        let mut search = Search { claw: self, presses: vec![0; buttons.len()], buttons, limit, best: None };
+        search.branch(2, machine.prize, 0)?;
+
+        Ok(search.best.map(|(tokens, presses)| {
+            let mut unordered = vec![0; presses.len()];
+            order.iter().zip(presses).for_each(|(i, count)| unordered[*i] = count);
+            (tokens, unordered)
+        }))
+    }
+
+    // Two independent buttons `a`, `b` and a third `c`. By Cramer's rule the
+    // presses of `a` and `b` are affine in the presses `t` of `c`, so the
+    // valid `t` form a progression within a range and, with the cost linear
+    // in `t`, the cheapest sits at one end of it.
+    fn triple(&self, [a, b, c]: [&Button; 3], prize: Point) -> Result<Option<(usize, Vec<usize>)>, Overflow> {
+        let [ax, ay, bx, by, cx, cy, px, py] = [a.step.0, a.step.1, b.step.0, b.step.1, c.step.0, c.step.1, prize.0, prize.1]
+            .map(|n| n as i128);
+
+        let det = sub(mul(ax, by)?, mul(ay, bx)?)?;
+        let (d, sign) = (det.abs(), det.signum());
+        // count_a = (na - alpha * t) / det, count_b = (nb - beta * t) / det
+        let (na, alpha) = (sub(mul(px, by)?, mul(py, bx)?)?, sub(mul(cx, by)?, mul(cy, bx)?)?);
+        let (nb, beta) = (sub(mul(ax, py)?, mul(ay, px)?)?, sub(mul(ax, cy)?, mul(ay, cx)?)?);
+
+        let Some((r, m)) = congruence(alpha, na, d)
+            .zip(congruence(beta, nb, d))
+            .and_then(|(lhs, rhs)| crt(lhs, rhs)) else {
+            return Ok(None);
+        };
+
+        let max = self.max_presses.map(|max| max as i128);
+        let cap = match max {
+            Some(max) => Some(mul(max, d)?),
+            None => None,
+        };
+        let mut range = (0, most_presses(prize, c.step).min(self.max_presses.unwrap_or(usize::MAX)) as i128);
+        range = bound(range, mul(na, sign)?, mul(-alpha, sign)?, cap);
+        range = bound(range, mul(nb, sign)?, mul(-beta, sign)?, cap);
+
+        let (lo, hi) = range;
+        let (first, last) = (lo + (r - lo).rem_euclid(m), hi - (hi - r).rem_euclid(m));
+        if first > last {
+            return Ok(None);
+        }
+
+        let mut cheapest = None;
+        for t in [first, last] {
+            let counts = [(na - alpha * t) / det, (nb - beta * t) / det, t].map(|count| count as usize);
+            let tokens = cost((counts[0], counts[1]), (a.cost, b.cost))?
+                .checked_add(counts[2].checked_mul(c.cost).ok_or(Overflow)?)
+                .ok_or(Overflow)?;
+            if cheapest.as_ref().is_none_or(|(fewest, _)| tokens < *fewest) {
+                cheapest = Some((tokens, counts.to_vec()));
+            }
+        }
+
+        Ok(cheapest)
+    }
+
+    // Independent buttons have at most one solution, found with Cramer's
+    // rule; collinear buttons reduce to `a * u + b * v = p` on one axis.
+    fn pair(&self, (ax, ay): Point, (bx, by): Point, costs: Point, (px, py): Point) -> Result<Option<Point>, Overflow> {
+        let [ax, ay, bx, by, px, py] = [ax, ay, bx, by, px, py].map(|n| n as i128);
+
+        let det = sub(mul(ax, by)?, mul(ay, bx)?)?;
+        if det != 0 {
+            let count_a = sub(mul(px, by)?, mul(py, bx)?)?;
+            let count_b = sub(mul(ax, py)?, mul(ay, px)?)?;
+
+            if count_a % det != 0 || count_b % det != 0 {
+                return Ok(None);
+            }
+            return Ok(self.capped(count_a / det, count_b / det));
+        }
+
+        for (x, y) in [(ax, ay), (bx, by)] {
+            if sub(mul(x, py)?, mul(y, px)?)? != 0 {
+                return Ok(None);
+            }
+        }
+
+        match (ax, ay, bx, by) {
+            (0, 0, 0, 0) => Ok(self.capped(0, 0).filter(|_| (px, py) == (0, 0))),
+            (0, _, 0, _) => self.collinear(ay, by, py, costs),
+            _ => self.collinear(ax, bx, px, costs),
+        }
+    }
+
+    pub fn tokens(&self, question: &Question) -> Result<Option<usize>, Overflow> {
+        match self.presses(question)? {
+            Some(presses) => self.cost(presses).map(Some),
+            None => Ok(None),
+        }
+    }
+
+    pub fn cost(&self, presses: Point) -> Result<usize, Overflow> {
+        cost(presses, self.costs)
+    }
+
+    fn capped(&self, count_a: i128, count_b: i128) -> Option<Point> {
+        let max = self.max_presses.map_or(i128::MAX, |max| max as i128);
+
+        match (0..=max).contains(&count_a) && (0..=max).contains(&count_b) {
+            true => Some((count_a as usize, count_b as usize)),
+            false => None,
+        }
+    }
+
+    // Solves `a * u + b * v = p` for the cheapest `u, v >= 0`. Solutions
+    // step by `(b / g, -a / g)`, and the cost is linear in that step, so the
+    // optimum sits at one end of the allowed range.
+    fn collinear(&self, a: i128, b: i128, p: i128, (cost_a, cost_b): Point) -> Result<Option<Point>, Overflow> {
+        if a == 0 || b == 0 {
+            let (step, count) = (a + b, p / (a + b));
+            return Ok(match (p % step, a == 0) {
+                (0, true) => self.capped(0, count),
+                (0, false) => self.capped(count, 0),
+                _ => None,
+            });
+        }
+
+        let (g, x) = ext_gcd(a, b);
+        if p % g != 0 {
+            return Ok(None);
+        }
+        let (a, b, p) = (a / g, b / g, p / g);
+
+        // The smallest u >= 0 with a * u ≡ p (mod b); the product of two
+        // residues below 2^64 fits in u128.
+        let u0 = (x.rem_euclid(b) as u128 * p.rem_euclid(b) as u128 % b as u128) as i128;
+        let v0 = sub(p, mul(a, u0)?)? / b;
+        if v0 < 0 {
+            return Ok(None);
+        }
+
+        let mut k_range = (0, v0 / a);
+        if let Some(max) = self.max_presses.map(|max| max as i128) {
+            k_range.0 = k_range.0.max(div_ceil(v0 - max, a));
+            k_range.1 = k_range.1.min((max - u0).div_euclid(b));
+        }
+        let (k_lo, k_hi) = k_range;
+        if k_lo > k_hi {
+            return Ok(None);
+        }
+
+        let k = match sub(mul(cost_a as i128, b)?, mul(cost_b as i128, a)?)? > 0 {
+            true => k_lo,
+            false => k_hi,
+        };
+
+        Ok(self.capped(u0 + k * b, v0 - k * a))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::claw::*;
+
+    fn question(a: Point, b: Point, prize: Point) -> Question {
+        Question { a, b, prize }
+    }
+
+    #[test]
+    fn presses_example() -> Result<(), Box<dyn std::error::Error>> {
+        let machine = ClawMachine { max_presses: Some(100), ..Default::default() };
+        let questions = crate::read_file("./aoc_input_example.txt")?;
+
+        let presses = questions.iter().map(|q| machine.presses(q)).collect::<Result<Vec<_>, _>>()?;
+        assert_eq!(vec![Some((80, 40)), None, Some((38, 86)), None], presses);
+        assert_eq!(Some(280), machine.tokens(&questions[0])?);
+        Ok(())
+    }
+
+    #[test]
+    fn presses_no_underflow() {
+        let machine = ClawMachine::default();
+
+        assert_eq!(Ok(None), machine.presses(&question((62, 27), (11, 34), (13026, 6898))));
+        assert_eq!(Ok(None), machine.presses(&question((5, 1), (1, 5), (1, 1))));
+    }
+
+    #[test]
+    fn presses_collinear() {
+        let machine = ClawMachine::default();
+
+        assert_eq!(Ok(Some((0, 5))), machine.presses(&question((1, 1), (2, 2), (10, 10))));
+        assert_eq!(Ok(Some((2, 2))), machine.presses(&question((3, 3), (5, 5), (16, 16))));
+        assert_eq!(Ok(None), machine.presses(&question((3, 3), (5, 5), (7, 7))));
+        assert_eq!(Ok(None), machine.presses(&question((1, 1), (2, 2), (10, 11))));
+        assert_eq!(Ok(Some((0, 3))), machine.presses(&question((0, 0), (2, 4), (6, 12))));
+        assert_eq!(Ok(Some((0, 2))), machine.presses(&question((0, 3), (0, 6), (0, 12))));
+
+        let cheap_a = ClawMachine { costs: (1, 3), ..Default::default() };
+        assert_eq!(Ok(Some((10, 0))), cheap_a.presses(&question((1, 1), (2, 2), (10, 10))));
+        assert_eq!(Ok(Some((7, 1))), cheap_a.presses(&question((3, 3), (5, 5), (26, 26))));
+
+        let capped = ClawMachine { max_presses: Some(4), ..Default::default() };
+        assert_eq!(Ok(Some((2, 4))), capped.presses(&question((1, 1), (2, 2), (10, 10))));
+        assert_eq!(Ok(None), capped.presses(&question((1, 1), (2, 2), (20, 20))));
+    }
+
+    #[test]
+    fn cheapest_buttons() -> Result<(), Box<dyn std::error::Error>> {
+        let machines = crate::read_machines("./aoc_input_example_buttons.txt", 0)?;
+        let claw = ClawMachine::default();
+
+        let cheapest = machines.iter().map(|m| claw.cheapest(m)).collect::<Result<Vec<_>, _>>()?;
+        assert_eq!(vec![Some((280, vec![80, 40])), Some((14, vec![3, 3, 1])), Some((5, vec![5]))], cheapest);
+
+        let capped = ClawMachine { max_presses: Some(2), ..Default::default() };
+        assert_eq!(Some((14, vec![3, 3, 1])), ClawMachine { max_presses: Some(3), ..capped.clone() }.cheapest(&machines[1])?);
+        assert_eq!(None, capped.cheapest(&machines[1])?);
+        assert_eq!(Some((0, vec![])), claw.cheapest(&Machine { buttons: vec![], prize: (0, 0) })?);
+        Ok(())
+    }
+
+    fn button(name: &str, step: Point, cost: usize) -> Button {
+        Button { name: name.to_string(), step, cost }
+    }
+
+    #[test]
+    fn cheapest_offset() -> Result<(), Box<dyn std::error::Error>> {
+        let offset = 10000000000000;
+        let mut machine = Machine {
+            buttons: vec![button("A", (2, 1), 3), button("B", (1, 2), 1), button("C", (1, 1), 2)],
+            prize: (offset + 10, offset + 10),
+        };
+        let claw = ClawMachine::default();
+
+        assert_eq!(Some((13333333333348, vec![3333333333336, 3333333333336, 2])), claw.cheapest(&machine)?);
+
+        machine.buttons.insert(0, button("D", (3, 3), 1));
+        assert_eq!(Some((3333333333340, vec![3333333333336, 0, 0, 2])), claw.cheapest(&machine)?);
+
+        machine.buttons.push(button("E", (1 << 12, 1), 1));
+        assert!(matches!(claw.cheapest(&machine), Err(ClawError::TooManyCombinations(_))));
+        Ok(())
+    }
+
+    struct Rng(u64);
+
+    impl Rng {
+        fn next(&mut self, n: u64) -> usize {
+            self.0 ^= self.0 << 13;
+            self.0 ^= self.0 >> 7;
+            self.0 ^= self.0 << 17;
+            (self.0 % n) as usize
+        }
+    }
+
+    // The fewest tokens over every press count from 0 to `max` per button.
+    fn brute_force(machine: &Machine, max: usize) -> Option<usize> {
+        let mut counts = vec![0; machine.buttons.len()];
+        let mut fewest = None::<usize>;
+
+        loop {
+            let lands = (0..2).all(|axis| {
+                let step = |button: &Button| if axis == 0 { button.step.0 } else { button.step.1 };
+                let prize = if axis == 0 { machine.prize.0 } else { machine.prize.1 };
+                counts.iter().zip(&machine.buttons).map(|(count, button)| count * step(button)).sum::<usize>() == prize
+            });
+            if lands {
+                let tokens = counts.iter().zip(&machine.buttons).map(|(count, button)| count * button.cost).sum::<usize>();
+                fewest = fewest.min(Some(tokens)).or(Some(tokens));
+            }
+
+            let Some(i) = counts.iter().position(|count| *count < max) else {
+                return fewest;
+            };
+            counts[..i].iter_mut().for_each(|count| *count = 0);
+            counts[i] += 1;
+        }
+    }
+
+    #[test]
+    fn cheapest_matches_brute_force() -> Result<(), Box<dyn std::error::Error>> {
+        let mut rng = Rng(24);
+
+        for _ in 0..500 {
+            let buttons = ["A", "B", "C"].map(|name| button(name, (rng.next(5), rng.next(5)), 1 + rng.next(4)));
+            let machine = Machine { buttons: buttons.to_vec(), prize: (rng.next(25), rng.next(25)) };
+            let claw = ClawMachine { max_presses: [None, Some(3)][rng.next(2)], ..Default::default() };
+
+            let found = claw.cheapest(&machine)?;
+            assert_eq!(brute_force(&machine, claw.max_presses.unwrap_or(25)), found.as_ref().map(|(tokens, _)| *tokens), "{:?} {:?}", machine, claw);
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn cheapest_four_buttons_matches_brute_force() -> Result<(), Box<dyn std::error::Error>> {
+        let mut rng = Rng(13);
+
+        for _ in 0..300 {
+            let buttons = ["A", "B", "C", "D"].map(|name| button(name, (rng.next(4), rng.next(4)), 1 + rng.next(4)));
+            let machine = Machine { buttons: buttons.to_vec(), prize: (rng.next(13), rng.next(13)) };
+            let claw = ClawMachine::default();
+
+            let found = claw.cheapest(&machine)?;
+            assert_eq!(brute_force(&machine, 12), found.as_ref().map(|(tokens, _)| *tokens), "{:?}", machine);
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn presses_overflow() {
+        let machine = ClawMachine::default();
+
+        assert_eq!(Err(Overflow), machine.presses(&question((usize::MAX, 1), (1, usize::MAX), (1, 1))));
+        assert_eq!(Err(Overflow), machine.cost((usize::MAX, 1)));
+        assert_eq!(Ok(Some((1 << 40, 1 << 40))), machine.presses(&question((1 << 20, 0), (0, 1 << 20), (1 << 60, 1 << 60))));
+    }
+}
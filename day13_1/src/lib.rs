@@ -0,0 +1,251 @@
+pub mod claw;
+
+use std::path::Path;
+
+use aoc_input::{Input, Line, ParseError, ParseErrorKind};
+
+use claw::{ClawMachine, Overflow};
+
+pub fn solve<P>(path: P) -> Result<usize, Box<dyn std::error::Error>>
+    where P: AsRef<Path>
+{
+    solve_parsed(read_machines(path, 0)?)
+}
+
+pub fn solve_parsed(machines: Vec<Machine>) -> Result<usize, Box<dyn std::error::Error>> {
+    let claw = ClawMachine { max_presses: Some(100), ..Default::default() };
+
+    let mut total = 0;
+    for machine in &machines {
+        total += claw.cheapest(machine)?.map_or(0, |(tokens, _)| tokens);
+    }
+
+    Ok(total)
+}
+
+pub fn serve_cost(question: Question) -> Result<Vec<Cost>, Overflow> {
+    let costs = match ClawMachine::default().presses(&question)? {
+        Some((count_a, count_b)) => vec![Cost::A(count_a), Cost::B(count_b)],
+        None => vec![],
+    };
+
+    Ok(costs)
+}
+
+pub type Point = (usize, usize);
+
+#[derive(PartialEq, Clone, Copy, Debug)]
+pub struct Question {
+    pub a: Point,
+    pub b: Point,
+    pub prize: Point,
+}
+
+#[derive(PartialEq, Clone, Debug)]
+pub enum Cost {
+    A(usize),
+    B(usize),
+}
+
+// A button from a machine with any number of buttons. Without an explicit
+// `cost`, button A takes 3 tokens and every other button 1.
+#[derive(PartialEq, Clone, Debug)]
+pub struct Button {
+    pub name: String,
+    pub step: Point,
+    pub cost: usize,
+}
+
+#[derive(PartialEq, Clone, Debug)]
+pub struct Machine {
+    pub buttons: Vec<Button>,
+    pub prize: Point,
+}
+
+pub fn default_cost(name: &str) -> usize {
+    match name {
+        "A" => 3,
+        _ => 1,
+    }
+}
+
+pub fn read_file<P>(path: P) -> Result<Vec<Question>, ParseError> 
+    where P: AsRef<Path>
+{
+    let input = Input::read_file(path)?;
+    let mut lines = input.lines();
+    let mut questions = vec![];
+
+    while let Some(s_a) = lines.next() {
+        if s_a.is_empty() {
+            continue;
+        }
+
+        questions.push(Question {
+            a: parse_button(&s_a)?,
+            b: parse_button(&lines.expect("\"Button B\"")?)?,
+            prize: parse_prize(&lines.expect("\"Prize\"")?, 0)?,
+        });
+    }
+
+    Ok(questions)
+}
+
+// Reads machines with one or more `Button <name>: X+.., Y+..[, cost ..]`
+// lines followed by a `Prize` line, adding `offset` to each prize axis.
+pub fn read_machines<P>(path: P, offset: usize) -> Result<Vec<Machine>, ParseError>
+    where P: AsRef<Path>
+{
+    let input = Input::read_file(path)?;
+    let mut lines = input.lines();
+    let mut machines = vec![];
+
+    while let Some(mut line) = lines.next() {
+        if line.is_empty() {
+            continue;
+        }
+
+        let mut buttons = vec![];
+        while !line.text.starts_with("Prize") {
+            buttons.push(parse_named_button(&line)?);
+            line = lines.expect("\"Prize\"")?;
+        }
+
+        machines.push(Machine { buttons, prize: parse_prize(&line, offset)? });
+    }
+
+    Ok(machines)
+}
+
+pub fn parse_button(line: &Line) -> Result<Point, ParseError> {
+    parse_point(line, '+')
+}
+
+pub fn parse_named_button(line: &Line) -> Result<Button, ParseError> {
+    let (label, s) = line.split_once(line.text.trim_end(), ":")?;
+    let name = line.strip_prefix(label.trim(), "Button ")?.trim();
+
+    let (s, cost) = match s.rsplit_once(',') {
+        Some((s, cost)) if cost.trim().starts_with("cost") => {
+            let cost = line.strip_prefix(cost.trim(), "cost")?;
+            (s, line.parse::<usize>(cost.trim())?)
+        }
+        _ => (s, default_cost(name)),
+    };
+
+    Ok(Button { name: name.to_string(), step: parse_xy(line, s, '+')?, cost })
+}
+
+pub fn parse_prize(line: &Line, offset: usize) -> Result<Point, ParseError> {
+    let (_, s) = line.split_once(line.text.trim_end(), ":")?;
+    let (x, y) = parse_xy(line, s, '=')?;
+
+    match (x.checked_add(offset), y.checked_add(offset)) {
+        (Some(x), Some(y)) => Ok((x, y)),
+        _ => Err(line.error(s.trim(), ParseErrorKind::Invalid(format!("prize plus offset {} overflows", offset)))),
+    }
+}
+
+pub fn parse_point(line: &Line, sep: char) -> Result<Point, ParseError> {
+    let (_, s) = line.split_once(line.text.trim_end(), ":")?;
+
+    parse_xy(line, s, sep)
+}
+
+fn parse_xy<'a>(line: &Line<'a>, s: &'a str, sep: char) -> Result<Point, ParseError> {
+    let (s_x, s_y) = line.split_once(s, ",")?;
+
+    let x = line.strip_prefix(s_x.trim(), &format!("X{}", sep))?;
+    let y = line.strip_prefix(s_y.trim(), &format!("Y{}", sep))?;
+
+    Ok((line.parse::<usize>(x)?, line.parse::<usize>(y)?))
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::*;
+
+    #[test]
+    fn solve_example() -> Result<(), Box<dyn std::error::Error>> {
+        assert_eq!(480, solve("./aoc_input_example.txt")?);
+        Ok(())
+    }
+
+    #[test]
+    fn read_file_example() -> Result<(), Box<dyn std::error::Error>> {
+        let questions = read_file("./aoc_input_example.txt")?;
+
+        let expect_questions = vec![
+            Question { a: (94, 34), b: (22, 67), prize: (8400, 5400) },
+            Question { a: (26, 66), b: (67, 21), prize: (12748, 12176) },
+            Question { a: (17, 86), b: (84, 37), prize: (7870, 6450) },
+            Question { a: (69, 23), b: (27, 71), prize: (18641, 10279) },
+        ];
+
+        assert_eq!(expect_questions, questions);
+        Ok(())
+    }
+
+    #[test]
+    fn parse_button_error() {
+        let input = Input::new("example.txt", "Button A: X+94, Y+34\nButton B: X+22 Y+67\nButton A: X+94, Y-34\n");
+        let lines = input.lines().collect::<Vec<_>>();
+
+        assert_eq!(Some((94, 34)), parse_button(&lines[0]).ok());
+
+        let err = parse_button(&lines[1]).unwrap_err();
+        assert_eq!((2, 20), (err.line, err.col));
+        assert_eq!("expected \",\"", err.kind.to_string());
+
+        let err = parse_button(&lines[2]).unwrap_err();
+        assert_eq!((3, 17, 2), (err.line, err.col, err.len));
+        assert_eq!("expected \"Y+\"", err.kind.to_string());
+    }
+
+    #[test]
+    fn read_machines_example() -> Result<(), Box<dyn std::error::Error>> {
+        let machines = read_machines("./aoc_input_example_buttons.txt", 0)?;
+        let button = |name: &str, step: Point, cost: usize| Button { name: name.into(), step, cost };
+
+        assert_eq!(vec![button("A", (94, 34), 3), button("B", (22, 67), 1)], machines[0].buttons);
+        assert_eq!(vec![button("A", (2, 1), 3), button("B", (1, 2), 1), button("C", (1, 1), 2)], machines[1].buttons);
+        assert_eq!(Machine { buttons: vec![button("A", (4, 4), 1)], prize: (20, 20) }, machines[2]);
+
+        let machines = read_machines("./aoc_input_example.txt", 10000000000000)?;
+        assert_eq!((10000000008400, 10000000005400), machines[0].prize);
+        Ok(())
+    }
+
+    #[test]
+    fn parse_named_button_error() {
+        let input = Input::new("example.txt", "Button C: X+1, Y+1, cost 2\nButton C: X+1, Y+1, cost two\nButton: X+1, Y+1\nPrize: X=1, Y=1\n");
+        let lines = input.lines().collect::<Vec<_>>();
+
+        assert_eq!(Some(Button { name: "C".into(), step: (1, 1), cost: 2 }), parse_named_button(&lines[0]).ok());
+
+        let err = parse_named_button(&lines[1]).unwrap_err();
+        assert_eq!((2, 26), (err.line, err.col));
+
+        let err = parse_named_button(&lines[2]).unwrap_err();
+        assert_eq!("expected \"Button \"", err.kind.to_string());
+
+        let err = parse_prize(&lines[3], usize::MAX).unwrap_err();
+        assert_eq!((4, 8), (err.line, err.col));
+    }
+
+    #[test]
+    fn solve_cost_example() -> Result<(), Box<dyn std::error::Error>> {
+        assert_eq!(vec![Cost::A(80), Cost::B(40)], serve_cost(Question { a: (94, 34), b: (22, 67), prize: (8400, 5400) })?);
+        assert_eq!(Vec::<Cost>::new(), serve_cost(Question { a: (26, 66), b: (67, 21), prize: (12748, 12176) })?);
+        assert_eq!(vec![Cost::A(38), Cost::B(86)], serve_cost(Question { a: (17, 86), b: (84, 37), prize: (7870, 6450) })?);
+        assert_eq!(Vec::<Cost>::new(), serve_cost(Question { a: (69, 23), b: (27, 71), prize: (18641, 10279) })?);
+        Ok(())
+    }
+
+    #[test]
+    fn solve_cost_checked() -> Result<(), Box<dyn std::error::Error>> {
+        assert_eq!(Vec::<Cost>::new(), serve_cost(Question { a: (62, 27), b: (11, 34), prize: (13026, 6898) })?);
+
+        Ok(())
+    }
+}
@@ -0,0 +1,63 @@
+use std::path::Path;
+
+pub use day13_1::{claw::{ClawError, ClawMachine, Overflow}, parse_button, parse_prize, read_file, read_machines, serve_cost, Cost, Machine, Point, Question};
+
+pub const PRIZE_OFFSET: usize = 10000000000000;
+
+pub fn solve<P>(path: P) -> Result<usize, Box<dyn std::error::Error>>
+    where P: AsRef<Path>
+{
+    solve_with(path, PRIZE_OFFSET)
+}
+
+pub fn solve_with<P>(path: P, offset: usize) -> Result<usize, Box<dyn std::error::Error>>
+    where P: AsRef<Path>
+{
+    fewest_tokens(read_machines(path, 0)?, offset)
+}
+
+pub fn solve_parsed(machines: Vec<Machine>) -> Result<usize, Box<dyn std::error::Error>> {
+    fewest_tokens(machines, PRIZE_OFFSET)
+}
+
+// Moves every prize by `offset` before solving, so parsed machines stay raw.
+pub fn fewest_tokens(machines: Vec<Machine>, offset: usize) -> Result<usize, Box<dyn std::error::Error>> {
+    let claw = ClawMachine::default();
+
+    let mut fewest = 0;
+    for mut machine in machines {
+        let (Some(x), Some(y)) = (machine.prize.0.checked_add(offset), machine.prize.1.checked_add(offset)) else {
+            return Err(Box::new(Overflow));
+        };
+        machine.prize = (x, y);
+        fewest += claw.cheapest(&machine)?.map_or(0, |(tokens, _)| tokens);
+    }
+
+    Ok(fewest)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::*;
+
+    #[test]
+    fn solve_example() -> Result<(), Box<dyn std::error::Error>> {
+        assert_eq!(875318608908, solve("./aoc_input_example.txt")?);
+        Ok(())
+    }
+
+    #[test]
+    fn solve_with_offset() -> Result<(), Box<dyn std::error::Error>> {
+        assert_eq!(480, solve_with("./aoc_input_example.txt", 0)?);
+        Ok(())
+    }
+
+    #[test]
+    fn solve_cost_example() -> Result<(), Box<dyn std::error::Error>> {
+        assert_eq!(Vec::<Cost>::new(), serve_cost(Question { a: (94, 34), b: (22, 67), prize: (10000000008400, 10000000005400) })?);
+        assert_eq!(vec![Cost::A(118679050709), Cost::B(103199174542)], serve_cost(Question { a: (26, 66), b: (67, 21), prize: (10000000012748, 10000000012176) })?);
+        assert_eq!(Vec::<Cost>::new(), serve_cost(Question { a: (17, 86), b: (84, 37), prize: (10000000007870, 10000000006450) })?);
+        assert_eq!(vec![Cost::A(102851800151), Cost::B(107526881786)], serve_cost(Question { a: (69, 23), b: (27, 71), prize: (10000000018641, 10000000010279) })?);
+        Ok(())
+    }
+}
@@ -0,0 +1,194 @@
+use crate::{Board, Robot};
+
+// Scores a rendered frame (`board.render`) by how much it looks like a
+// picture rather than noise. Scores are only comparable within a detector.
+pub trait Detector {
+    fn name(&self) -> &'static str;
+    fn score(&self, board: &Board, traces: &str) -> f64;
+}
+
+pub const DETECTORS: &[&str] = &["triangle", "density", "compression", "run"];
+
+pub fn detector(name: &str) -> Option<Box<dyn Detector>> {
+    match name {
+        "triangle" => Some(Box::new(Triangle)),
+        "density" => Some(Box::new(Density::new(0.1))),
+        "compression" => Some(Box::new(Compression)),
+        "run" => Some(Box::new(LongestRun)),
+        _ => None,
+    }
+}
+
+fn rows<'a>(board: &Board, traces: &'a str) -> impl Iterator<Item = &'a [u8]> {
+    traces.as_bytes().chunks(board.width as usize)
+}
+
+// Height of the tallest solid triangle of `#`, apex up, each row two wider
+// than the one above.
+pub struct Triangle;
+
+impl Detector for Triangle {
+    fn name(&self) -> &'static str {
+        "triangle"
+    }
+
+    fn score(&self, board: &Board, traces: &str) -> f64 {
+        let w = board.width as usize;
+        let mut below = vec![0; w + 2];
+        let mut best = 0;
+
+        // `below[c + 1]` is the tallest triangle with its apex at column `c`
+        // of the row below; a triangle grows up from three of those.
+        for row in rows(board, traces).collect::<Vec<_>>().into_iter().rev() {
+            let mut heights = vec![0; w + 2];
+            for (c, cell) in row.iter().enumerate() {
+                if *cell == b'#' {
+                    heights[c + 1] = 1 + below[c].min(below[c + 1]).min(below[c + 2]);
+                }
+            }
+            best = best.max(heights.iter().copied().max().unwrap_or(0));
+            below = heights;
+        }
+
+        best as f64
+    }
+}
+
+// Share of the trimmed bounding box covered by robots, ignoring the
+// outermost `trim` fraction of robots on each side of each axis.
+pub struct Density {
+    trim: f64,
+}
+
+impl Density {
+    // `trim` is clamped to `0.0..=0.5`, where the box shrinks to the median.
+    pub fn new(trim: f64) -> Self {
+        Self { trim: if trim.is_nan() { 0.0 } else { trim.clamp(0.0, 0.5) } }
+    }
+}
+
+impl Detector for Density {
+    fn name(&self) -> &'static str {
+        "density"
+    }
+
+    fn score(&self, board: &Board, traces: &str) -> f64 {
+        let (mut xs, mut ys): (Vec<_>, Vec<_>) = rows(board, traces).enumerate()
+            .flat_map(|(y, row)| row.iter().enumerate().filter(|(_, cell)| **cell == b'#').map(move |(x, _)| (x, y)))
+            .unzip();
+        if xs.is_empty() {
+            return 0.0;
+        }
+        xs.sort();
+        ys.sort();
+
+        let cut = ((xs.len() as f64 * self.trim) as usize).min((xs.len() - 1) / 2);
+        let (x0, x1, y0, y1) = (xs[cut], xs[xs.len() - 1 - cut], ys[cut], ys[ys.len() - 1 - cut]);
+
+        let inside = rows(board, traces).enumerate()
+            .filter(|(y, _)| (y0..=y1).contains(y))
+            .map(|(_, row)| row[x0..=x1].iter().filter(|cell| **cell == b'#').count())
+            .sum::<usize>();
+
+        inside as f64 / ((x1 - x0 + 1) * (y1 - y0 + 1)) as f64
+    }
+}
+
+// Run-length compression ratio: frame size over the number of runs. Noise
+// breaks the frame into many short runs; a picture leaves long ones.
+pub struct Compression;
+
+impl Detector for Compression {
+    fn name(&self) -> &'static str {
+        "compression"
+    }
+
+    fn score(&self, board: &Board, traces: &str) -> f64 {
+        let runs = rows(board, traces)
+            .map(|row| 1 + row.windows(2).filter(|pair| pair[0] != pair[1]).count())
+            .sum::<usize>();
+
+        traces.len() as f64 / runs as f64
+    }
+}
+
+// Length of the longest horizontal run of `#`.
+pub struct LongestRun;
+
+impl Detector for LongestRun {
+    fn name(&self) -> &'static str {
+        "run"
+    }
+
+    fn score(&self, board: &Board, traces: &str) -> f64 {
+        rows(board, traces)
+            .flat_map(|row| row.split(|cell| *cell != b'#').map(|run| run.len()))
+            .max()
+            .unwrap_or(0) as f64
+    }
+}
+
+// Scores the frame at each of `times`, best first, keeping the top `count`.
+pub fn rank<I>(board: &Board, robots: &[Robot], detector: &dyn Detector, times: I, count: usize) -> Vec<(usize, f64)>
+    where I: IntoIterator<Item = usize>
+{
+    let mut scores = times.into_iter()
+        .map(|t| (t, detector.score(board, &board.render(&board.positions_at(robots, t)))))
+        .collect::<Vec<_>>();
+
+    scores.sort_by(|(t_a, a), (t_b, b)| b.total_cmp(a).then(t_a.cmp(t_b)));
+    scores.truncate(count);
+    scores
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::detect::*;
+    use crate::read_file;
+
+    const BOARD: Board = Board { width: 8, height: 7 };
+
+    fn frame(rows: &[&str]) -> String {
+        rows.concat()
+    }
+
+    #[test]
+    fn score_patterns() {
+        let tree = frame(&["........", "#.......", "........", "....#...", "...###..", "..#####.", ".#######"]);
+        let noise = frame(&["#.....#.", "..#.....", ".....#..", "#..#....", "......#.", ".#......", "....#..#"]);
+
+        assert_eq!((4.0, 1.0), (Triangle.score(&BOARD, &tree), Triangle.score(&BOARD, &noise)));
+        assert_eq!((7.0, 1.0), (LongestRun.score(&BOARD, &tree), LongestRun.score(&BOARD, &noise)));
+        assert_eq!(56.0 / 15.0, Compression.score(&BOARD, &tree));
+        assert!(Compression.score(&BOARD, &tree) > Compression.score(&BOARD, &noise));
+
+        let density = Density::new(0.0);
+        assert_eq!(17.0 / 48.0, density.score(&BOARD, &tree));
+        assert_eq!(15.0 / 24.0, Density::new(0.1).score(&BOARD, &tree));
+        assert_eq!(0.0, density.score(&BOARD, &frame(&["........"; 7])));
+        assert_eq!(17.0 / 48.0, Density::new(f64::NAN).score(&BOARD, &tree));
+        for trim in [0.5, 0.9, f64::INFINITY] {
+            assert_eq!(1.0, Density::new(trim).score(&BOARD, &tree));
+        }
+    }
+
+    #[test]
+    fn detector_by_name() {
+        for name in DETECTORS {
+            assert_eq!(Some(*name), detector(name).map(|detector| detector.name()));
+        }
+        assert!(detector("tree").is_none());
+    }
+
+    #[test]
+    fn rank_input() -> Result<(), Box<dyn std::error::Error>> {
+        let board = Board { width: 101, height: 103 };
+        let robots = read_file("./aoc_input.txt")?;
+
+        for name in DETECTORS {
+            let ranked = rank(&board, &robots, &*detector(name).unwrap(), 6800..6900, 3);
+            assert_eq!(6876, ranked[0].0, "{}: {:?}", name, ranked);
+        }
+        Ok(())
+    }
+}
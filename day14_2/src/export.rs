@@ -0,0 +1,322 @@
+use std::{io::Write, time::Duration};
+
+use crate::{Board, Robot};
+
+// One byte per pixel, 1 for a robot and 0 for an empty tile, each tile
+// blown up to `scale` x `scale` pixels.
+fn pixels(board: &Board, traces: &str, scale: usize) -> Vec<u8> {
+    traces.as_bytes()
+        .chunks(board.width as usize)
+        .flat_map(|row| {
+            let line = row.iter()
+                .flat_map(|cell| std::iter::repeat_n((*cell == b'#') as u8, scale))
+                .collect::<Vec<_>>();
+            std::iter::repeat_n(line, scale).flatten()
+        })
+        .collect()
+}
+
+fn crc32(bytes: &[u8]) -> u32 {
+    !bytes.iter().fold(!0, |crc, byte| {
+        (0..8).fold(crc ^ *byte as u32, |crc, _| match crc & 1 {
+            1 => (crc >> 1) ^ 0xEDB88320,
+            _ => crc >> 1,
+        })
+    })
+}
+
+fn adler32(bytes: &[u8]) -> u32 {
+    let (a, b) = bytes.iter().fold((1, 0), |(a, b), byte| {
+        let a = (a + *byte as u32) % 65521;
+        (a, (b + a) % 65521)
+    });
+    (b << 16) | a
+}
+
+fn png_chunk(png: &mut Vec<u8>, kind: &[u8; 4], data: &[u8]) {
+    png.extend((data.len() as u32).to_be_bytes());
+    let start = png.len();
+    png.extend(kind);
+    png.extend(data);
+    let crc = crc32(&png[start..]);
+    png.extend(crc.to_be_bytes());
+}
+
+// A grayscale PNG of one frame. The image data is stored uncompressed in
+// zlib's "stored" blocks, which keeps the encoder to a few lines.
+pub fn to_png(board: &Board, traces: &str, scale: usize) -> Vec<u8> {
+    let (w, h) = (board.width as usize * scale, board.height as usize * scale);
+
+    let raw = pixels(board, traces, scale)
+        .chunks(w)
+        .flat_map(|row| std::iter::once(0).chain(row.iter().map(|pixel| pixel * 255)))
+        .collect::<Vec<_>>();
+
+    let mut zlib = vec![0x78, 0x01];
+    let blocks = raw.chunks(u16::MAX as usize).collect::<Vec<_>>();
+    for (i, block) in blocks.iter().enumerate() {
+        let len = block.len() as u16;
+        zlib.push((i + 1 == blocks.len()) as u8);
+        zlib.extend(len.to_le_bytes());
+        zlib.extend((!len).to_le_bytes());
+        zlib.extend(*block);
+    }
+    zlib.extend(adler32(&raw).to_be_bytes());
+
+    let mut header = vec![];
+    header.extend((w as u32).to_be_bytes());
+    header.extend((h as u32).to_be_bytes());
+    header.extend([8, 0, 0, 0, 0]);
+
+    let mut png = b"\x89PNG\r\n\x1a\n".to_vec();
+    png_chunk(&mut png, b"IHDR", &header);
+    png_chunk(&mut png, b"IDAT", &zlib);
+    png_chunk(&mut png, b"IEND", &[]);
+    png
+}
+
+const GIF_MIN_CODE_SIZE: u8 = 2;
+const GIF_MAX_CODES: u16 = 4096;
+
+// Variable-width LZW as GIF wants it: codes packed LSB first, the width
+// growing with the table and the table reset once it holds 4096 codes.
+fn lzw(indices: &[u8]) -> Vec<u8> {
+    let clear = 1u16 << GIF_MIN_CODE_SIZE;
+    let mut out = vec![];
+    let (mut bits, mut count) = (0u32, 0);
+    let mut emit = |code: u16, width: u32, out: &mut Vec<u8>| {
+        bits |= (code as u32) << count;
+        count += width;
+        while count >= 8 {
+            out.push(bits as u8);
+            bits >>= 8;
+            count -= 8;
+        }
+    };
+
+    let mut table = std::collections::HashMap::new();
+    let mut width = GIF_MIN_CODE_SIZE as u32 + 1;
+    let mut next = clear + 2;
+    emit(clear, width, &mut out);
+
+    if let Some((first, rest)) = indices.split_first() {
+        let mut current = *first as u16;
+        for index in rest {
+            if let Some(code) = table.get(&(current, *index)) {
+                current = *code;
+                continue;
+            }
+
+            emit(current, width, &mut out);
+            if next == GIF_MAX_CODES {
+                emit(clear, width, &mut out);
+                table.clear();
+                width = GIF_MIN_CODE_SIZE as u32 + 1;
+                next = clear + 2;
+            } else {
+                if next >= 1 << width {
+                    width += 1;
+                }
+                table.insert((current, *index), next);
+                next += 1;
+            }
+            current = *index as u16;
+        }
+        emit(current, width, &mut out);
+    }
+
+    emit(clear + 1, width, &mut out);
+    if count > 0 {
+        out.push(bits as u8);
+    }
+    out
+}
+
+#[derive(PartialEq, Debug)]
+pub struct ImageTooLarge {
+    pub width: usize,
+    pub height: usize,
+}
+impl std::fmt::Display for ImageTooLarge {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}x{} image does not fit in a GIF, which allows at most {} pixels per side", self.width, self.height, u16::MAX)
+    }
+}
+impl std::error::Error for ImageTooLarge {}
+
+// An animated, looping GIF with one frame per entry of `frames` and `delay`
+// between frames (GIF counts in hundredths of a second).
+pub fn to_gif(board: &Board, frames: &[String], scale: usize, delay: Duration) -> Result<Vec<u8>, ImageTooLarge> {
+    let (width, height) = (board.width as usize * scale, board.height as usize * scale);
+    let (Ok(w), Ok(h)) = (u16::try_from(width), u16::try_from(height)) else {
+        return Err(ImageTooLarge { width, height });
+    };
+    let delay = (delay.as_millis() / 10).min(u16::MAX as u128) as u16;
+
+    let mut gif = b"GIF89a".to_vec();
+    gif.extend(w.to_le_bytes());
+    gif.extend(h.to_le_bytes());
+    gif.extend([0x80, 0, 0]);
+    gif.extend([0x00, 0x00, 0x00, 0x4c, 0xaf, 0x50]);
+    gif.extend(b"\x21\xff\x0bNETSCAPE2.0\x03\x01\x00\x00\x00");
+
+    for traces in frames {
+        gif.extend([0x21, 0xf9, 0x04, 0x00]);
+        gif.extend(delay.to_le_bytes());
+        gif.extend([0x00, 0x00]);
+
+        gif.push(0x2c);
+        gif.extend([0, 0, 0, 0]);
+        gif.extend(w.to_le_bytes());
+        gif.extend(h.to_le_bytes());
+        gif.push(0x00);
+
+        gif.push(GIF_MIN_CODE_SIZE);
+        for block in lzw(&pixels(board, traces, scale)).chunks(255) {
+            gif.push(block.len() as u8);
+            gif.extend(block);
+        }
+        gif.push(0x00);
+    }
+
+    gif.push(0x3b);
+    Ok(gif)
+}
+
+// Two board rows per terminal line using upper half blocks: the foreground
+// colour paints the top row and the background the bottom one.
+pub fn to_ansi(board: &Board, traces: &str) -> String {
+    let rows = traces.as_bytes().chunks(board.width as usize).collect::<Vec<_>>();
+    let color = |cell: Option<&u8>| if cell == Some(&b'#') { 2 } else { 0 };
+
+    let mut out = String::new();
+    for pair in rows.chunks(2) {
+        for x in 0..board.width as usize {
+            let (top, bottom) = (color(pair[0].get(x)), color(pair.get(1).and_then(|row| row.get(x))));
+            out.push_str(&format!("\x1b[3{};4{}m\u{2580}", top, bottom));
+        }
+        out.push_str("\x1b[0m\n");
+    }
+    out
+}
+
+// Redraws each frame of `times` in place, `delay` apart.
+pub fn play<W, I>(out: &mut W, board: &Board, robots: &[Robot], times: I, delay: Duration) -> std::io::Result<()>
+    where W: Write, I: IntoIterator<Item = usize>
+{
+    write!(out, "\x1b[2J")?;
+    for t in times {
+        let traces = board.render(&board.positions_at(robots, t));
+        writeln!(out, "\x1b[H{}t = {}", to_ansi(board, &traces), t)?;
+        out.flush()?;
+        std::thread::sleep(delay);
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::export::*;
+
+    const BOARD: Board = Board { width: 3, height: 3 };
+    const TRACES: &str = "#....#.#.";
+
+    // Undoes `lzw`, to check it round-trips.
+    fn unlzw(data: &[u8]) -> Vec<u8> {
+        let clear = 1u16 << GIF_MIN_CODE_SIZE;
+        let (mut pos, mut width) = (0, GIF_MIN_CODE_SIZE as usize + 1);
+        let mut read = |width: usize| {
+            let code = (0..width).fold(0, |code, i| code | (((data[(pos + i) / 8] >> ((pos + i) % 8)) & 1) as u16) << i);
+            pos += width;
+            code
+        };
+
+        let mut table: Vec<Vec<u8>> = vec![];
+        let mut out = vec![];
+        let mut prev: Option<Vec<u8>> = None;
+        loop {
+            let code = read(width);
+            if code == clear {
+                table = (0..clear).map(|i| vec![i as u8]).chain([vec![], vec![]]).collect();
+                width = GIF_MIN_CODE_SIZE as usize + 1;
+                prev = None;
+                continue;
+            }
+            if code == clear + 1 {
+                return out;
+            }
+
+            let entry = match (table.get(code as usize), &prev) {
+                (Some(entry), _) => entry.clone(),
+                (None, Some(prev)) => [prev.clone(), vec![prev[0]]].concat(),
+                (None, None) => panic!("bad code {}", code),
+            };
+            out.extend(&entry);
+            if let Some(prev) = prev {
+                table.push([prev, vec![entry[0]]].concat());
+            }
+            if table.len() == 1 << width && width < 12 {
+                width += 1;
+            }
+            prev = Some(entry);
+        }
+    }
+
+    #[test]
+    fn checksums() {
+        assert_eq!(0xCBF43926, crc32(b"123456789"));
+        assert_eq!(0x11E60398, adler32(b"Wikipedia"));
+    }
+
+    #[test]
+    fn png_frame() {
+        let png = to_png(&BOARD, TRACES, 2);
+
+        assert_eq!(b"\x89PNG\r\n\x1a\n", &png[..8]);
+        assert_eq!(b"IHDR\x00\x00\x00\x06\x00\x00\x00\x06\x08\x00", &png[12..26]);
+        assert_eq!(b"IEND\xae\x42\x60\x82", &png[png.len() - 8..]);
+
+        // Stored block: 6 rows of a filter byte and 6 pixels.
+        let idat = &png[37..];
+        assert_eq!((b"IDAT".as_slice(), 1, 42), (&idat[..4], idat[6], u16::from_le_bytes([idat[7], idat[8]])));
+        assert_eq!(&[0, 255, 255, 0, 0, 0, 0], &idat[11..18]);
+    }
+
+    #[test]
+    fn gif_frames() -> Result<(), Box<dyn std::error::Error>> {
+        let gif = to_gif(&BOARD, &[TRACES.to_string(), ".........".to_string()], 2, Duration::from_millis(250))?;
+
+        assert_eq!(b"GIF89a\x06\x00\x06\x00\x80", &gif[..11]);
+        assert_eq!(2, gif.windows(4).filter(|w| *w == [0x21, 0xf9, 0x04, 0x00]).count());
+        assert_eq!(Some(&0x3b), gif.last());
+        assert_eq!(&[25, 0], &gif[gif.windows(4).position(|w| w == [0x21, 0xf9, 0x04, 0x00]).unwrap() + 4..][..2]);
+
+        assert_eq!(Err(ImageTooLarge { width: 65538, height: 65538 }), to_gif(&BOARD, &[], 21846, Duration::ZERO).map(|_| ()));
+        Ok(())
+    }
+
+    #[test]
+    fn lzw_round_trip() {
+        let pixels = pixels(&BOARD, TRACES, 2);
+        assert_eq!(pixels, unlzw(&lzw(&pixels)));
+
+        // Long enough to fill the table and force a reset.
+        let mut state = 24u32;
+        let noise = (0..20_000).map(|_| {
+            state = state.wrapping_mul(1103515245).wrapping_add(12345);
+            ((state >> 16) % 4) as u8
+        }).collect::<Vec<_>>();
+        assert_eq!(noise, unlzw(&lzw(&noise)));
+        assert_eq!(Vec::<u8>::new(), unlzw(&lzw(&[])));
+    }
+
+    #[test]
+    fn ansi_frame() {
+        let ansi = to_ansi(&BOARD, TRACES);
+        let lines = ansi.lines().collect::<Vec<_>>();
+
+        assert_eq!(2, lines.len());
+        assert_eq!("\x1b[32;40m\u{2580}\x1b[30;40m\u{2580}\x1b[30;42m\u{2580}\x1b[0m", lines[0]);
+        assert_eq!("\x1b[30;40m\u{2580}\x1b[32;40m\u{2580}\x1b[30;40m\u{2580}\x1b[0m", lines[1]);
+    }
+}
@@ -0,0 +1,242 @@
+pub mod detect;
+pub mod export;
+
+use std::path::Path;
+
+use aoc_input::{Input, Line, ParseError};
+
+use detect::{Detector, Triangle};
+
+pub fn solve<P>(path: P, board: Board) -> Result<usize, Box<dyn std::error::Error>>
+    where P: AsRef<Path>
+{
+    solve_parsed(read_file(path)?, board)
+}
+
+pub fn solve_parsed(robots: Vec<Robot>, board: Board) -> Result<usize, Box<dyn std::error::Error>> {
+    Ok(most_clustered(&board, &robots))
+}
+
+pub struct Board {
+    pub width: isize,
+    pub height: isize,
+}
+
+impl Board {
+    pub fn to_index(&self, (x, y): (isize, isize)) -> usize {
+        (x + y * self.width) as usize
+    }
+
+    // Every robot is back where it started after this many seconds.
+    pub fn period(&self) -> usize {
+        let (w, h) = (self.width as usize, self.height as usize);
+        w / gcd(w, h) * h
+    }
+
+    // Where `robot` is after `t` seconds. Each axis repeats with the board
+    // size, so `t` is reduced first and any `t` is fine.
+    pub fn position_at(&self, robot: &Robot, t: usize) -> (isize, isize) {
+        let axis = |p: isize, v: isize, size: isize| {
+            let t = (t % size as usize) as isize;
+            (p + v.rem_euclid(size) * t).rem_euclid(size)
+        };
+
+        (axis(robot.position.0, robot.velocity.0, self.width), axis(robot.position.1, robot.velocity.1, self.height))
+    }
+
+    pub fn positions_at(&self, robots: &[Robot], t: usize) -> Vec<(isize, isize)> {
+        robots.iter().map(|robot| self.position_at(robot, t)).collect()
+    }
+
+    pub fn render(&self, positions: &[(isize, isize)]) -> String {
+        let mut traces = vec![b'.'; self.width as usize * self.height as usize];
+        for position in positions {
+            traces[self.to_index(*position)] = b'#';
+        }
+
+        String::from_utf8(traces).unwrap_or_default()
+    }
+}
+
+#[derive(PartialEq, Clone, Debug)]
+pub struct Robot {
+    pub position: (isize, isize),
+    pub velocity: (isize, isize),
+}
+
+pub fn read_file<P>(path: P) -> Result<Vec<Robot>, ParseError> 
+    where P: AsRef<Path>
+{
+    let input = Input::read_file(path)?;
+    
+    input.lines()
+        .map(|line| parse_line(&line))
+        .collect()
+}
+
+pub fn parse_line(line: &Line) -> Result<Robot, ParseError>  {
+    let (p, v) = line.split_once(line.text.trim(), " ")?;
+
+    let position = parse_line_internal(line, line.strip_prefix(p, "p=")?)?;
+    let velocity = parse_line_internal(line, line.strip_prefix(v.trim(), "v=")?)?;
+
+    Ok(Robot { position, velocity })
+}
+
+pub fn parse_line_internal<'a>(line: &Line<'a>, pair: &'a str) -> Result<(isize, isize), ParseError>  {
+    let (x, y) = line.split_once(pair, ",")?;
+
+    Ok((line.parse::<isize>(x)?, line.parse::<isize>(y)?))
+}
+
+fn gcd(a: usize, b: usize) -> usize {
+    match b {
+        0 => a,
+        _ => gcd(b, a % b),
+    }
+}
+
+// The smallest `t` with `t ≡ a (mod m)` and `t ≡ b (mod n)`, if any.
+pub fn crt(a: usize, m: usize, b: usize, n: usize) -> Option<usize> {
+    let g = gcd(m, n);
+    let (a, b) = (a % m, b % n);
+    if a % g != b % g {
+        return None;
+    }
+
+    // t = a + m * k with (m / g) * k ≡ (b - a) / g (mod n / g).
+    let (m_g, n_g) = (m / g, n / g);
+    let diff = (b + n - a % n) % n / g;
+    let k = (1..=n_g).find(|inv| m_g * inv % n_g == 1 % n_g)? * diff % n_g;
+
+    Some(a + m * k)
+}
+
+// Spread of `values` around their mean, scaled by `count^2` to stay in integers.
+fn spread(values: impl Iterator<Item = isize>) -> isize {
+    let (n, sum, squares) = values.fold((0, 0, 0), |(n, sum, squares), v| (n + 1, sum + v, squares + v * v));
+    n * squares - sum * sum
+}
+
+// The time within one period at which the robots are packed tightest. X
+// positions repeat every `width` seconds and y positions every `height`,
+// so each axis is minimised on its own and the two times are joined with
+// the CRT. Boards whose axes share a factor may have no joint time, in
+// which case the whole period is scanned.
+pub fn most_clustered(board: &Board, robots: &[Robot]) -> usize {
+    let spread_at = |t: usize, axis: fn((isize, isize)) -> isize| {
+        spread(robots.iter().map(|robot| axis(board.position_at(robot, t))))
+    };
+    let min_by_spread = |times: std::ops::Range<usize>, axis: fn((isize, isize)) -> isize| {
+        times.min_by_key(|t| spread_at(*t, axis)).unwrap_or(0)
+    };
+
+    let t_x = min_by_spread(0..board.width as usize, |(x, _)| x);
+    let t_y = min_by_spread(0..board.height as usize, |(_, y)| y);
+
+    crt(t_x, board.width as usize, t_y, board.height as usize)
+        .unwrap_or_else(|| {
+            (0..board.period())
+                .min_by_key(|t| spread_at(*t, |(x, _)| x) + spread_at(*t, |(_, y)| y))
+                .unwrap_or(0)
+        })
+}
+
+// A solid triangle with a base of at least five robots.
+pub fn is_tree_drawn(board: &Board, traces: &str) -> bool {
+    Triangle.score(board, traces) >= 3.0
+}
+
+pub fn dump_trace(board: &Board, traces: &str, time: usize) {
+    let w = board.width as usize;
+
+    eprintln!("\n#{} >>>", time);    
+
+    for r in 0..board.height as usize {
+        eprintln!("{}", &traces[(r * w)..((r + 1) * w)]);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::*;
+
+    #[test]
+    fn solve_input() -> Result<(), Box<dyn std::error::Error>> {
+        assert_eq!(6876, solve("./aoc_input.txt", Board { width: 101, height: 103 })?);
+        Ok(())
+    }
+
+    #[test]
+    fn position_at_example() {
+        let board = Board { width: 11, height: 7 };
+        let robot = Robot { position: (2, 4), velocity: (2, -3) };
+
+        let positions = (0..=5).map(|t| board.position_at(&robot, t)).collect::<Vec<_>>();
+        assert_eq!(vec![(2, 4), (4, 1), (6, 5), (8, 2), (10, 6), (1, 3)], positions);
+
+        assert_eq!(77, board.period());
+        assert_eq!(board.position_at(&robot, 5), board.position_at(&robot, 5 + 77 * 1_000_000_000_000));
+        assert_eq!(board.position_at(&robot, 3), board.position_at(&Robot { velocity: (2 - 22, -3 + 70), ..robot }, 3));
+        assert_eq!(12, Board { width: 4, height: 6 }.period());
+    }
+
+    #[test]
+    fn crt_example() {
+        assert_eq!(Some(23), crt(2, 3, 3, 5).and_then(|t| crt(t, 15, 2, 7)));
+        assert_eq!(Some(6876), crt(6876 % 101, 101, 6876 % 103, 103));
+        assert_eq!(Some(10), crt(2, 4, 4, 6));
+        assert_eq!(None, crt(1, 4, 2, 6));
+        assert_eq!(Some(0), crt(0, 1, 0, 1));
+    }
+
+    #[test]
+    fn most_clustered_example() {
+        let board = Board { width: 11, height: 7 };
+        let velocities = [(1, 2), (-3, 1), (4, -2), (2, 3), (-1, -1), (5, 0), (0, 4)];
+        let time: isize = 41;
+
+        // Robots that all meet at (5, 3) after `time` seconds.
+        let robots = velocities.iter()
+            .map(|&(v_x, v_y)| Robot {
+                position: ((5 - v_x * time).rem_euclid(11), (3 - v_y * time).rem_euclid(7)),
+                velocity: (v_x, v_y),
+            })
+            .collect::<Vec<_>>();
+
+        assert_eq!(time as usize, most_clustered(&board, &robots));
+        assert!(board.positions_at(&robots, time as usize).iter().all(|p| *p == (5, 3)));
+
+        let board = Board { width: 4, height: 6 };
+        let robots = vec![Robot { position: (1, 0), velocity: (1, 1) }, Robot { position: (3, 2), velocity: (1, 1) }];
+        assert_eq!(0, most_clustered(&board, &robots));
+    }
+
+    #[test]
+    fn is_tree_drawn_example() -> Result<(), Box<dyn std::error::Error>> {
+        let board = Board{ width: 8, height: 7};
+        let pattern = format!("{}{}{}{}{}{}{}",
+            "........",
+            "........",
+            "........",
+            "....#...",
+            "...###..",
+            "..#####.",
+            ".#######",
+        );
+        assert_eq!(true, is_tree_drawn(&board, &pattern));
+
+        let pattern = format!("{}{}{}{}{}{}{}",
+            "........",
+            "........",
+            "........",
+            "....#...",
+            "...###..",
+            "....#...",
+            "...###..",
+        );
+        assert_eq!(false, is_tree_drawn(&board, &pattern));
+
+        Ok(())
+    }
+}
\ No newline at end of file
@@ -0,0 +1,335 @@
+use std::path::Path;
+
+use aoc_grid::{Direction, Grid, Point};
+use aoc_input::{Input, Lines, ParseError, ParseErrorKind};
+
+pub fn solve<P>(path: P) -> Result<usize, Box<dyn std::error::Error>>
+    where P: AsRef<Path>
+{
+    solve_parsed(read_file(path)?)
+}
+
+pub fn solve_parsed((p0, mut board, moves): (Point, Board, Vec<Direction>)) -> Result<usize, Box<dyn std::error::Error>> {
+    board.move_robot(p0, &moves);
+
+    let total = board.map.iter()
+        .filter_map(|(p, obj)| match obj {
+            Object::Box => Some(p),
+            Object::Floor | Object::Wall => None,
+        })
+        .map(|(x, y)| x + 100 * y)
+        .sum::<usize>()
+    ;
+
+    Ok(total)
+}
+
+#[derive(Clone)]
+pub struct Board {
+    pub map: Grid<Object>,
+}
+
+impl Board {
+    pub fn move_robot(&mut self, mut p0: Point, moves: &[Direction]) {
+        for d in moves {
+            if let Some(p) = self.move_robot_internal(p0, *d) {
+                p0 = p;
+            }
+        }
+    } 
+
+    pub fn move_robot_internal(&mut self, p0: Point, d: Direction) -> Option<Point> {
+        let Some(p) = self.map.next(p0, d) else {
+            return None;
+        };
+
+        match self.map[p] {
+            Object::Box => {
+                if self.try_push_box(p, &d).is_none() {
+                    return None;
+                }
+            },
+            Object::Floor => {},
+            Object::Wall => return None,
+        }
+
+        Some(p)
+    }
+
+    pub fn try_push_box(&mut self, p0: Point, d: &Direction) -> Option<Point> {
+        let Some(p) = self.map.next(p0, *d) else {
+            return None;
+        };
+
+        match self.map[p] {
+            Object::Box => {
+                if self.try_push_box(p, d).is_none() {
+                    return None;
+                };
+            },
+            Object::Floor => {}
+            Object::Wall => return None,
+        }
+
+        self.map.swap(p0, p);
+
+        Some(p)
+    } 
+}
+
+#[derive(PartialEq, Clone, Debug)]
+pub enum Object {
+    Floor,
+    Box,
+    Wall,
+}
+
+pub fn read_file<P>(path: P) -> Result<(Point, Board, Vec<Direction>), ParseError> 
+    where P: AsRef<Path>
+{
+    let input = Input::read_file(path)?;
+    let mut lines = input.lines();
+
+    let (p0, board) = read_map(&mut lines)?;
+    let moves = read_moves(&mut lines)?;
+
+    Ok((p0, board, moves))
+}
+
+pub fn read_map(lines: &mut Lines<'_>) -> Result<(Point, Board), ParseError> {
+    let mut p0 = Option::<Point>::None;
+
+    let map = Grid::read_lines(lines, |p, ch| match ch {
+        '#' => Some(Object::Wall),
+        '@' => {
+            p0 = Some(p);
+            Some(Object::Floor)
+        }
+        '.' => Some(Object::Floor),
+        'O' => Some(Object::Box),
+        _ => None,
+    })?;
+
+    let board = Board { map };
+
+    match p0 { 
+        Some(p) => Ok((p, board)),
+        None => Err(lines.error(ParseErrorKind::Expected("robot '@' in map".to_string()))),
+    }
+}
+
+pub fn read_moves(lines: &mut Lines<'_>) -> Result<Vec<Direction>, ParseError> {
+    let mut moves = vec![];
+
+    for line in lines {
+        for (i, ch) in line.text.chars().enumerate() {
+            moves.push(match ch {
+                '^' => Direction::N,
+                '>' => Direction::E,
+                'v' => Direction::S,
+                '<' => Direction::W,
+                _ => return Err(line.error_at(i, 1, ParseErrorKind::UnexpectedChar(ch))),
+            });
+        }
+    }
+
+    Ok(moves)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::*;
+
+    #[test]
+    fn solve_example() -> Result<(), Box<dyn std::error::Error>> {
+        assert_eq!(2028, solve("./aoc_input_example_1.txt")?);
+        assert_eq!(10092, solve("./aoc_input_example_2.txt")?);
+        Ok(())
+    }
+
+    #[test]
+    fn read_file_example() -> Result<(), Box<dyn std::error::Error>> {
+        let (p0, board, moves) = read_file("aoc_input_example_1.txt")?;
+
+        assert_eq!(8, board.map.width);
+        assert_eq!(8, board.map.height);
+
+        assert_eq!((2, 2), p0);
+
+        let expect_map = vec![
+            Object::Wall, Object::Wall, Object::Wall, Object::Wall, Object::Wall, Object::Wall, Object::Wall, Object::Wall, 
+            Object::Wall, Object::Floor, Object::Floor, Object::Box, Object::Floor, Object::Box, Object::Floor, Object::Wall, 
+            Object::Wall, Object::Wall, Object::Floor, Object::Floor, Object::Box, Object::Floor, Object::Floor, Object::Wall, 
+            Object::Wall, Object::Floor, Object::Floor, Object::Floor, Object::Box, Object::Floor, Object::Floor, Object::Wall, 
+            Object::Wall, Object::Floor, Object::Wall, Object::Floor, Object::Box, Object::Floor, Object::Floor, Object::Wall, 
+            Object::Wall, Object::Floor, Object::Floor, Object::Floor, Object::Box, Object::Floor, Object::Floor, Object::Wall, 
+            Object::Wall, Object::Floor, Object::Floor, Object::Floor, Object::Floor, Object::Floor, Object::Floor, Object::Wall, 
+            Object::Wall, Object::Wall, Object::Wall, Object::Wall, Object::Wall, Object::Wall, Object::Wall, Object::Wall, 
+        ];
+
+        assert_eq!(expect_map, board.map.cells);
+
+        let expect_moves = vec![
+            Direction::W, Direction::N, Direction::N, Direction::E, 
+            Direction::E, Direction::E, Direction::S, Direction::S, 
+            Direction::W, Direction::S, Direction::E, Direction::E, 
+            Direction::S, Direction::W, Direction::W, 
+        ];
+
+        assert_eq!(expect_moves, moves);
+
+        Ok(())
+    }
+
+    #[test]
+    fn read_map_error() {
+        let input = Input::new("example.txt", "####\n#@.#\n#.x#\n####\n\n<^\n");
+        let err = read_map(&mut input.lines()).err().unwrap();
+
+        assert_eq!((3, 3), (err.line, err.col));
+        assert_eq!("unexpected char 'x'", err.kind.to_string());
+
+        let input = Input::new("example.txt", "####\n#..#\n####\n\n<^\n");
+        let err = read_map(&mut input.lines()).err().unwrap();
+
+        assert_eq!("expected robot '@' in map", err.kind.to_string());
+
+        let input = Input::new("example.txt", "####\n#@.#\n####\n\n<^\n>?v\n");
+        let mut lines = input.lines();
+        let _ = read_map(&mut lines);
+        let err = read_moves(&mut lines).unwrap_err();
+
+        assert_eq!((6, 2), (err.line, err.col));
+    }
+
+    #[test]
+    fn next_position() -> Result<(), Box<dyn std::error::Error>> {
+        let board = Board { map: Grid::filled(2, 2, Object::Floor) };
+        let p0: Point = (0, 0);
+        let p1 = board.map.next(p0, Direction::N);
+        assert_eq!(None, p1);
+        let p1 = board.map.next(p0, Direction::W);
+        assert_eq!(None, p1);
+        let p1 = board.map.next(p0, Direction::E);
+        assert_eq!(Some((1, 0)), p1);
+        let p2 = board.map.next(p1.unwrap(), Direction::E);
+        assert_eq!(None, p2);
+        let p2 = board.map.next(p1.unwrap(), Direction::S);
+        assert_eq!(Some((1, 1)), p2);
+        let p3 = board.map.next(p2.unwrap(), Direction::S);
+        assert_eq!(None, p3);
+        let p3 = board.map.next(p2.unwrap(), Direction::W);
+        assert_eq!(Some((0, 1)), p3);
+        let p4 = board.map.next(p3.unwrap(), Direction::W);
+        assert_eq!(None, p4);
+        Ok(())
+    }
+
+    #[test]
+    fn move_robot_internal_example() -> Result<(), Box<dyn std::error::Error>> {
+        let initial_map = vec![
+            Object::Wall, Object::Wall, Object::Wall, Object::Wall, Object::Wall, Object::Wall, Object::Wall, Object::Wall, 
+            Object::Wall, Object::Floor, Object::Floor, Object::Box, Object::Floor, Object::Box, Object::Floor, Object::Wall, 
+            Object::Wall, Object::Wall, Object::Floor, Object::Floor, Object::Box, Object::Floor, Object::Floor, Object::Wall, 
+            Object::Wall, Object::Floor, Object::Floor, Object::Floor, Object::Box, Object::Floor, Object::Floor, Object::Wall, 
+            Object::Wall, Object::Floor, Object::Wall, Object::Floor, Object::Box, Object::Floor, Object::Floor, Object::Wall, 
+            Object::Wall, Object::Floor, Object::Floor, Object::Floor, Object::Box, Object::Floor, Object::Floor, Object::Wall, 
+            Object::Wall, Object::Floor, Object::Floor, Object::Floor, Object::Floor, Object::Floor, Object::Floor, Object::Wall, 
+            Object::Wall, Object::Wall, Object::Wall, Object::Wall, Object::Wall, Object::Wall, Object::Wall, Object::Wall, 
+        ];
+
+        let mut board = Board {
+            map: Grid::new(8, 8, initial_map.clone())?,
+        };
+        let p0: Point = (1, 1);
+
+        assert_eq!(None, board.move_robot_internal(p0, Direction::N));
+
+        let p1 = board.move_robot_internal(p0, Direction::E);
+        assert_eq!(Some((2, 1)), p1);
+        assert_eq!(initial_map, board.map.cells);
+
+        Ok(())
+    }
+
+    #[test]
+    fn move_robot_and_push_box() -> Result<(), Box<dyn std::error::Error>> {
+        let initial_map = vec![
+            Object::Wall, Object::Wall, Object::Wall, Object::Wall, Object::Wall, Object::Wall, Object::Wall, Object::Wall, 
+            Object::Wall, Object::Floor, Object::Floor, Object::Box, Object::Floor, Object::Box, Object::Floor, Object::Wall, 
+            Object::Wall, Object::Wall, Object::Floor, Object::Floor, Object::Box, Object::Floor, Object::Floor, Object::Wall, 
+            Object::Wall, Object::Floor, Object::Floor, Object::Floor, Object::Box, Object::Floor, Object::Floor, Object::Wall, 
+            Object::Wall, Object::Floor, Object::Wall, Object::Floor, Object::Box, Object::Floor, Object::Floor, Object::Wall, 
+            Object::Wall, Object::Floor, Object::Floor, Object::Floor, Object::Box, Object::Floor, Object::Floor, Object::Wall, 
+            Object::Wall, Object::Floor, Object::Floor, Object::Floor, Object::Floor, Object::Floor, Object::Floor, Object::Wall, 
+            Object::Wall, Object::Wall, Object::Wall, Object::Wall, Object::Wall, Object::Wall, Object::Wall, Object::Wall, 
+        ];
+
+        let mut board = Board {
+            map: Grid::new(8, 8, initial_map.clone())?,
+        };
+        let p0: Point = (4, 1);
+        let p1 = board.move_robot_internal(p0, Direction::S);
+        assert_eq!(Some((4, 2)), p1);
+
+        let expect_map = vec![
+            Object::Wall, Object::Wall, Object::Wall, Object::Wall, Object::Wall, Object::Wall, Object::Wall, Object::Wall, 
+            Object::Wall, Object::Floor, Object::Floor, Object::Box, Object::Floor, Object::Box, Object::Floor, Object::Wall, 
+            Object::Wall, Object::Wall, Object::Floor, Object::Floor, Object::Floor, Object::Floor, Object::Floor, Object::Wall, 
+            Object::Wall, Object::Floor, Object::Floor, Object::Floor, Object::Box, Object::Floor, Object::Floor, Object::Wall, 
+            Object::Wall, Object::Floor, Object::Wall, Object::Floor, Object::Box, Object::Floor, Object::Floor, Object::Wall, 
+            Object::Wall, Object::Floor, Object::Floor, Object::Floor, Object::Box, Object::Floor, Object::Floor, Object::Wall, 
+            Object::Wall, Object::Floor, Object::Floor, Object::Floor, Object::Box, Object::Floor, Object::Floor, Object::Wall, 
+            Object::Wall, Object::Wall, Object::Wall, Object::Wall, Object::Wall, Object::Wall, Object::Wall, Object::Wall, 
+        ];
+        assert_eq!(expect_map, board.map.cells);
+
+        assert_eq!(None, board.move_robot_internal(p1.unwrap(), Direction::S));
+
+        Ok(())
+    }
+
+    #[test]
+    fn move_robot_all() -> Result<(), Box<dyn std::error::Error>> {
+        let initial_map = vec![
+            Object::Wall, Object::Wall,  Object::Wall,  Object::Wall,  Object::Wall,  Object::Wall,  Object::Wall,  Object::Wall, 
+            Object::Wall, Object::Floor, Object::Floor, Object::Box,   Object::Floor, Object::Box,   Object::Floor, Object::Wall, 
+            Object::Wall, Object::Wall,  Object::Floor, Object::Floor, Object::Box,   Object::Floor, Object::Floor, Object::Wall, 
+            Object::Wall, Object::Floor, Object::Floor, Object::Floor, Object::Box,   Object::Floor, Object::Floor, Object::Wall, 
+            Object::Wall, Object::Floor, Object::Wall,  Object::Floor, Object::Box,   Object::Floor, Object::Floor, Object::Wall, 
+            Object::Wall, Object::Floor, Object::Floor, Object::Floor, Object::Box,   Object::Floor, Object::Floor, Object::Wall, 
+            Object::Wall, Object::Floor, Object::Floor, Object::Floor, Object::Floor, Object::Floor, Object::Floor, Object::Wall, 
+            Object::Wall, Object::Wall,  Object::Wall,  Object::Wall,  Object::Wall,  Object::Wall,  Object::Wall,  Object::Wall, 
+        ];
+
+        let mut board = Board {
+            map: Grid::new(8, 8, initial_map.clone())?,
+        };
+        let p0: Point = (2, 2);
+
+        let moves = vec![
+            Direction::W, Direction::N, Direction::N, Direction::E, 
+            Direction::E, Direction::E, Direction::S, Direction::S, 
+            Direction::W, Direction::S, Direction::E, Direction::E, 
+            Direction::S, Direction::W, Direction::W, 
+        ];
+
+        board.move_robot(p0, &moves);
+
+        let expected_map = vec![
+            Object::Wall, Object::Wall,  Object::Wall,  Object::Wall,  Object::Wall,  Object::Wall,  Object::Wall,  Object::Wall, 
+            Object::Wall, Object::Floor, Object::Floor, Object::Floor, Object::Floor, Object::Box,   Object::Box,   Object::Wall, 
+            Object::Wall, Object::Wall,  Object::Floor, Object::Floor, Object::Floor, Object::Floor, Object::Floor, Object::Wall, 
+            Object::Wall, Object::Floor, Object::Floor, Object::Floor, Object::Floor, Object::Floor, Object::Box,   Object::Wall, 
+            Object::Wall, Object::Floor, Object::Wall,  Object::Box,   Object::Floor, Object::Floor, Object::Floor, Object::Wall, 
+            Object::Wall, Object::Floor, Object::Floor, Object::Floor, Object::Box,   Object::Floor, Object::Floor, Object::Wall, 
+            Object::Wall, Object::Floor, Object::Floor, Object::Floor, Object::Box,   Object::Floor, Object::Floor, Object::Wall, 
+            Object::Wall, Object::Wall,  Object::Wall,  Object::Wall,  Object::Wall,  Object::Wall,  Object::Wall,  Object::Wall, 
+        ];
+
+        assert_eq!(expected_map, board.map.cells);
+
+        Ok(())
+    }
+}
+
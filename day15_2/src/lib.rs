@@ -0,0 +1,471 @@
+use std::{collections::{HashSet, VecDeque}, path::Path};
+
+use aoc_grid::{Direction, Grid, Point};
+use aoc_input::{Input, Lines, ParseError, ParseErrorKind};
+
+pub fn solve<P>(path: P) -> Result<usize, Box<dyn std::error::Error>>
+    where P: AsRef<Path>
+{
+    solve_parsed(read_file(path)?)
+}
+
+pub fn solve_parsed((p0, mut board, moves): (Point, Board, Vec<Direction>)) -> Result<usize, Box<dyn std::error::Error>> {
+    board.move_robot(p0, &moves);
+
+    let total = board.map.iter()
+        .filter_map(|(p, obj)| match obj {
+            Object::BoxL => Some(p),
+            Object::BoxR | Object::Floor | Object::Wall => None,
+        })
+        .map(|(x, y)| x + 100 * y)
+        .sum::<usize>()
+    ;
+
+    Ok(total)
+}
+
+#[derive(Clone)]
+pub struct Board {
+    pub map: Grid<Object>,
+}
+
+impl Board {
+    pub fn move_robot(&mut self, mut p0: Point, moves: &[Direction]) {
+        for d in moves {
+            if let Some(p) = self.move_robot_internal(p0, d) {
+                p0 = p;
+            }
+        }
+    } 
+
+    pub fn move_robot_internal(&mut self, p0: Point, d: &Direction) -> Option<Point> {
+        let Some(p) = self.map.next(p0, *d) else {
+            return None;
+        };
+
+        match self.map[p] {
+            Object::BoxL | Object::BoxR => {
+                match self.try_push_box(p0, p, &d) {
+                    Some(q) => {
+                        self.swap_box(q);
+                        Some(p)
+                    }
+                    None => None,
+                }
+            }
+            Object::Floor => {
+                Some(p)
+            },
+            Object::Wall => return None,
+        }
+    }
+
+    pub fn try_push_box(&self, current: Point, next: Point, d: &Direction) -> Option<VecDeque<(usize, usize)>> {
+        match d {
+            Direction::W | Direction::E => {
+                self.try_push_box_horizontal(next, self.map.to_index(next), &d)
+            }
+            Direction::N | Direction::S => {
+                let mut trace = HashSet::<usize>::new();
+                self.try_push_box_vertical(current, self.map.to_index(current), &d, &self.map[current], &mut trace)
+            }
+        }
+    }
+
+    pub fn try_push_box_horizontal(&self, p0: Point, index_from: usize, d: &Direction) -> Option<VecDeque<(usize, usize)>> {
+        let Some(p) = self.map.next(p0, *d) else {
+            return None;
+        };
+
+        let index_to = self.map.to_index(p);
+        match self.map.cells[index_to] {
+            Object::BoxL | Object::BoxR => {
+                self.try_push_box_horizontal(p, index_to, &d)
+                    .map(|mut q| {
+                        q.push_back((index_from, index_to));
+                        q
+                    })
+            },
+            Object::Floor => {
+                Some(VecDeque::<(usize, usize)>::from([(index_from, index_to)]))
+            }
+            Object::Wall => {
+                None
+            }
+        }
+    }
+
+    pub fn try_push_box_vertical(&self, p0: Point, index_from: usize, d: &Direction, obj0: &Object, trace: &mut HashSet<usize>) -> Option<VecDeque<(usize, usize)>> {
+        let Some(p) = self.map.next(p0, *d) else {
+            return None;
+        };
+
+        let index_to = self.map.to_index(p);
+        if trace.contains(&index_to) {
+            return Some(VecDeque::<(usize, usize)>::new());
+        }
+
+        trace.insert(index_to);
+
+        let obj = &self.map.cells[index_to];
+        match obj {
+            Object::BoxL | Object::BoxR if *obj == *obj0 => {
+                match self.try_push_box_vertical(p, index_to, d, obj, trace) {
+                    Some(mut q) => {
+                        q.push_back((index_from, index_to));
+                        Some(q)
+                    }
+                    None => None,
+                }
+            }
+            Object::BoxL => {
+                let l_p = p;
+                let l_index_to = index_to;
+                let r_p = (p.0 + 1, p.1);
+                let r_index_to = self.map.to_index(r_p);
+                
+                match (self.try_push_box_vertical(l_p, l_index_to, d, obj, trace), self.try_push_box_vertical(r_p, r_index_to, d, &self.map.cells[r_index_to], trace)) {
+                    (Some(mut l_q), Some(mut r_q)) => {
+                        l_q.append(&mut r_q);
+                        l_q.push_back((index_from, index_to));
+                        Some(l_q)
+                    }
+                    _ => None,
+                }
+            }
+            Object::BoxR => {
+                let l_p = (p.0 - 1, p.1);
+                let l_index_to = self.map.to_index((p.0 - 1, p.1));
+                let r_p = p;
+                let r_index_to = index_to;
+                
+                match (self.try_push_box_vertical(l_p, l_index_to, d, &self.map.cells[l_index_to], trace), self.try_push_box_vertical(r_p, r_index_to, d, obj, trace)) {
+                    (Some(mut l_q), Some(mut r_q)) => {
+                        l_q.append(&mut r_q);
+                        l_q.push_back((index_from, index_to));
+                        Some(l_q)
+                    }
+                    _ => None,
+                }
+            }
+            Object::Floor => {
+                Some(VecDeque::<(usize, usize)>::from([(index_from, index_to)]))
+            }
+            Object::Wall => {
+                None
+            }
+        }
+    }
+
+    pub fn swap_box(&mut self, mut queue: VecDeque<(usize, usize)>) {
+        while let Some((index_from, index_to)) = queue.pop_front() {
+            self.map.cells.swap(index_from, index_to);
+        }
+    }
+
+    #[allow(unused)]
+    pub fn dump(&self, p0: Point) {
+        let map = self.map.render(|p, obj| match obj {
+            Object::Floor if p == p0 => '@',
+            Object::Floor => '.',
+            Object::BoxL => '[',
+            Object::BoxR => ']',
+            Object::Wall => '#',
+        });
+        eprintln!("{}", map);
+    }
+}
+
+#[derive(PartialEq, Clone, Debug)]
+pub enum Object {
+    Floor,
+    BoxL,
+    BoxR,
+    Wall,
+}
+
+pub fn read_file<P>(path: P) -> Result<(Point, Board, Vec<Direction>), ParseError> 
+    where P: AsRef<Path>
+{
+    let input = Input::read_file(path)?;
+    let mut lines = input.lines();
+
+    let (p0, board) = read_map(&mut lines)?;
+    let moves = read_moves(&mut lines)?;
+
+    Ok((p0, board, moves))
+}
+
+pub fn read_map(lines: &mut Lines<'_>) -> Result<(Point, Board), ParseError> {
+    let mut p0 = Option::<Point>::None;
+
+    let tiles = Grid::read_lines(lines, |(x, y), ch| match ch {
+        '#' => Some([Object::Wall, Object::Wall]),
+        '@' => {
+            p0 = Some((x * 2, y));
+            Some([Object::Floor, Object::Floor])
+        }
+        '.' => Some([Object::Floor, Object::Floor]),
+        'O' => Some([Object::BoxL, Object::BoxR]),
+        _ => None,
+    })?;
+
+    let board = Board {
+        map: Grid {
+            width: tiles.width * 2,
+            height: tiles.height,
+            cells: tiles.cells.into_iter().flatten().collect(),
+        },
+    };
+
+    match p0 { 
+        Some(p) => Ok((p, board)),
+        None => Err(lines.error(ParseErrorKind::Expected("robot '@' in map".to_string()))),
+    }
+}
+
+pub fn read_moves(lines: &mut Lines<'_>) -> Result<Vec<Direction>, ParseError> {
+    let mut moves = vec![];
+
+    for line in lines {
+        for (i, ch) in line.text.chars().enumerate() {
+            moves.push(match ch {
+                '^' => Direction::N,
+                '>' => Direction::E,
+                'v' => Direction::S,
+                '<' => Direction::W,
+                _ => return Err(line.error_at(i, 1, ParseErrorKind::UnexpectedChar(ch))),
+            });
+        }
+    }
+
+    Ok(moves)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::*;
+
+    #[test]
+    #[ignore = "reason"]
+    fn solve_example() -> Result<(), Box<dyn std::error::Error>> {
+        assert_eq!(2028, solve("./aoc_input_example_1.txt")?);
+        Ok(())
+    }
+
+    #[test]
+    fn read_file_example() -> Result<(), Box<dyn std::error::Error>> {
+        let (p0, board, moves) = read_file("aoc_input_example_1.txt")?;
+
+        assert_eq!(14, board.map.width);
+        assert_eq!(7, board.map.height);
+
+        assert_eq!((10, 3), p0);
+
+        let expect_map = vec![
+            Object::Wall, Object::Wall, Object::Wall,  Object::Wall,  Object::Wall,  Object::Wall,  Object::Wall,  Object::Wall,  Object::Wall,  Object::Wall,  Object::Wall,  Object::Wall,  Object::Wall, Object::Wall, 
+            Object::Wall, Object::Wall, Object::Floor, Object::Floor, Object::Floor, Object::Floor, Object::Floor, Object::Floor, Object::Wall,  Object::Wall,  Object::Floor, Object::Floor, Object::Wall, Object::Wall, 
+            Object::Wall, Object::Wall, Object::Floor, Object::Floor, Object::Floor, Object::Floor, Object::Floor, Object::Floor, Object::Floor, Object::Floor, Object::Floor, Object::Floor, Object::Wall, Object::Wall,
+            Object::Wall, Object::Wall, Object::Floor, Object::Floor, Object::Floor, Object::Floor, Object::BoxL , Object::BoxR , Object::BoxL , Object::BoxR , Object::Floor, Object::Floor, Object::Wall, Object::Wall,
+            Object::Wall, Object::Wall, Object::Floor, Object::Floor, Object::Floor, Object::Floor, Object::BoxL,  Object::BoxR,  Object::Floor, Object::Floor, Object::Floor, Object::Floor, Object::Wall, Object::Wall,
+            Object::Wall, Object::Wall, Object::Floor, Object::Floor, Object::Floor, Object::Floor, Object::Floor, Object::Floor, Object::Floor, Object::Floor, Object::Floor, Object::Floor, Object::Wall, Object::Wall,
+            Object::Wall, Object::Wall, Object::Wall,  Object::Wall,  Object::Wall,  Object::Wall,  Object::Wall,  Object::Wall,  Object::Wall,  Object::Wall,  Object::Wall,  Object::Wall,  Object::Wall, Object::Wall, 
+        ];
+
+        assert_eq!(expect_map, board.map.cells);
+
+        let expect_moves = vec![
+            Direction::W, Direction::S, Direction::S, Direction::W, 
+            Direction::W, Direction::N, Direction::N, Direction::W, 
+            Direction::W, Direction::N, Direction::N, 
+        ];
+
+        assert_eq!(expect_moves, moves);
+
+        Ok(())
+    }
+
+    #[test]
+    fn move_robot_internal_example() -> Result<(), Box<dyn std::error::Error>> {
+        let initial_map = vec![
+            Object::Wall, Object::Wall, Object::Wall,  Object::Wall,  Object::Wall,  Object::Wall,  Object::Wall,  Object::Wall,  Object::Wall,  Object::Wall,  Object::Wall,  Object::Wall,  Object::Wall, Object::Wall, 
+            Object::Wall, Object::Wall, Object::Floor, Object::Floor, Object::Floor, Object::Floor, Object::Floor, Object::Floor, Object::Wall,  Object::Wall,  Object::Floor, Object::Floor, Object::Wall, Object::Wall, 
+            Object::Wall, Object::Wall, Object::Floor, Object::Floor, Object::Floor, Object::Floor, Object::Floor, Object::Floor, Object::Floor, Object::Floor, Object::Floor, Object::Floor, Object::Wall, Object::Wall,
+            Object::Wall, Object::Wall, Object::Floor, Object::Floor, Object::Floor, Object::Floor, Object::BoxL , Object::BoxR , Object::BoxL , Object::BoxR , Object::Floor, Object::Floor, Object::Wall, Object::Wall,
+            Object::Wall, Object::Wall, Object::Floor, Object::Floor, Object::Floor, Object::Floor, Object::BoxL,  Object::BoxR,  Object::Floor, Object::Floor, Object::Floor, Object::Floor, Object::Wall, Object::Wall,
+            Object::Wall, Object::Wall, Object::Floor, Object::Floor, Object::Floor, Object::Floor, Object::Floor, Object::Floor, Object::Floor, Object::Floor, Object::Floor, Object::Floor, Object::Wall, Object::Wall,
+            Object::Wall, Object::Wall, Object::Wall,  Object::Wall,  Object::Wall,  Object::Wall,  Object::Wall,  Object::Wall,  Object::Wall,  Object::Wall,  Object::Wall,  Object::Wall,  Object::Wall, Object::Wall, 
+        ];
+
+        let mut board = Board {
+            map: Grid::new(14, 7, initial_map.clone())?,
+        };
+        let p0: Point = (2, 1);
+
+        assert_eq!(None, board.move_robot_internal(p0, &Direction::N));
+
+        let p1 = board.move_robot_internal(p0, &Direction::E);
+        assert_eq!(Some((3, 1)), p1);
+        assert_eq!(initial_map, board.map.cells);
+
+        Ok(())
+    }
+
+    #[test]
+    fn move_robot_and_push_box() -> Result<(), Box<dyn std::error::Error>> {
+        let initial_map = vec![
+            Object::Wall, Object::Wall, Object::Wall,  Object::Wall,  Object::Wall,  Object::Wall,  Object::Wall,  Object::Wall,  Object::Wall,  Object::Wall,  Object::Wall,  Object::Wall,  Object::Wall, Object::Wall, 
+            Object::Wall, Object::Wall, Object::Floor, Object::Floor, Object::Floor, Object::Floor, Object::Floor, Object::Floor, Object::Wall,  Object::Wall,  Object::Floor, Object::Floor, Object::Wall, Object::Wall, 
+            Object::Wall, Object::Wall, Object::Floor, Object::Floor, Object::Floor, Object::Floor, Object::Floor, Object::Floor, Object::Floor, Object::Floor, Object::Floor, Object::Floor, Object::Wall, Object::Wall,
+            Object::Wall, Object::Wall, Object::Floor, Object::Floor, Object::Floor, Object::Floor, Object::BoxL , Object::BoxR , Object::BoxL , Object::BoxR , Object::Floor, Object::Floor, Object::Wall, Object::Wall,
+            Object::Wall, Object::Wall, Object::Floor, Object::Floor, Object::Floor, Object::Floor, Object::BoxL,  Object::BoxR,  Object::Floor, Object::Floor, Object::Floor, Object::Floor, Object::Wall, Object::Wall,
+            Object::Wall, Object::Wall, Object::Floor, Object::Floor, Object::Floor, Object::Floor, Object::Floor, Object::Floor, Object::Floor, Object::Floor, Object::Floor, Object::Floor, Object::Wall, Object::Wall,
+            Object::Wall, Object::Wall, Object::Wall,  Object::Wall,  Object::Wall,  Object::Wall,  Object::Wall,  Object::Wall,  Object::Wall,  Object::Wall,  Object::Wall,  Object::Wall,  Object::Wall, Object::Wall, 
+        ];
+
+        let mut board = Board {
+            map: Grid::new(14, 7, initial_map.clone())?,
+        };
+        let p0: Point = (10, 3);
+        let p1 = board.move_robot_internal(p0, &Direction::W);
+        assert_eq!(Some((9, 3)), p1);
+
+        let p0: Point = (7, 5);
+        let p1 = board.move_robot_internal(p0, &Direction::N);
+        assert_eq!(Some((7, 4)), p1);
+
+        let expect_map = vec![
+            Object::Wall, Object::Wall, Object::Wall,  Object::Wall,  Object::Wall,  Object::Wall,  Object::Wall,  Object::Wall,  Object::Wall,  Object::Wall,  Object::Wall,  Object::Wall,  Object::Wall, Object::Wall, 
+            Object::Wall, Object::Wall, Object::Floor, Object::Floor, Object::Floor, Object::Floor, Object::Floor, Object::Floor, Object::Wall,  Object::Wall,  Object::Floor, Object::Floor, Object::Wall, Object::Wall, 
+            Object::Wall, Object::Wall, Object::Floor, Object::Floor, Object::Floor, Object::BoxL,  Object::BoxR,  Object::BoxL,  Object::BoxR,  Object::Floor, Object::Floor, Object::Floor, Object::Wall, Object::Wall,
+            Object::Wall, Object::Wall, Object::Floor, Object::Floor, Object::Floor, Object::Floor, Object::BoxL , Object::BoxR , Object::Floor, Object::Floor, Object::Floor, Object::Floor, Object::Wall, Object::Wall,
+            Object::Wall, Object::Wall, Object::Floor, Object::Floor, Object::Floor, Object::Floor, Object::Floor, Object::Floor, Object::Floor, Object::Floor, Object::Floor, Object::Floor, Object::Wall, Object::Wall,
+            Object::Wall, Object::Wall, Object::Floor, Object::Floor, Object::Floor, Object::Floor, Object::Floor, Object::Floor, Object::Floor, Object::Floor, Object::Floor, Object::Floor, Object::Wall, Object::Wall,
+            Object::Wall, Object::Wall, Object::Wall,  Object::Wall,  Object::Wall,  Object::Wall,  Object::Wall,  Object::Wall,  Object::Wall,  Object::Wall,  Object::Wall,  Object::Wall,  Object::Wall, Object::Wall, 
+        ];
+
+        assert_eq!(expect_map, board.map.cells);
+
+        assert_eq!(None, board.move_robot_internal(p1.unwrap(), &Direction::N));
+
+        Ok(())
+    }
+
+    #[test]
+    fn move_robot_and_push_box_vertical() -> Result<(), Box<dyn std::error::Error>> {
+        let initial_map = vec![
+            Object::Wall, Object::Wall, Object::Wall,  Object::Wall,  Object::Wall,  Object::Wall,  Object::Wall,  Object::Wall,  Object::Wall,  Object::Wall,  Object::Wall,  Object::Wall,  Object::Wall, Object::Wall, 
+            Object::Wall, Object::Wall, Object::Floor, Object::Floor, Object::Floor, Object::Floor, Object::Floor, Object::Floor, Object::Wall,  Object::Wall,  Object::Floor, Object::Floor, Object::Wall, Object::Wall, 
+            Object::Wall, Object::Wall, Object::Floor, Object::Floor, Object::Floor, Object::Floor, Object::Floor, Object::Floor, Object::Floor, Object::Floor, Object::Floor, Object::Floor, Object::Wall, Object::Wall,
+            Object::Wall, Object::Wall, Object::Floor, Object::Floor, Object::Floor, Object::Floor, Object::BoxL , Object::BoxR , Object::BoxL , Object::BoxR , Object::Floor, Object::Floor, Object::Wall, Object::Wall,
+            Object::Wall, Object::Wall, Object::Floor, Object::Floor, Object::Floor, Object::Floor, Object::BoxL,  Object::BoxR,  Object::Floor, Object::Floor, Object::Floor, Object::Floor, Object::Wall, Object::Wall,
+            Object::Wall, Object::Wall, Object::Floor, Object::Floor, Object::Floor, Object::Floor, Object::Floor, Object::Floor, Object::Floor, Object::Floor, Object::Floor, Object::Floor, Object::Wall, Object::Wall,
+            Object::Wall, Object::Wall, Object::Wall,  Object::Wall,  Object::Wall,  Object::Wall,  Object::Wall,  Object::Wall,  Object::Wall,  Object::Wall,  Object::Wall,  Object::Wall,  Object::Wall, Object::Wall, 
+        ];
+
+        let mut board = Board {
+            map: Grid::new(14, 7, initial_map.clone())?,
+        };
+        let p0: Point = (6, 5);
+        let p1 = board.move_robot_internal(p0, &Direction::N);
+        assert_eq!(Some((6, 4)), p1);
+
+        board.dump(p1.unwrap());
+
+        let expect_map = vec![
+            Object::Wall, Object::Wall, Object::Wall,  Object::Wall,  Object::Wall,  Object::Wall,  Object::Wall,  Object::Wall,  Object::Wall,  Object::Wall,  Object::Wall,  Object::Wall,  Object::Wall, Object::Wall, 
+            Object::Wall, Object::Wall, Object::Floor, Object::Floor, Object::Floor, Object::Floor, Object::Floor, Object::Floor, Object::Wall,  Object::Wall,  Object::Floor, Object::Floor, Object::Wall, Object::Wall, 
+            Object::Wall, Object::Wall, Object::Floor, Object::Floor, Object::Floor, Object::Floor, Object::BoxL,  Object::BoxR,  Object::Floor, Object::Floor, Object::Floor, Object::Floor, Object::Wall, Object::Wall,
+            Object::Wall, Object::Wall, Object::Floor, Object::Floor, Object::Floor, Object::Floor, Object::BoxL,  Object::BoxR,  Object::BoxL , Object::BoxR , Object::Floor, Object::Floor, Object::Wall, Object::Wall,
+            Object::Wall, Object::Wall, Object::Floor, Object::Floor, Object::Floor, Object::Floor, Object::Floor, Object::Floor, Object::Floor, Object::Floor, Object::Floor, Object::Floor, Object::Wall, Object::Wall,
+            Object::Wall, Object::Wall, Object::Floor, Object::Floor, Object::Floor, Object::Floor, Object::Floor, Object::Floor, Object::Floor, Object::Floor, Object::Floor, Object::Floor, Object::Wall, Object::Wall,
+            Object::Wall, Object::Wall, Object::Wall,  Object::Wall,  Object::Wall,  Object::Wall,  Object::Wall,  Object::Wall,  Object::Wall,  Object::Wall,  Object::Wall,  Object::Wall,  Object::Wall, Object::Wall, 
+        ];
+
+        assert_eq!(expect_map, board.map.cells);
+
+        Ok(())
+    }
+
+    #[test]
+    fn move_robot_and_push_box_vertical_2() -> Result<(), Box<dyn std::error::Error>> {
+        let initial_map = vec![
+            Object::Wall, Object::Wall, Object::Wall,  Object::Wall,  Object::Wall,  Object::Wall,  Object::Wall,  Object::Wall,  Object::Wall,  Object::Wall,  Object::Wall,  Object::Wall,  Object::Wall, Object::Wall, 
+            Object::Wall, Object::Wall, Object::Floor, Object::Floor, Object::Floor, Object::Floor, Object::Floor, Object::Floor, Object::Wall,  Object::Wall,  Object::Floor, Object::Floor, Object::Wall, Object::Wall, 
+            Object::Wall, Object::Wall, Object::Floor, Object::Floor, Object::Floor, Object::Floor, Object::BoxL,  Object::BoxR,  Object::Floor, Object::Floor , Object::Floor, Object::Floor, Object::Wall, Object::Wall,
+            Object::Wall, Object::Wall, Object::Floor, Object::Floor, Object::Floor, Object::BoxL,  Object::BoxR,  Object::BoxL,  Object::BoxR,  Object::Floor, Object::Floor, Object::Floor, Object::Wall, Object::Wall,
+            Object::Wall, Object::Wall, Object::Floor, Object::Floor, Object::Floor, Object::Floor, Object::BoxL,  Object::BoxR,  Object::Floor, Object::Floor, Object::Floor, Object::Floor, Object::Wall, Object::Wall,
+            Object::Wall, Object::Wall, Object::Floor, Object::Floor, Object::Floor, Object::Floor, Object::Floor, Object::Floor, Object::Floor, Object::Floor, Object::Floor, Object::Floor, Object::Wall, Object::Wall,
+            Object::Wall, Object::Wall, Object::Wall,  Object::Wall,  Object::Wall,  Object::Wall,  Object::Wall,  Object::Wall,  Object::Wall,  Object::Wall,  Object::Wall,  Object::Wall,  Object::Wall, Object::Wall, 
+        ];
+
+        let mut board = Board {
+            map: Grid::new(14, 7, initial_map.clone())?,
+        };
+        let p0: Point = (7, 5);
+        board.dump(p0);
+        let p1 = board.move_robot_internal(p0, &Direction::N);
+        assert_eq!(Some((7, 4)), p1);
+
+        board.dump(p1.unwrap());
+
+        let expect_map = vec![
+            Object::Wall, Object::Wall, Object::Wall,  Object::Wall,  Object::Wall,  Object::Wall,  Object::Wall,  Object::Wall,  Object::Wall,  Object::Wall,  Object::Wall,  Object::Wall,  Object::Wall, Object::Wall, 
+            Object::Wall, Object::Wall, Object::Floor, Object::Floor, Object::Floor, Object::Floor, Object::BoxL,  Object::BoxR,  Object::Wall,  Object::Wall , Object::Floor, Object::Floor, Object::Wall, Object::Wall,
+            Object::Wall, Object::Wall, Object::Floor, Object::Floor, Object::Floor, Object::BoxL,  Object::BoxR,  Object::BoxL,  Object::BoxR,  Object::Floor, Object::Floor, Object::Floor, Object::Wall, Object::Wall,
+            Object::Wall, Object::Wall, Object::Floor, Object::Floor, Object::Floor, Object::Floor, Object::BoxL,  Object::BoxR,  Object::Floor, Object::Floor, Object::Floor, Object::Floor, Object::Wall, Object::Wall,
+            Object::Wall, Object::Wall, Object::Floor, Object::Floor, Object::Floor, Object::Floor, Object::Floor, Object::Floor, Object::Floor, Object::Floor, Object::Floor, Object::Floor, Object::Wall, Object::Wall, 
+            Object::Wall, Object::Wall, Object::Floor, Object::Floor, Object::Floor, Object::Floor, Object::Floor, Object::Floor, Object::Floor, Object::Floor, Object::Floor, Object::Floor, Object::Wall, Object::Wall,
+            Object::Wall, Object::Wall, Object::Wall,  Object::Wall,  Object::Wall,  Object::Wall,  Object::Wall,  Object::Wall,  Object::Wall,  Object::Wall,  Object::Wall,  Object::Wall,  Object::Wall, Object::Wall, 
+        ];
+
+        assert_eq!(expect_map, board.map.cells);
+
+        let p2 = board.move_robot_internal(p1.unwrap(), &Direction::N);
+        assert_eq!(None, p2);
+
+        Ok(())
+    }
+
+    #[test]
+    fn move_robot_all() -> Result<(), Box<dyn std::error::Error>> {
+        let initial_map = vec![
+            Object::Wall, Object::Wall, Object::Wall,  Object::Wall,  Object::Wall,  Object::Wall,  Object::Wall,  Object::Wall,  Object::Wall,  Object::Wall,  Object::Wall,  Object::Wall,  Object::Wall, Object::Wall, 
+            Object::Wall, Object::Wall, Object::Floor, Object::Floor, Object::Floor, Object::Floor, Object::Floor, Object::Floor, Object::Wall,  Object::Wall,  Object::Floor, Object::Floor, Object::Wall, Object::Wall, 
+            Object::Wall, Object::Wall, Object::Floor, Object::Floor, Object::Floor, Object::Floor, Object::Floor, Object::Floor, Object::Floor, Object::Floor, Object::Floor, Object::Floor, Object::Wall, Object::Wall,
+            Object::Wall, Object::Wall, Object::Floor, Object::Floor, Object::Floor, Object::Floor, Object::BoxL , Object::BoxR , Object::BoxL , Object::BoxR , Object::Floor, Object::Floor, Object::Wall, Object::Wall,
+            Object::Wall, Object::Wall, Object::Floor, Object::Floor, Object::Floor, Object::Floor, Object::BoxL,  Object::BoxR,  Object::Floor, Object::Floor, Object::Floor, Object::Floor, Object::Wall, Object::Wall,
+            Object::Wall, Object::Wall, Object::Floor, Object::Floor, Object::Floor, Object::Floor, Object::Floor, Object::Floor, Object::Floor, Object::Floor, Object::Floor, Object::Floor, Object::Wall, Object::Wall,
+            Object::Wall, Object::Wall, Object::Wall,  Object::Wall,  Object::Wall,  Object::Wall,  Object::Wall,  Object::Wall,  Object::Wall,  Object::Wall,  Object::Wall,  Object::Wall,  Object::Wall, Object::Wall, 
+        ];
+
+        let mut board = Board {
+            map: Grid::new(14, 7, initial_map.clone())?,
+        };
+        let p0: Point = (2, 2);
+
+        let moves = vec![
+            Direction::W, Direction::S, Direction::S, Direction::W, 
+            Direction::W, Direction::N, Direction::N, Direction::W, 
+            Direction::W, Direction::N, Direction::N, 
+        ];
+
+        board.move_robot(p0, &moves);
+        board.dump((0, 0));
+
+        let expected_map = vec![
+            Object::Wall, Object::Wall, Object::Wall,  Object::Wall,  Object::Wall,  Object::Wall,  Object::Wall,  Object::Wall,  Object::Wall,  Object::Wall,  Object::Wall,  Object::Wall,  Object::Wall, Object::Wall, 
+            Object::Wall, Object::Wall, Object::Floor, Object::Floor, Object::Floor, Object::Floor, Object::Floor, Object::Floor, Object::Wall,  Object::Wall,  Object::Floor, Object::Floor, Object::Wall, Object::Wall, 
+            Object::Wall, Object::Wall, Object::Floor, Object::Floor, Object::Floor, Object::Floor, Object::Floor, Object::Floor, Object::Floor, Object::Floor, Object::Floor, Object::Floor, Object::Wall, Object::Wall,
+            Object::Wall, Object::Wall, Object::Floor, Object::Floor, Object::Floor, Object::Floor, Object::BoxL , Object::BoxR , Object::BoxL , Object::BoxR , Object::Floor, Object::Floor, Object::Wall, Object::Wall,
+            Object::Wall, Object::Wall, Object::Floor, Object::Floor, Object::Floor, Object::Floor, Object::BoxL,  Object::BoxR,  Object::Floor, Object::Floor, Object::Floor, Object::Floor, Object::Wall, Object::Wall,
+            Object::Wall, Object::Wall, Object::Floor, Object::Floor, Object::Floor, Object::Floor, Object::Floor, Object::Floor, Object::Floor, Object::Floor, Object::Floor, Object::Floor, Object::Wall, Object::Wall,
+            Object::Wall, Object::Wall, Object::Wall,  Object::Wall,  Object::Wall,  Object::Wall,  Object::Wall,  Object::Wall,  Object::Wall,  Object::Wall,  Object::Wall,  Object::Wall,  Object::Wall, Object::Wall, 
+        ];
+
+        assert_eq!(expected_map, board.map.cells);
+
+        Ok(())
+    }
+}
+
@@ -0,0 +1,169 @@
+use std::path::Path;
+
+use aoc_grid::{Direction, Grid, Point};
+use aoc_input::{Input, ParseError, ParseErrorKind};
+use aoc_search::{dijkstra, SearchResult};
+
+pub fn solve<P>(path: P) -> Result<Option<u64>, Box<dyn std::error::Error>>
+    where P: AsRef<Path>
+{
+    solve_parsed(read_file(path)?)
+}
+
+pub fn solve_parsed((p0, goal, board): (Point, Point, Board)) -> Result<Option<u64>, Box<dyn std::error::Error>> {
+    let score = board.find_route(p0, goal);
+
+    Ok(score)
+}
+
+#[derive(PartialEq, Clone, Debug)]
+pub enum Object {
+    Wall,
+    Road,
+}
+
+pub type State = (Point, Direction);
+
+#[derive(Clone)]
+pub struct Board {
+    pub map: Grid<Object>,
+}
+
+impl Board {
+    pub fn find_route(&self, p0: Point, goal: Point) -> Option<u64> {
+        self.search(p0).nearest(Direction::iter().map(|d| (goal, d)))
+            .map(|(_, score)| score)
+    }
+
+    pub fn search(&self, p0: Point) -> SearchResult<State> {
+        dijkstra([(p0, Direction::E)], |state| self.moves(state))
+    }
+
+    pub fn moves(&self, &(p0, d0): &State) -> Vec<(State, u64)> {
+        Direction::iter().into_iter()
+            .filter(|d| *d != d0.rev())
+            .filter_map(|d| match self.map.next(p0, d) {
+                Some(p) if self.map[p] == Object::Road => {
+                    Some(((p, d), 1 + if d == d0 { 0 } else { 1000 }))
+                }
+                _ => None,
+            })
+            .collect()
+    }
+
+    pub fn scores(&self, result: &SearchResult<State>) -> Grid<Option<u64>> {
+        Grid {
+            width: self.map.width,
+            height: self.map.height,
+            cells: self.map.points()
+                .map(|p| result.nearest(Direction::iter().map(|d| (p, d))).map(|(_, score)| score))
+                .collect(),
+        }
+    }
+}
+
+pub fn read_file<P>(path: P) -> Result<(Point, Point, Board), ParseError> 
+    where P: AsRef<Path>
+{
+    let input = Input::read_file(path)?;
+    let mut lines = input.lines();
+    let mut p0: Option<Point> = None;
+    let mut goal: Option<Point> = None;
+
+    let map = Grid::read_lines(&mut lines, |p, ch| match ch {
+        '#' => Some(Object::Wall),
+        'S' => {
+            p0 = p0.or(Some(p));
+            Some(Object::Road)
+        }
+        'E' => {
+            goal = goal.or(Some(p));
+            Some(Object::Road)
+        }
+        '.' => Some(Object::Road),
+        _ => None,
+    })?;
+
+    let board = Board {
+        map,
+    };
+
+    let (Some(p0), Some(goal)) = (p0, goal) else {
+        return Err(lines.error(ParseErrorKind::Expected("start 'S' and end 'E' in map".to_string())));
+    };
+
+    Ok((p0, goal, board))
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::*;
+
+    #[test]
+    fn solve_example() -> Result<(), Box<dyn std::error::Error>> {
+        assert_eq!(Some(7036), solve("./aoc_input_example_1.txt")?);
+        assert_eq!(Some(11048), solve("./aoc_input_example_2.txt")?);
+        Ok(())
+    }
+
+    #[test]
+    fn read_file_example() -> Result<(), Box<dyn std::error::Error>> {
+        let (p0, goal, board) = read_file("aoc_input_example_1.txt")?;
+
+        assert_eq!(15, board.map.width);
+        assert_eq!(15, board.map.height);
+
+        assert_eq!((1, 13), p0);
+        assert_eq!((13, 1), goal);
+
+        let expect_map = vec![
+            Object::Wall, Object::Wall, Object::Wall, Object::Wall, Object::Wall, Object::Wall, Object::Wall, Object::Wall, Object::Wall, Object::Wall, Object::Wall, Object::Wall, Object::Wall, Object::Wall, Object::Wall, 
+            Object::Wall, Object::Road, Object::Road, Object::Road, Object::Road, Object::Road, Object::Road, Object::Road, Object::Wall, Object::Road, Object::Road, Object::Road, Object::Road, Object::Road, Object::Wall, 
+            Object::Wall, Object::Road, Object::Wall, Object::Road, Object::Wall, Object::Wall, Object::Wall, Object::Road, Object::Wall, Object::Road, Object::Wall, Object::Wall, Object::Wall, Object::Road, Object::Wall, 
+            Object::Wall, Object::Road, Object::Road, Object::Road, Object::Road, Object::Road, Object::Wall, Object::Road, Object::Wall, Object::Road, Object::Road, Object::Road, Object::Wall, Object::Road, Object::Wall, 
+            Object::Wall, Object::Road, Object::Wall, Object::Wall, Object::Wall, Object::Road, Object::Wall, Object::Wall, Object::Wall, Object::Wall, Object::Wall, Object::Road, Object::Wall, Object::Road, Object::Wall, 
+            Object::Wall, Object::Road, Object::Wall, Object::Road, Object::Wall, Object::Road, Object::Road, Object::Road, Object::Road, Object::Road, Object::Road, Object::Road, Object::Wall, Object::Road, Object::Wall, 
+            Object::Wall, Object::Road, Object::Wall, Object::Road, Object::Wall, Object::Wall, Object::Wall, Object::Wall, Object::Wall, Object::Road, Object::Wall, Object::Wall, Object::Wall, Object::Road, Object::Wall, 
+            Object::Wall, Object::Road, Object::Road, Object::Road, Object::Road, Object::Road, Object::Road, Object::Road, Object::Road, Object::Road, Object::Road, Object::Road, Object::Wall, Object::Road, Object::Wall, 
+            Object::Wall, Object::Wall, Object::Wall, Object::Road, Object::Wall, Object::Road, Object::Wall, Object::Wall, Object::Wall, Object::Wall, Object::Wall, Object::Road, Object::Wall, Object::Road, Object::Wall, 
+            Object::Wall, Object::Road, Object::Road, Object::Road, Object::Wall, Object::Road, Object::Road, Object::Road, Object::Road, Object::Road, Object::Wall, Object::Road, Object::Wall, Object::Road, Object::Wall, 
+            Object::Wall, Object::Road, Object::Wall, Object::Road, Object::Wall, Object::Road, Object::Wall, Object::Wall, Object::Wall, Object::Road, Object::Wall, Object::Road, Object::Wall, Object::Road, Object::Wall, 
+            Object::Wall, Object::Road, Object::Road, Object::Road, Object::Road, Object::Road, Object::Wall, Object::Road, Object::Road, Object::Road, Object::Wall, Object::Road, Object::Wall, Object::Road, Object::Wall, 
+            Object::Wall, Object::Road, Object::Wall, Object::Wall, Object::Wall, Object::Road, Object::Wall, Object::Road, Object::Wall, Object::Road, Object::Wall, Object::Road, Object::Wall, Object::Road, Object::Wall, 
+            Object::Wall, Object::Road, Object::Road, Object::Road, Object::Wall, Object::Road, Object::Road, Object::Road, Object::Road, Object::Road, Object::Wall, Object::Road, Object::Road, Object::Road, Object::Wall, 
+            Object::Wall, Object::Wall, Object::Wall, Object::Wall, Object::Wall, Object::Wall, Object::Wall, Object::Wall, Object::Wall, Object::Wall, Object::Wall, Object::Wall, Object::Wall, Object::Wall, Object::Wall, 
+        ];
+        assert_eq!(expect_map, board.map.cells);
+        Ok(())
+    }
+
+    #[test]
+    fn find_route_5() -> Result<(), Box<dyn std::error::Error>> {
+        let map = vec![
+            Object::Wall, Object::Road, Object::Road, Object::Road, Object::Road, 
+            Object::Wall, Object::Road, Object::Wall, Object::Wall, Object::Wall,
+            Object::Wall, Object::Road, Object::Road, Object::Road, Object::Wall,
+            Object::Wall, Object::Wall, Object::Wall, Object::Wall, Object::Wall,
+        ];
+
+        let board = Board {
+            map: Grid::new(5, 4, map)?,
+        };
+        let p0 = (1, 2);
+        let goal = (4, 0);
+
+        let p = board.find_route(p0, goal);
+
+        let expect_scores = vec![
+            None, Some(1002), Some(2003), Some(2004), Some(2005), 
+            None, Some(1001), None,       None,       None,
+            None, Some(0),    Some(1),    Some(2),    None,
+            None, None,       None,       None,       None,
+        ];
+        assert_eq!(expect_scores, board.scores(&board.search(p0)).cells);
+
+        assert_eq!(Some(2005), p);
+
+        Ok(())
+    }
+}
\ No newline at end of file
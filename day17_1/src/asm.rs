@@ -0,0 +1,268 @@
+use std::collections::{BTreeSet, HashMap};
+
+use aoc_input::{Input, Line, ParseError, ParseErrorKind};
+
+pub const MNEMONICS: [&str; 8] = ["adv", "bxl", "bst", "jnz", "bxc", "out", "bdv", "cdv"];
+
+pub fn is_combo(opcode: u8) -> bool {
+    matches!(opcode, 0 | 2 | 5 | 6 | 7)
+}
+
+pub fn combo_name(operand: u8) -> String {
+    match operand {
+        4 => "A".to_string(),
+        5 => "B".to_string(),
+        6 => "C".to_string(),
+        _ => operand.to_string(),
+    }
+}
+
+pub fn label_name(addr: usize) -> String {
+    format!("L{}", addr)
+}
+
+fn jump_targets(programs: &[u8]) -> BTreeSet<usize> {
+    programs.chunks_exact(2)
+        .filter(|instruction| instruction[0] == 3)
+        .map(|instruction| instruction[1] as usize)
+        .filter(|target| target % 2 == 0 && *target < programs.len())
+        .collect()
+}
+
+pub fn format_instruction(opcode: u8, operand: u8) -> String {
+    match MNEMONICS.get(opcode as usize) {
+        Some(mnemonic) => format!("{} {}", mnemonic, render_operand(opcode, operand, &BTreeSet::new())),
+        None => format!("db {}, {}", opcode, operand),
+    }
+}
+
+fn render_operand(opcode: u8, operand: u8, labels: &BTreeSet<usize>) -> String {
+    match opcode {
+        3 if labels.contains(&(operand as usize)) => label_name(operand as usize),
+        _ if is_combo(opcode) => combo_name(operand),
+        _ => operand.to_string(),
+    }
+}
+
+fn describe(opcode: u8, operand: &str) -> String {
+    match opcode {
+        0 => format!("A = A >> {}", operand),
+        1 => format!("B = B ^ {}", operand),
+        2 => format!("B = {} & 7", operand),
+        3 => format!("if A != 0 goto {}", operand),
+        4 => "B = B ^ C".to_string(),
+        5 => format!("output {} & 7", operand),
+        6 => format!("B = A >> {}", operand),
+        _ => format!("C = A >> {}", operand),
+    }
+}
+
+pub fn disassemble(programs: &[u8]) -> String {
+    let labels = jump_targets(programs);
+    let mut lines = vec![];
+
+    for (i, instruction) in programs.chunks(2).enumerate() {
+        let addr = i * 2;
+        if labels.contains(&addr) {
+            lines.push(format!("{}:", label_name(addr)));
+        }
+
+        // operands above 7 only assemble as a jump label, anything else stays raw bytes
+        match instruction {
+            &[opcode, operand] if (opcode as usize) < MNEMONICS.len()
+                && (operand <= 7 || (opcode == 3 && labels.contains(&(operand as usize)))) => {
+                let operand = render_operand(opcode, operand, &labels);
+                let text = format!("{} {}", MNEMONICS[opcode as usize], operand);
+                lines.push(format!("{:>4}: {:<8} ; {}", addr, text, describe(opcode, &operand)));
+            }
+            bytes => {
+                let bytes = bytes.iter().map(|b| b.to_string()).collect::<Vec<_>>();
+                lines.push(format!("{:>4}: db {}", addr, bytes.join(", ")));
+            }
+        }
+    }
+
+    lines.push(String::new());
+    lines.join("\n")
+}
+
+fn is_label(s: &str) -> bool {
+    let mut chars = s.chars();
+    chars.next().is_some_and(|ch| ch.is_ascii_alphabetic() || ch == '_')
+        && chars.all(|ch| ch.is_ascii_alphanumeric() || ch == '_')
+}
+
+fn strip_comment(text: &str) -> &str {
+    text.split(';').next().unwrap_or("").trim()
+}
+
+fn strip_address<'a>(line: &Line<'a>, s: &'a str, addr: usize) -> Result<&'a str, ParseError> {
+    let Some((prefix, rest)) = s.split_once(':') else {
+        return Ok(s);
+    };
+    if !prefix.trim().chars().all(|ch| ch.is_ascii_digit()) {
+        return Ok(s);
+    }
+
+    let expected = line.parse::<usize>(prefix.trim())?;
+    if expected != addr {
+        return Err(line.error(prefix.trim(), ParseErrorKind::Invalid(format!("address {} does not match {}", expected, addr))));
+    }
+
+    Ok(rest.trim())
+}
+
+pub fn assemble(input: &Input) -> Result<Vec<u8>, ParseError> {
+    let mut labels = HashMap::<&str, usize>::new();
+    let mut statements = vec![];
+
+    for line in input.lines() {
+        let s = strip_comment(line.text);
+        if s.is_empty() {
+            continue;
+        }
+
+        if let Some(label) = s.strip_suffix(':').filter(|label| is_label(label.trim())) {
+            let label = label.trim();
+            let addr = statements.iter().map(|(_, _, len)| len).sum::<usize>();
+            if labels.insert(label, addr).is_some() {
+                return Err(line.error(label, ParseErrorKind::Invalid(format!("duplicate label {:?}", label))));
+            }
+            continue;
+        }
+
+        let addr = statements.iter().map(|(_, _, len)| len).sum::<usize>();
+        let s = strip_address(&line, s, addr)?;
+        let (mnemonic, args) = s.split_once(char::is_whitespace).unwrap_or((s, ""));
+        let len = match mnemonic {
+            "db" => args.split(',').count(),
+            _ => 2,
+        };
+        statements.push((line, s, len));
+    }
+
+    let mut programs = vec![];
+
+    for (line, s, _) in statements {
+        let (mnemonic, args) = s.split_once(char::is_whitespace).unwrap_or((s, ""));
+        let args = args.trim();
+
+        if mnemonic == "db" {
+            for byte in args.split(',') {
+                programs.push(line.parse::<u8>(byte.trim())?);
+            }
+            continue;
+        }
+
+        let Some(opcode) = MNEMONICS.iter().position(|m| *m == mnemonic) else {
+            return Err(line.error(mnemonic, ParseErrorKind::Invalid(format!("unknown instruction {:?}", mnemonic))));
+        };
+        let opcode = opcode as u8;
+
+        if args.is_empty() {
+            return Err(line.expected_after(mnemonic, "operand"));
+        }
+
+        let operand = match (opcode, args) {
+            (3, label) if is_label(label) => match labels.get(label) {
+                Some(addr) => u8::try_from(*addr).map_err(|_| {
+                    line.error(label, ParseErrorKind::Invalid(format!("label {:?} at {} is out of range for jnz", label, addr)))
+                })?,
+                None => return Err(line.error(label, ParseErrorKind::Invalid(format!("undefined label {:?}", label)))),
+            },
+            (_, "A") if is_combo(opcode) => 4,
+            (_, "B") if is_combo(opcode) => 5,
+            (_, "C") if is_combo(opcode) => 6,
+            _ => {
+                let operand = line.parse::<u8>(args)?;
+                if operand > 7 || (is_combo(opcode) && (4..=6).contains(&operand)) {
+                    return Err(line.error(args, ParseErrorKind::Invalid(format!("operand {} out of range for {}", operand, mnemonic))));
+                }
+                operand
+            }
+        };
+
+        programs.extend([opcode, operand]);
+    }
+
+    Ok(programs)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::asm::*;
+
+    const INPUT: [u8; 16] = [2, 4, 1, 3, 7, 5, 1, 5, 0, 3, 4, 1, 5, 5, 3, 0];
+
+    #[test]
+    fn disassemble_input() {
+        assert_eq!("\
+L0:
+   0: bst A    ; B = A & 7
+   2: bxl 3    ; B = B ^ 3
+   4: cdv B    ; C = A >> B
+   6: bxl 5    ; B = B ^ 5
+   8: adv 3    ; A = A >> 3
+  10: bxc 1    ; B = B ^ C
+  12: out B    ; output B & 7
+  14: jnz L0   ; if A != 0 goto L0
+", disassemble(&INPUT));
+    }
+
+    #[test]
+    fn round_trip() -> Result<(), Box<dyn std::error::Error>> {
+        let programs = [
+            INPUT.to_vec(),
+            vec![0, 1, 5, 4, 3, 0],
+            vec![0, 3, 5, 4, 3, 0],
+            vec![3, 7, 5, 7, 3, 4, 0, 1],
+            vec![1, 7, 9, 2, 5],
+            vec![0, 9, 5, 4, 3, 8, 1, 255],
+        ];
+
+        for programs in programs {
+            let text = disassemble(&programs);
+            assert_eq!(programs, assemble(&Input::new("program.asm", &text))?, "{}", text);
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn assemble_source() -> Result<(), Box<dyn std::error::Error>> {
+        let input = Input::new("program.asm", "\
+; prints A in octal, lowest digit first
+loop:
+    adv 3
+    out A
+    jnz loop
+");
+        assert_eq!(vec![0, 3, 5, 4, 3, 0], assemble(&input)?);
+
+        let input = Input::new("program.asm", "   0: db 1, 2, 3\n   3: db 4\nend:\n   4: jnz end\n");
+        assert_eq!(vec![1, 2, 3, 4, 3, 4], assemble(&input)?);
+        Ok(())
+    }
+
+    #[test]
+    fn assemble_errors() {
+        let err = assemble(&Input::new("program.asm", "adv 3\nmul 2\n")).unwrap_err();
+        assert_eq!((2, 1, 3), (err.line, err.col, err.len));
+
+        let err = assemble(&Input::new("program.asm", "bst 4\n")).unwrap_err();
+        assert_eq!((1, 5), (err.line, err.col));
+
+        let err = assemble(&Input::new("program.asm", "jnz end\n")).unwrap_err();
+        assert_eq!("undefined label \"end\"", err.kind.to_string());
+
+        let err = assemble(&Input::new("program.asm", "   0: adv 3\n   4: out A\n")).unwrap_err();
+        assert_eq!((2, 4), (err.line, err.col));
+
+        let far = format!("{}far:\nout A\njnz far\n", "adv 1\n".repeat(128));
+        let err = assemble(&Input::new("program.asm", &far)).unwrap_err();
+        assert_eq!((131, 5), (err.line, err.col));
+        assert_eq!("label \"far\" at 256 is out of range for jnz", err.kind.to_string());
+
+        let err = assemble(&Input::new("program.asm", "out\n")).unwrap_err();
+        assert_eq!("expected \"operand\"", err.kind.to_string());
+    }
+}
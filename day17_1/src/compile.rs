@@ -0,0 +1,347 @@
+use std::{ops::Range, sync::atomic::{AtomicU64, Ordering}, thread};
+
+use crate::{shr, Budget, ExecError, Registers, Watchdog};
+
+const CHUNK: u64 = 1 << 12;
+
+#[derive(Clone, Copy, PartialEq, Debug)]
+pub enum Operand {
+    Lit(u64),
+    A,
+    B,
+    C,
+}
+
+impl Operand {
+    fn combo(operand: u8) -> Option<Self> {
+        match operand {
+            0..=3 | 7 => Some(Operand::Lit(operand as u64)),
+            4 => Some(Operand::A),
+            5 => Some(Operand::B),
+            6 => Some(Operand::C),
+            _ => None,
+        }
+    }
+
+    #[inline]
+    fn get(&self, regs: &Registers) -> u64 {
+        match *self {
+            Operand::Lit(value) => value,
+            Operand::A => regs.reg_a,
+            Operand::B => regs.reg_b,
+            Operand::C => regs.reg_c,
+        }
+    }
+}
+
+#[derive(Clone, Copy, PartialEq, Debug)]
+pub enum Instr {
+    Adv(Operand),
+    Bxl(u64),
+    Bst(Operand),
+    Jnz(usize),
+    Bxc,
+    Out(Operand),
+    Bdv(Operand),
+    Cdv(Operand),
+    Hlt,
+}
+
+impl Instr {
+    pub fn decode(opcode: u8, operand: u8) -> Self {
+        if opcode == 1 || opcode == 3 {
+            return match opcode {
+                1 => Instr::Bxl(operand as u64),
+                _ => Instr::Jnz(operand as usize),
+            };
+        }
+
+        let Some(combo) = Operand::combo(operand) else {
+            return Instr::Hlt;
+        };
+
+        match opcode {
+            0 => Instr::Adv(combo),
+            2 => Instr::Bst(combo),
+            4 => Instr::Bxc,
+            5 => Instr::Out(combo),
+            6 => Instr::Bdv(combo),
+            7 => Instr::Cdv(combo),
+            _ => Instr::Hlt,
+        }
+    }
+}
+
+// One pre-decoded instruction per byte offset, since `jnz` may land on an
+// odd address and read the operand of one instruction as the next opcode.
+#[derive(Clone, Debug)]
+pub struct Compiled {
+    pub instrs: Vec<Instr>,
+}
+
+impl Compiled {
+    pub fn new(programs: &[u8]) -> Self {
+        let instrs = (0..programs.len())
+            .map(|pc| match programs.get(pc..pc + 2) {
+                Some(&[opcode, operand]) => Instr::decode(opcode, operand),
+                _ => Instr::Hlt,
+            })
+            .collect();
+
+        Self { instrs }
+    }
+
+    pub fn halts(&self, pc: usize) -> bool {
+        matches!(self.instrs.get(pc), None | Some(Instr::Hlt))
+    }
+
+    #[inline]
+    pub fn step(&self, regs: &mut Registers, pc: usize, output: &mut Vec<u64>) -> Option<usize> {
+        match *self.instrs.get(pc)? {
+            Instr::Adv(operand) => regs.reg_a = shr(regs.reg_a, operand.get(regs)),
+            Instr::Bxl(operand) => regs.reg_b ^= operand,
+            Instr::Bst(operand) => regs.reg_b = operand.get(regs) % 8,
+            Instr::Jnz(target) => return Some(if regs.reg_a == 0 { pc + 2 } else { target }),
+            Instr::Bxc => regs.reg_b ^= regs.reg_c,
+            Instr::Out(operand) => output.push(operand.get(regs) % 8),
+            Instr::Bdv(operand) => regs.reg_b = shr(regs.reg_a, operand.get(regs)),
+            Instr::Cdv(operand) => regs.reg_c = shr(regs.reg_a, operand.get(regs)),
+            Instr::Hlt => return None,
+        }
+
+        Some(pc + 2)
+    }
+
+    pub fn exec(&self, regs: &mut Registers, output: &mut Vec<u64>) -> usize {
+        let len = output.len();
+        let mut pc = 0;
+
+        while let Some(next) = self.step(regs, pc, output) {
+            pc = next;
+        }
+
+        output.len() - len
+    }
+
+    pub fn exec_with(&self, regs: &mut Registers, output: &mut Vec<u64>, budget: &Budget) -> Result<usize, ExecError> {
+        let len = output.len();
+        let mut watchdog = Watchdog::new(budget);
+        let mut pc = 0;
+
+        while !self.halts(pc) {
+            watchdog.check(pc, regs)?;
+            let outputs = output.len() - len + matches!(self.instrs[pc], Instr::Out(_)) as usize;
+            watchdog.check_outputs(outputs, pc)?;
+
+            let Some(next) = self.step(regs, pc, output) else {
+                break;
+            };
+            pc = next;
+        }
+
+        Ok(output.len() - len)
+    }
+
+    // Like `exec` followed by a comparison, but stops at the first output
+    // that differs from `target`. Running out of `budget` counts as a mismatch.
+    pub fn matches(&self, mut regs: Registers, target: &[u64], output: &mut Vec<u64>, budget: &Budget) -> bool {
+        output.clear();
+        let mut watchdog = Watchdog::new(budget);
+        let mut pc = 0;
+
+        while !self.halts(pc) {
+            if watchdog.check(pc, &regs).is_err() {
+                return false;
+            }
+            let Some(next) = self.step(&mut regs, pc, output) else {
+                break;
+            };
+
+            if let Some(&last) = output.last() {
+                let i = output.len() - 1;
+                if i >= target.len() || last != target[i] {
+                    return false;
+                }
+            }
+            pc = next;
+        }
+
+        output.len() == target.len()
+    }
+
+    pub fn find_reg_a(&self, regs: &Registers, range: Range<u64>, target: &[u64], budget: &Budget) -> Option<u64> {
+        scan(range, threads(), Vec::new, |output, reg_a| {
+            self.matches(Registers { reg_a, ..regs.clone() }, target, output, budget)
+        })
+    }
+}
+
+pub fn threads() -> usize {
+    thread::available_parallelism().map_or(1, |n| n.get())
+}
+
+// Returns the smallest value in `range` accepted by `pred`, splitting the
+// range into chunks handed out to `threads` workers. Each worker keeps its
+// own scratch state built by `init`.
+pub fn scan<S, I, F>(range: Range<u64>, threads: usize, init: I, pred: F) -> Option<u64>
+    where I: Fn() -> S + Sync,
+          F: Fn(&mut S, u64) -> bool + Sync,
+{
+    let next = AtomicU64::new(range.start);
+    let found = AtomicU64::new(u64::MAX);
+
+    thread::scope(|s| {
+        for _ in 0..threads.max(1) {
+            s.spawn(|| {
+                let mut state = init();
+
+                loop {
+                    let claim = |start: u64| (start < range.end).then(|| start.saturating_add(CHUNK).min(range.end));
+                    let Ok(start) = next.fetch_update(Ordering::Relaxed, Ordering::Relaxed, claim) else {
+                        break;
+                    };
+                    if start >= found.load(Ordering::Relaxed) {
+                        break;
+                    }
+
+                    let end = start.saturating_add(CHUNK).min(range.end);
+                    if let Some(value) = (start..end).find(|value| pred(&mut state, *value)) {
+                        found.fetch_min(value, Ordering::Relaxed);
+                        break;
+                    }
+                }
+            });
+        }
+    });
+
+    Some(found.into_inner()).filter(|value| *value != u64::MAX)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::compile::*;
+    use crate::Computer;
+
+    const INPUT: [u8; 16] = [2, 4, 1, 3, 7, 5, 1, 5, 0, 3, 4, 1, 5, 5, 3, 0];
+
+    struct Rng(u64);
+
+    impl Rng {
+        fn next(&mut self, n: u64) -> u64 {
+            self.0 ^= self.0 << 13;
+            self.0 ^= self.0 >> 7;
+            self.0 ^= self.0 << 17;
+            self.0 % n
+        }
+
+        // mostly full-range values, with small ones mixed in so shifts land below 64 too
+        fn reg(&mut self) -> u64 {
+            match self.next(4) {
+                0 => self.next(64),
+                _ => self.next(u64::MAX),
+            }
+        }
+    }
+
+    fn random_program(rng: &mut Rng) -> Vec<u8> {
+        let len = 2 + rng.next(8) as usize * 2;
+
+        (0..len / 2)
+            .flat_map(|_| {
+                let opcode = rng.next(8) as u8;
+                let operand = match opcode {
+                    3 => rng.next(len as u64 - 1) as u8,
+                    _ => rng.next(8) as u8,
+                };
+                [opcode, operand]
+            })
+            .collect()
+    }
+
+    #[test]
+    fn exec_matches_interpreter() {
+        let mut rng = Rng(0x2024_1217);
+
+        for _ in 0..2000 {
+            let programs = random_program(&mut rng);
+            let regs = Registers { reg_a: rng.reg(), reg_b: rng.reg(), reg_c: rng.reg() };
+
+            let mut computer = Computer::new(regs.clone(), &programs);
+            let compiled = Compiled::new(&programs);
+            let (mut expected, mut output) = (vec![], vec![]);
+            let (mut regs, mut pc) = (regs, 0);
+
+            for _ in 0..500 {
+                let op = computer.next();
+                let next = compiled.step(&mut regs, pc, &mut output);
+
+                let Some(op) = op else {
+                    assert_eq!(None, next, "{:?}", programs);
+                    break;
+                };
+                computer.exec_op(op, &mut expected);
+
+                assert_eq!((&computer.regs, Some(computer.pc)), (&regs, next), "{:?}", programs);
+                pc = computer.pc;
+            }
+
+            assert_eq!(expected, output, "{:?}", programs);
+        }
+    }
+
+    #[test]
+    fn exec_with_matches_interpreter() {
+        let mut rng = Rng(0x2024_1225);
+        let budget = Budget { max_steps: Some(300), max_outputs: Some(20), timeout: None, detect_cycles: true };
+
+        for _ in 0..2000 {
+            let programs = random_program(&mut rng);
+            let regs = Registers { reg_a: rng.next(64), reg_b: rng.next(64), reg_c: rng.next(64) };
+
+            let mut computer = Computer::new(regs.clone(), &programs);
+            let (mut expected, mut output) = (vec![], vec![]);
+            let mut regs = regs;
+
+            assert_eq!(
+                computer.exec_with(&mut expected, &budget),
+                Compiled::new(&programs).exec_with(&mut regs, &mut output, &budget),
+                "{:?}", programs,
+            );
+            assert_eq!((computer.regs, expected), (regs, output), "{:?}", programs);
+        }
+    }
+
+    #[test]
+    fn exec_input() {
+        let compiled = Compiled::new(&INPUT);
+
+        for reg_a in [0, 7, 729, 117440, 1 << 40] {
+            let regs = Registers { reg_a, reg_b: 0, reg_c: 0 };
+            let (mut expected, mut output) = (vec![], vec![]);
+
+            let len = Computer::new(regs.clone(), &INPUT).exec(&mut expected);
+            assert_eq!(len, compiled.exec(&mut regs.clone(), &mut output));
+            assert_eq!(expected, output);
+
+            let budget = Budget::default();
+            assert!(compiled.matches(regs.clone(), &expected, &mut output, &budget));
+            assert!(!compiled.matches(regs, &expected[1..], &mut output, &budget));
+        }
+    }
+
+    #[test]
+    fn scan_smallest() {
+        assert_eq!(Some(1000), scan(0..1 << 20, 4, || (), |_, x| x >= 1000 && x % 1000 == 0));
+        assert_eq!(Some(5), scan(5..6, 8, || (), |_, _| true));
+        assert_eq!(None, scan(0..50_000, 3, || (), |_, x| x == 50_000));
+
+        // claiming chunks must not wrap around past the end of u64
+        assert_eq!(Some(u64::MAX - 100), scan(u64::MAX - 101..u64::MAX, 8, || (), |_, x| x % 2 == 1));
+        assert_eq!(None, scan(u64::MAX - 5000..u64::MAX, 8, || (), |_, x| x < u64::MAX - 5000));
+
+        let programs = [0, 3, 5, 4, 3, 0];
+        let regs = Registers { reg_a: 0, reg_b: 0, reg_c: 0 };
+        let target = programs.map(|x| x as u64);
+        assert_eq!(Some(117440), Compiled::new(&programs).find_reg_a(&regs, 0..1 << 20, &target, &Budget::default()));
+    }
+}
@@ -0,0 +1,364 @@
+use std::collections::BTreeSet;
+
+use crate::{asm, Budget, Computer, ExecError, Registers, Watchdog};
+
+const MAX_RUN_STEPS: usize = 1_000_000;
+
+#[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Debug)]
+pub enum Reg {
+    A,
+    B,
+    C,
+}
+
+impl Reg {
+    pub fn get(&self, regs: &Registers) -> u64 {
+        match self {
+            Reg::A => regs.reg_a,
+            Reg::B => regs.reg_b,
+            Reg::C => regs.reg_c,
+        }
+    }
+}
+
+impl std::str::FromStr for Reg {
+    type Err = DebugError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "a" | "A" => Ok(Reg::A),
+            "b" | "B" => Ok(Reg::B),
+            "c" | "C" => Ok(Reg::C),
+            _ => Err(DebugError::InvalidArgument(s.to_string())),
+        }
+    }
+}
+
+#[derive(PartialEq, Clone, Debug)]
+pub struct TraceEntry {
+    pub step: usize,
+    pub pc: usize,
+    pub instruction: String,
+    pub regs: Registers,
+    pub output: Option<u64>,
+}
+
+#[derive(PartialEq, Debug)]
+pub enum StopReason {
+    Stepped(usize),
+    Breakpoint(usize),
+    Watch { reg: Reg, old: u64, new: u64 },
+    Halted(usize),
+    Exec(ExecError),
+}
+impl std::fmt::Display for StopReason {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            StopReason::Stepped(pc) => write!(f, "stepped to pc {}", pc),
+            StopReason::Breakpoint(pc) => write!(f, "breakpoint at pc {}", pc),
+            StopReason::Watch { reg, old, new } => write!(f, "watch {:?}: {} -> {}", reg, old, new),
+            StopReason::Halted(pc) => write!(f, "halted at pc {}", pc),
+            StopReason::Exec(err) => write!(f, "stopped: {}", err),
+        }
+    }
+}
+
+#[derive(PartialEq, Debug)]
+pub enum DebugError {
+    UnknownCommand(String),
+    InvalidArgument(String),
+}
+impl std::fmt::Display for DebugError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            DebugError::UnknownCommand(command) => write!(f, "Unknown command: {}", command),
+            DebugError::InvalidArgument(arg) => write!(f, "Invalid argument: {}", arg),
+        }
+    }
+}
+impl std::error::Error for DebugError {}
+
+pub struct Debugger {
+    pub computer: Computer,
+    pub output: Vec<u64>,
+    pub breakpoints: BTreeSet<usize>,
+    pub watches: BTreeSet<Reg>,
+    pub trace: Vec<TraceEntry>,
+    pub budget: Budget,
+    // The pc execution last stopped at, whose breakpoint resuming skips.
+    stopped_at: Option<usize>,
+}
+
+impl Debugger {
+    pub fn new(computer: Computer) -> Self {
+        Self {
+            computer,
+            output: vec![],
+            breakpoints: BTreeSet::new(),
+            watches: BTreeSet::new(),
+            trace: vec![],
+            budget: Budget { max_steps: Some(MAX_RUN_STEPS), detect_cycles: true, ..Default::default() },
+            stopped_at: None,
+        }
+    }
+
+    pub fn step(&mut self) -> Option<&TraceEntry> {
+        let pc = self.computer.pc;
+        let instruction = match self.computer.programs.get(pc..pc + 2) {
+            Some(&[opcode, operand]) => asm::format_instruction(opcode, operand),
+            _ => String::new(),
+        };
+
+        let op = self.computer.next()?;
+        let len = self.output.len();
+        self.computer.exec_op(op, &mut self.output);
+        self.stopped_at = Some(self.computer.pc);
+
+        self.trace.push(TraceEntry {
+            step: self.trace.len(),
+            pc,
+            instruction,
+            regs: self.computer.regs.clone(),
+            output: self.output.get(len).copied(),
+        });
+        self.trace.last()
+    }
+
+    pub fn step_n(&mut self, n: usize) -> StopReason {
+        for _ in 0..n {
+            if let Some(reason) = self.step_checked() {
+                return self.stop(reason);
+            }
+        }
+
+        self.stop(StopReason::Stepped(self.computer.pc))
+    }
+
+    // Runs until a stop condition, or until `budget` runs out; each call
+    // starts a fresh budget.
+    pub fn run(&mut self) -> StopReason {
+        let budget = self.budget.clone();
+        let mut watchdog = Watchdog::new(&budget);
+
+        loop {
+            if let Err(err) = watchdog.check(self.computer.pc, &self.computer.regs) {
+                return self.stop(StopReason::Exec(err));
+            }
+            if let Some(reason) = self.step_checked() {
+                return self.stop(reason);
+            }
+        }
+    }
+
+    // Breakpoints fire before their instruction runs, except on the pc
+    // execution is resuming from.
+    fn step_checked(&mut self) -> Option<StopReason> {
+        let pc = self.computer.pc;
+        if self.breakpoints.contains(&pc) && self.stopped_at != Some(pc) {
+            return Some(StopReason::Breakpoint(pc));
+        }
+
+        let before = self.computer.regs.clone();
+        if self.step().is_none() {
+            return Some(StopReason::Halted(self.computer.pc));
+        }
+
+        let after = &self.computer.regs;
+        for reg in &self.watches {
+            let (old, new) = (reg.get(&before), reg.get(after));
+            if old != new {
+                return Some(StopReason::Watch { reg: *reg, old, new });
+            }
+        }
+
+        self.stopped_at = None;
+        None
+    }
+
+    fn stop(&mut self, reason: StopReason) -> StopReason {
+        self.stopped_at = Some(self.computer.pc);
+        reason
+    }
+
+    pub fn trace_csv(&self) -> String {
+        let mut lines = vec!["step,pc,instruction,a,b,c,output".to_string()];
+
+        for entry in &self.trace {
+            lines.push(format!("{},{},{},{},{},{},{}",
+                entry.step, entry.pc, entry.instruction,
+                entry.regs.reg_a, entry.regs.reg_b, entry.regs.reg_c,
+                entry.output.map_or(String::new(), |x| x.to_string()),
+            ));
+        }
+
+        lines.push(String::new());
+        lines.join("\n")
+    }
+
+    pub fn trace_json(&self) -> String {
+        let entries = self.trace.iter()
+            .map(|entry| format!(
+                "  {{\"step\": {}, \"pc\": {}, \"instruction\": {:?}, \"a\": {}, \"b\": {}, \"c\": {}, \"output\": {}}}",
+                entry.step, entry.pc, entry.instruction,
+                entry.regs.reg_a, entry.regs.reg_b, entry.regs.reg_c,
+                entry.output.map_or("null".to_string(), |x| x.to_string()),
+            ))
+            .collect::<Vec<_>>();
+
+        match entries.is_empty() {
+            true => "[]\n".to_string(),
+            false => format!("[\n{}\n]\n", entries.join(",\n")),
+        }
+    }
+
+    pub fn command(&mut self, line: &str) -> Result<String, DebugError> {
+        let mut args = line.split_whitespace();
+        let Some(command) = args.next() else {
+            return Ok(String::new());
+        };
+
+        let arg = args.next();
+        let parse_pc = |arg: Option<&str>| {
+            arg.and_then(|pc| pc.parse::<usize>().ok())
+                .ok_or_else(|| DebugError::InvalidArgument(arg.unwrap_or("").to_string()))
+        };
+
+        let text = match command {
+            "s" | "step" => {
+                let n = match arg {
+                    Some(n) => n.parse::<usize>().map_err(|_| DebugError::InvalidArgument(n.to_string()))?,
+                    None => 1,
+                };
+                self.step_n(n).to_string()
+            }
+            "c" | "continue" => self.run().to_string(),
+            "b" | "break" => {
+                let pc = parse_pc(arg)?;
+                self.breakpoints.insert(pc);
+                format!("breakpoint at pc {}", pc)
+            }
+            "d" | "delete" => {
+                let pc = parse_pc(arg)?;
+                self.breakpoints.remove(&pc);
+                format!("deleted breakpoint at pc {}", pc)
+            }
+            "w" | "watch" => {
+                let reg = arg.unwrap_or("").parse::<Reg>()?;
+                self.watches.insert(reg);
+                format!("watching {:?}", reg)
+            }
+            "r" | "regs" => {
+                let Registers { reg_a, reg_b, reg_c } = self.computer.regs;
+                format!("pc={} A={} B={} C={}", self.computer.pc, reg_a, reg_b, reg_c)
+            }
+            "o" | "output" => {
+                self.output.iter().map(|x| x.to_string()).collect::<Vec<_>>().join(",")
+            }
+            "t" | "trace" => match arg {
+                Some("csv") => return Ok(self.trace_csv()),
+                Some("json") | None => return Ok(self.trace_json()),
+                Some(format) => return Err(DebugError::InvalidArgument(format.to_string())),
+            },
+            _ => return Err(DebugError::UnknownCommand(command.to_string())),
+        };
+
+        Ok(text + "\n")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::debugger::*;
+
+    fn example() -> Debugger {
+        Debugger::new(Computer::new(Registers{ reg_a: 2024, reg_b: 0, reg_c: 0 }, &[0, 1, 5, 4, 3, 0]))
+    }
+
+    #[test]
+    fn step_and_breakpoint() {
+        let mut debugger = example();
+
+        let entry = debugger.step().cloned();
+        assert_eq!(Some(TraceEntry {
+            step: 0,
+            pc: 0,
+            instruction: "adv 1".into(),
+            regs: Registers{ reg_a: 1012, reg_b: 0, reg_c: 0 },
+            output: None,
+        }), entry);
+
+        debugger.breakpoints.insert(4);
+        assert_eq!(StopReason::Breakpoint(4), debugger.run());
+        assert_eq!(StopReason::Breakpoint(4), debugger.run());
+        assert_eq!(vec![4, 2], debugger.output);
+
+        debugger.breakpoints.clear();
+        assert_eq!(StopReason::Halted(6), debugger.run());
+        assert_eq!(vec![4, 2, 5, 6, 7, 7, 7, 7, 3, 1, 0], debugger.output);
+        assert_eq!(33, debugger.trace.len());
+    }
+
+    #[test]
+    fn breakpoint_at_start() {
+        let mut debugger = example();
+
+        debugger.breakpoints.insert(0);
+        assert_eq!(StopReason::Breakpoint(0), debugger.run());
+        assert!(debugger.trace.is_empty());
+        assert_eq!(StopReason::Breakpoint(0), debugger.run());
+        assert_eq!(vec![4], debugger.output);
+        assert_eq!(StopReason::Breakpoint(0), debugger.step_n(10));
+        assert_eq!(vec![4, 2], debugger.output);
+    }
+
+    #[test]
+    fn watch_register() {
+        let mut debugger = Debugger::new(Computer::new(Registers{ reg_a: 10, reg_b: 0, reg_c: 0 }, &[5, 4, 2, 4, 1, 7, 0, 3, 3, 0]));
+        debugger.watches.insert(Reg::B);
+
+        assert_eq!(StopReason::Watch{ reg: Reg::B, old: 0, new: 2 }, debugger.run());
+        assert_eq!(StopReason::Watch{ reg: Reg::B, old: 2, new: 5 }, debugger.run());
+        assert_eq!(6, debugger.computer.pc);
+    }
+
+    #[test]
+    fn run_budget() {
+        // bxl 1; jnz 0 never halts and B repeats every other pass
+        let mut debugger = Debugger::new(Computer::new(Registers{ reg_a: 1, reg_b: 0, reg_c: 0 }, &[1, 1, 3, 0]));
+
+        let StopReason::Exec(ExecError::Cycle { period: 4, .. }) = debugger.run() else {
+            panic!("the loop should be detected");
+        };
+        assert!(debugger.command("continue").unwrap().starts_with("stopped: infinite loop: pc "));
+
+        debugger.budget = Budget { max_steps: Some(10), ..Default::default() };
+        let pc = debugger.computer.pc;
+        assert_eq!(StopReason::Exec(ExecError::StepLimit { steps: 10, pc }), debugger.run());
+    }
+
+    #[test]
+    fn command_script() -> Result<(), Box<dyn std::error::Error>> {
+        let mut debugger = example();
+
+        let script = ["break 4", "continue", "regs", "step 2", "output", "trace csv"]
+            .map(|command| debugger.command(command));
+
+        assert_eq!(Ok("breakpoint at pc 4\n".into()), script[0]);
+        assert_eq!(Ok("breakpoint at pc 4\n".into()), script[1]);
+        assert_eq!(Ok("pc=4 A=1012 B=0 C=0\n".into()), script[2]);
+        assert_eq!(Ok("stepped to pc 2\n".into()), script[3]);
+        assert_eq!(Ok("4\n".into()), script[4]);
+        assert_eq!(Ok("\
+step,pc,instruction,a,b,c,output
+0,0,adv 1,1012,0,0,
+1,2,out A,1012,0,0,4
+2,4,jnz 0,1012,0,0,
+3,0,adv 1,506,0,0,
+".into()), script[5]);
+
+        assert!(debugger.trace_json().starts_with("[\n  {\"step\": 0, \"pc\": 0, \"instruction\": \"adv 1\", \"a\": 1012, \"b\": 0, \"c\": 0, \"output\": null},\n"));
+        assert_eq!(Err(DebugError::UnknownCommand("jump".into())), debugger.command("jump 4"));
+        assert_eq!(Err(DebugError::InvalidArgument("d".into())), debugger.command("watch d"));
+        Ok(())
+    }
+}
@@ -0,0 +1,488 @@
+pub mod asm;
+pub mod compile;
+pub mod debugger;
+
+use std::{path::Path, time::{Duration, Instant}};
+
+use aoc_input::{Input, Lines, ParseError};
+
+pub fn solve<P>(path: P) -> Result<String, Box<dyn std::error::Error>>
+    where P: AsRef<Path>
+{
+    solve_parsed(read_file(path)?)
+}
+
+pub fn solve_parsed((regs, programs): (Registers, Vec<u8>)) -> Result<String, Box<dyn std::error::Error>> {
+    let mut computer = Computer::new(regs, &programs);
+    let mut output = vec![];
+
+    computer.exec_with(&mut output, &Budget { detect_cycles: true, ..Default::default() })?;
+
+    let output = output.into_iter()
+        .map(|x| x.to_string())
+        .collect::<Vec<_>>()
+        .join(",")
+    ;
+
+    Ok(output)
+}
+
+#[derive(PartialEq, Clone, Debug)]
+pub struct Registers {
+    pub reg_a: u64,
+    pub reg_b: u64,
+    pub reg_c: u64,
+}
+
+#[derive(Clone, Default, Debug)]
+pub struct Budget {
+    pub max_steps: Option<usize>,
+    pub max_outputs: Option<usize>,
+    pub timeout: Option<Duration>,
+    pub detect_cycles: bool,
+}
+
+#[derive(PartialEq, Debug)]
+pub enum ExecError {
+    StepLimit { steps: usize, pc: usize },
+    OutputLimit { outputs: usize, pc: usize },
+    Timeout { elapsed: Duration, steps: usize, pc: usize },
+    Cycle { pc: usize, regs: Registers, period: usize, steps: usize },
+}
+impl std::fmt::Display for ExecError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ExecError::StepLimit { steps, pc } => write!(f, "step limit of {} reached at pc {}", steps, pc),
+            ExecError::OutputLimit { outputs, pc } => write!(f, "output limit of {} reached at pc {}", outputs, pc),
+            ExecError::Timeout { elapsed, steps, pc } => {
+                write!(f, "timed out after {:?} ({} steps) at pc {}", elapsed, steps, pc)
+            }
+            ExecError::Cycle { pc, regs, period, steps } => {
+                write!(f, "infinite loop: pc {} with A={} B={} C={} repeats every {} steps (after {} steps)",
+                    pc, regs.reg_a, regs.reg_b, regs.reg_c, period, steps)
+            }
+        }
+    }
+}
+impl std::error::Error for ExecError {}
+
+const TIMEOUT_INTERVAL: usize = 4096;
+
+// Enforces a `Budget` one instruction at a time. Cycles are found with
+// Brent's algorithm, so only a single saved `(pc, regs)` is kept.
+pub(crate) struct Watchdog<'a> {
+    budget: &'a Budget,
+    start: Instant,
+    steps: usize,
+    saved: Option<(usize, Registers)>,
+    power: usize,
+    lam: usize,
+}
+
+impl<'a> Watchdog<'a> {
+    pub(crate) fn new(budget: &'a Budget) -> Self {
+        Self {
+            budget,
+            start: Instant::now(),
+            steps: 0,
+            saved: None,
+            power: 1,
+            lam: 1,
+        }
+    }
+
+    // Called before executing the instruction at `pc`.
+    pub(crate) fn check(&mut self, pc: usize, regs: &Registers) -> Result<(), ExecError> {
+        if self.budget.max_steps.is_some_and(|max| self.steps >= max) {
+            return Err(ExecError::StepLimit { steps: self.steps, pc });
+        }
+
+        if let Some(timeout) = self.budget.timeout {
+            if self.steps.is_multiple_of(TIMEOUT_INTERVAL) && self.start.elapsed() > timeout {
+                return Err(ExecError::Timeout { elapsed: self.start.elapsed(), steps: self.steps, pc });
+            }
+        }
+
+        if self.budget.detect_cycles {
+            if let Some((saved_pc, saved_regs)) = &self.saved {
+                if *saved_pc == pc && saved_regs == regs {
+                    return Err(ExecError::Cycle { pc, regs: regs.clone(), period: self.lam, steps: self.steps });
+                }
+            }
+            if self.lam == self.power {
+                self.saved = Some((pc, regs.clone()));
+                self.power *= 2;
+                self.lam = 0;
+            }
+            self.lam += 1;
+        }
+
+        self.steps += 1;
+        Ok(())
+    }
+
+    pub(crate) fn check_outputs(&self, outputs: usize, pc: usize) -> Result<(), ExecError> {
+        match self.budget.max_outputs {
+            Some(max) if outputs > max => Err(ExecError::OutputLimit { outputs: max, pc }),
+            _ => Ok(()),
+        }
+    }
+}
+
+#[derive(PartialEq, Debug)]
+pub enum Opcode {
+    Adv(u64),
+    Bxl(u64),
+    Bst(u64),
+    Jnz(u64),
+    Bxc(u64),
+    Out(u64),
+    Bdv(u64),
+    Cdv(u64),
+    Hlt,
+}
+
+#[derive(Clone)]
+pub struct Computer {
+    pub regs: Registers,
+    pub programs: Vec<u8>,
+    pub pc: usize,
+}
+
+impl Computer {
+    pub fn new(regs: Registers, programs: &[u8]) -> Self {
+        Self {
+            regs,
+            programs: programs.to_vec(),
+            pc: 0,
+        }
+    }
+
+    pub fn decode(&mut self) -> Option<Opcode> {
+        // a `jnz` to an odd address can leave a lone byte at the end
+        let Some(&[opcode, operand]) = self.programs.get(self.pc..self.pc + 2) else {
+            return None;
+        };
+        let operand = operand as u64;
+
+        let operand = if (opcode == 1) || (opcode == 3) {
+            operand
+        }
+        else {
+            match operand {
+                0_u64..=3_u64 | 7 => operand,
+                4_u64 => self.regs.reg_a,
+                5_u64 => self.regs.reg_b,
+                6_u64 => self.regs.reg_c,
+                _ => return None,
+            }
+        };
+
+        let opcode = match opcode {
+            0 => Opcode::Adv(operand),
+            1 => Opcode::Bxl(operand),
+            2 => Opcode::Bst(operand),
+            3 => Opcode::Jnz(operand),
+            4 => Opcode::Bxc(operand),
+            5 => Opcode::Out(operand),
+            6 => Opcode::Bdv(operand),
+            7 => Opcode::Cdv(operand),
+            _ => Opcode::Hlt,
+        };
+
+        match opcode {
+            Opcode::Jnz(_) => Some(opcode),
+            Opcode::Hlt => None,
+            _ => {
+                self.pc += 2;
+                Some(opcode)
+            }
+        }
+    }
+
+    pub fn exec(&mut self, output: &mut Vec<u64>) -> usize {
+        let mut len: usize = 0;
+
+        while let Some(op) = self.next() {
+            len += self.exec_op(op, output);
+        }
+
+        len
+    } 
+
+    // Like `exec`, but stops with an error once `budget` is exhausted. On
+    // error `pc` is left at the instruction that was not executed, so calling
+    // it again resumes without losing output.
+    pub fn exec_with(&mut self, output: &mut Vec<u64>, budget: &Budget) -> Result<usize, ExecError> {
+        let len = output.len();
+        let mut watchdog = Watchdog::new(budget);
+
+        loop {
+            let pc = self.pc;
+            let Some(op) = self.next() else {
+                return Ok(output.len() - len);
+            };
+
+            let outputs = output.len() - len + matches!(op, Opcode::Out(_)) as usize;
+            if let Err(err) = watchdog.check(pc, &self.regs).and_then(|_| watchdog.check_outputs(outputs, pc)) {
+                self.pc = pc;
+                return Err(err);
+            }
+
+            self.exec_op(op, output);
+        }
+    }
+
+    pub fn exec_op(&mut self, op: Opcode, output: &mut Vec<u64>) -> usize {
+        match op {
+            Opcode::Adv(operand) => self.exec_adv(operand),
+            Opcode::Bxl(operand) => self.exec_bxl(operand),
+            Opcode::Bst(operand) => self.exec_bst(operand),
+            Opcode::Jnz(operand) => self.exec_jnz(operand),
+            Opcode::Bxc(_) => self.exec_bxc(),
+            Opcode::Out(operand) => return self.exec_out(operand, output),
+            Opcode::Bdv(operand) => self.exec_bdv(operand),
+            Opcode::Cdv(operand) => self.exec_cdv(operand),
+            Opcode::Hlt => {},
+        }
+
+        0
+    }
+
+    pub fn exec_adv(&mut self, operand: u64) {
+        self.regs.reg_a = shr(self.regs.reg_a, operand);
+    }
+
+    pub fn exec_bxl(&mut self, operand: u64) {
+        self.regs.reg_b ^= operand;
+    }
+
+    pub fn exec_bst(&mut self, operand: u64) {
+        self.regs.reg_b = operand % 8;
+    }
+
+    pub fn exec_jnz(&mut self, operand: u64) {
+            if self.regs.reg_a == 0 {
+            self.pc += 2;
+        }
+        else {
+            self.pc = operand as usize;
+        }
+    }
+
+    pub fn exec_bxc(&mut self) {
+        self.regs.reg_b ^= self.regs.reg_c;
+    }
+
+    pub fn exec_out(&mut self, operand: u64, output: &mut Vec<u64>) -> usize {
+        output.push(operand % 8);
+        1
+    }
+
+    pub fn exec_bdv(&mut self, operand: u64) {
+        self.regs.reg_b = shr(self.regs.reg_a, operand);
+    }
+
+    pub fn exec_cdv(&mut self, operand: u64) {
+        self.regs.reg_c = shr(self.regs.reg_a, operand);
+    }
+}
+
+// A / 2^n, without the overflow `u64::pow` hits once n reaches 64.
+#[inline]
+fn shr(value: u64, n: u64) -> u64 {
+    u32::try_from(n).ok().and_then(|n| value.checked_shr(n)).unwrap_or(0)
+}
+
+impl Iterator for Computer {
+    type Item = Opcode;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.decode()
+    }
+}
+
+pub fn read_file<P>(path: P) -> Result<(Registers, Vec<u8>), ParseError> 
+    where P: AsRef<Path>
+{
+    let input = Input::read_file(path)?;
+    let mut lines = input.lines();
+
+    let regs = Registers {
+        reg_a: read_reg(&mut lines, "A")?,
+        reg_b: read_reg(&mut lines, "B")?,
+        reg_c: read_reg(&mut lines, "C")?,
+    };
+    let _ = lines.next();
+
+    let programs = read_program(&mut lines)?;
+
+    Ok((regs, programs))
+}
+
+pub fn read_reg(lines: &mut Lines<'_>, name: &str) -> Result<u64, ParseError> {
+    let prefix = format!("Register {}:", name);
+    let line = lines.expect(&format!("{:?}", prefix))?;
+
+    let value = line.strip_prefix(line.text.trim(), &prefix)?;
+
+    line.parse::<u64>(value.trim())
+}
+
+pub fn read_program(lines: &mut Lines<'_>) -> Result<Vec<u8>, ParseError> {
+    let line = lines.expect("\"Program:\"")?;
+
+    let codes = line.strip_prefix(line.text.trim(), "Program:")?;
+
+    codes.trim().split(',')
+        .map(|op| line.parse::<u8>(op))
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::*;
+
+    #[test]
+    fn solve_example() -> Result<(), Box<dyn std::error::Error>> {
+        assert_eq!("4,6,3,5,6,3,5,2,1,0", &solve("./aoc_input_example.txt")?);
+        Ok(())
+    }
+
+    #[test]
+    fn read_file_example() -> Result<(), Box<dyn std::error::Error>> {
+        let (registers, programs) = read_file("aoc_input_example.txt")?;
+
+        assert_eq!(Registers{ reg_a: 729, reg_b: 0, reg_c: 0 }, registers);
+        assert_eq!(vec![0,1,5,4,3,0], programs);
+        Ok(())
+    }
+
+    #[test]
+    fn decode_example() -> Result<(), Box<dyn std::error::Error>> {
+        let regs = Registers {
+            reg_a: 1000,
+            reg_b: 16,
+            reg_c: 32,
+        };
+        let mut decoder = Computer::new(regs, &[0, 1, 1, 2, 2, 3, 4, 4, 5, 6, 6, 7, 7, 5, 3, 4]);
+
+        assert_eq!(Some(Opcode::Adv(1)), decoder.next());
+        assert_eq!(Some(Opcode::Bxl(2)), decoder.next());
+        assert_eq!(Some(Opcode::Bst(3)), decoder.next());
+        assert_eq!(Some(Opcode::Bxc(1000)), decoder.next());
+        assert_eq!(Some(Opcode::Out(32)), decoder.next());
+        assert_eq!(Some(Opcode::Bdv(7)), decoder.next());
+        assert_eq!(Some(Opcode::Cdv(16)), decoder.next());
+        assert_eq!(Some(Opcode::Jnz(4)), decoder.next());
+        Ok(())
+    }
+
+    #[test]
+    fn exec_example() -> Result<(), Box<dyn std::error::Error>> {
+        let mut output = Vec::<u64>::new();
+        let mut write_len: usize = 0;
+
+        write_len += {
+            let mut computer = Computer::new(Registers{ reg_a: 0, reg_b: 0, reg_c: 9}, &[2, 6]);
+            let len = computer.exec(&mut output);
+            assert_eq!(0, len);
+            assert_eq!(1, computer.regs.reg_b);
+            assert_eq!(&Vec::<u64>::new(), &output[write_len..]);
+            len
+        };
+        write_len += {
+            let mut computer = Computer::new(Registers{ reg_a: 10, reg_b: 0, reg_c: 0}, &[5,0,5,1,5,4]);
+            let len = computer.exec(&mut output);
+            assert_eq!(3, len);
+            assert_eq!(vec![0, 1, 2], output[write_len..]);
+            len
+        };
+        let _ =  {
+            let mut computer = Computer::new(Registers{ reg_a: 2024, reg_b: 0, reg_c: 0}, &[0,1,5,4,3,0]);
+            let len = computer.exec(&mut output);
+            assert_eq!(11, len);
+            assert_eq!(vec![4, 2, 5, 6, 7, 7, 7, 7, 3, 1, 0], output[write_len..]);
+            len
+        };
+        let _ = {
+            let mut computer = Computer::new(Registers{ reg_a: 0, reg_b: 29, reg_c: 0}, &[1, 7]);
+            let len = computer.exec(&mut output);
+            assert_eq!(0, len);
+            assert_eq!(26, computer.regs.reg_b);
+            len
+        };
+        let _ = {
+            let mut computer = Computer::new(Registers{ reg_a: 0, reg_b: 2024, reg_c: 43690}, &[4, 0]);
+            let len = computer.exec(&mut output);
+            assert_eq!(0, len);
+            assert_eq!(44354, computer.regs.reg_b);
+            len
+        };
+        let _ = {
+            let mut computer = Computer::new(Registers{ reg_a: 999, reg_b: 0, reg_c: 0}, &[6, 1]);
+            let len = computer.exec(&mut output);
+            assert_eq!(0, len);
+            assert_eq!(999, computer.regs.reg_a);
+            assert_eq!(499, computer.regs.reg_b);
+            assert_eq!(0, computer.regs.reg_c);
+            len
+        };
+        let _ = {
+            let mut computer = Computer::new(Registers{ reg_a: 999, reg_b: 0, reg_c: 0}, &[7, 1]);
+            let len = computer.exec(&mut output);
+            assert_eq!(0, len);
+            assert_eq!(999, computer.regs.reg_a);
+            assert_eq!(0, computer.regs.reg_b);
+            assert_eq!(499, computer.regs.reg_c);
+            len
+        };
+
+        Ok(())
+    }
+
+    #[test]
+    fn exec_with_budget() {
+        let regs = Registers { reg_a: 2024, reg_b: 0, reg_c: 0 };
+        let mut output = vec![];
+
+        let mut computer = Computer::new(regs.clone(), &[0, 1, 5, 4, 3, 0]);
+        let budget = Budget { max_steps: Some(10), ..Default::default() };
+        assert_eq!(Err(ExecError::StepLimit { steps: 10, pc: 2 }), computer.exec_with(&mut output, &budget));
+        assert_eq!(vec![4, 2, 5], output);
+        assert_eq!(Ok(8), computer.exec_with(&mut output, &Budget::default()));
+        assert_eq!(vec![4, 2, 5, 6, 7, 7, 7, 7, 3, 1, 0], output);
+
+        output.clear();
+        let mut computer = Computer::new(regs.clone(), &[0, 1, 5, 4, 3, 0]);
+        let budget = Budget { max_outputs: Some(3), ..Default::default() };
+        assert_eq!(Err(ExecError::OutputLimit { outputs: 3, pc: 2 }), computer.exec_with(&mut output, &budget));
+        assert_eq!(vec![4, 2, 5], output);
+        assert_eq!(2, computer.pc);
+        assert_eq!(Ok(8), computer.exec_with(&mut output, &Budget::default()));
+        assert_eq!(vec![4, 2, 5, 6, 7, 7, 7, 7, 3, 1, 0], output);
+
+        output.clear();
+        let mut computer = Computer::new(regs.clone(), &[1, 1, 5, 5, 3, 0]);
+        let budget = Budget { detect_cycles: true, ..Default::default() };
+        let err = computer.exec_with(&mut output, &budget).unwrap_err();
+        assert!(matches!(err, ExecError::Cycle { period: 6, .. }), "{}", err);
+        assert!(output.len() < 20);
+
+        let mut computer = Computer::new(regs, &[3, 0]);
+        let budget = Budget { timeout: Some(Duration::ZERO), ..Default::default() };
+        assert!(matches!(computer.exec_with(&mut output, &budget), Err(ExecError::Timeout { steps: 0, pc: 0, .. })));
+    }
+
+    #[test]
+    fn exec_example_p2() -> Result<(), Box<dyn std::error::Error>> {
+        let mut output = Vec::<u64>::new();
+
+        let mut computer = Computer::new(Registers{ reg_a: 117440, reg_b: 0, reg_c: 0}, &[0, 3, 5, 4, 3, 0]);
+        let len = computer.exec(&mut output);
+        assert_eq!(6, len);
+        assert_eq!(&vec![0, 3, 5, 4, 3, 0], &output);
+
+        Ok(())
+    }
+}
\ No newline at end of file
@@ -0,0 +1,79 @@
+pub mod search;
+
+use std::path::Path;
+
+use aoc_input::{Input, Lines, ParseError};
+
+use crate::search::SearchError;
+
+pub fn solve<P>(path: P) -> Result<Option<String>, Box<dyn std::error::Error>>
+    where P: AsRef<Path>
+{
+    solve_parsed(read_file(path)?)
+}
+
+pub fn solve_parsed((regs, programs): (Registers, Vec<u8>)) -> Result<Option<String>, Box<dyn std::error::Error>> {
+    let regs = day17_1::Registers { reg_a: regs.reg_a, reg_b: regs.reg_b, reg_c: regs.reg_c };
+    let target = programs.iter().map(|x| *x as u64).collect::<Vec<_>>();
+
+    match search::find_reg_a(&regs, &programs, &target, search::DEFAULT_LIMIT) {
+        Ok((reg_a, _)) => Ok(Some(reg_a.to_string())),
+        Err(SearchError::NoSolution) => Ok(None),
+        Err(err) => Err(Box::new(err)),
+    }
+}
+
+#[derive(PartialEq, Clone, Debug)]
+pub struct Registers {
+    pub reg_a: u64,
+    pub reg_b: u64,
+    pub reg_c: u64,
+}
+
+pub fn read_file<P>(path: P) -> Result<(Registers, Vec<u8>), ParseError> 
+    where P: AsRef<Path>
+{
+    let input = Input::read_file(path)?;
+    let mut lines = input.lines();
+
+    let regs = Registers {
+        reg_a: read_reg(&mut lines, "A")?,
+        reg_b: read_reg(&mut lines, "B")?,
+        reg_c: read_reg(&mut lines, "C")?,
+    };
+    let _ = lines.next();
+
+    let programs = read_program(&mut lines)?;
+
+    Ok((regs, programs))
+}
+
+pub fn read_reg(lines: &mut Lines<'_>, name: &str) -> Result<u64, ParseError> {
+    let prefix = format!("Register {}:", name);
+    let line = lines.expect(&format!("{:?}", prefix))?;
+
+    let value = line.strip_prefix(line.text.trim(), &prefix)?;
+
+    line.parse::<u64>(value.trim())
+}
+
+pub fn read_program(lines: &mut Lines<'_>) -> Result<Vec<u8>, ParseError> {
+    let line = lines.expect("\"Program:\"")?;
+
+    let codes = line.strip_prefix(line.text.trim(), "Program:")?;
+
+    codes.trim().split(',')
+        .map(|op| line.parse::<u8>(op))
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::*;
+
+    #[test]
+    fn solve_example() -> Result<(), Box<dyn std::error::Error>> {
+        assert_eq!(Some("117440".into()), solve("./aoc_input_example.txt")?);
+        Ok(())
+    }
+}
\ No newline at end of file
@@ -0,0 +1,445 @@
+use day17_1::{compile::{Compiled, Instr, Operand}, Budget, Registers};
+
+pub const DEFAULT_LIMIT: u64 = 1 << 20;
+const MAX_STEPS: usize = 100_000;
+const MAX_SYMBOLIC_STEPS: usize = 1 << 22;
+
+#[derive(PartialEq, Debug)]
+pub enum Method {
+    Symbolic,
+    Bounded(u64),
+}
+
+#[derive(PartialEq, Debug)]
+pub enum SearchError {
+    NotSupported { reason: String, searched: u64 },
+    NoSolution,
+}
+impl std::fmt::Display for SearchError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            SearchError::NotSupported { reason, searched } => {
+                write!(f, "program not supported ({}) and no reg_a below {} matches", reason, searched)
+            }
+            SearchError::NoSolution => write!(f, "no initial reg_a produces the requested output"),
+        }
+    }
+}
+impl std::error::Error for SearchError {}
+
+// One bit of a register as an affine function of the bits of the initial
+// A: the XOR of the A bits in `vars`, flipped when `one` is set.
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+struct Bit {
+    vars: u64,
+    one: bool,
+}
+
+const ZERO: Bit = Bit { vars: 0, one: false };
+
+type Word = [Bit; 64];
+
+fn var(i: u32) -> Bit {
+    match i {
+        0..64 => Bit { vars: 1 << i, one: false },
+        _ => ZERO,
+    }
+}
+
+fn constant(value: u64) -> Word {
+    std::array::from_fn(|j| Bit { vars: 0, one: value >> j & 1 == 1 })
+}
+
+// A shifted right by `shift`; bits past the top of the initial A are zero.
+fn a_word(shift: u32) -> Word {
+    std::array::from_fn(|j| var(shift + j as u32))
+}
+
+fn low_bits(word: &Word) -> Word {
+    std::array::from_fn(|j| if j < 3 { word[j] } else { ZERO })
+}
+
+// Linear equations `form == 0` over the bits of the initial A, in echelon
+// form: `rows[p]` has `p` as its highest variable.
+#[derive(Clone)]
+struct System {
+    rows: [Bit; 64],
+    pivots: u64,
+}
+
+impl System {
+    fn new() -> Self {
+        Self { rows: [ZERO; 64], pivots: 0 }
+    }
+
+    fn reduce(&self, mut form: Bit) -> Bit {
+        while form.vars & self.pivots != 0 {
+            let p = 63 - (form.vars & self.pivots).leading_zeros();
+            let row = self.rows[p as usize];
+            form = Bit { vars: form.vars ^ row.vars, one: form.one ^ row.one };
+        }
+        form
+    }
+
+    // Adds `form == value`, returning false if it contradicts the system.
+    fn assume(&mut self, form: Bit, value: bool) -> bool {
+        let form = self.reduce(Bit { one: form.one ^ value, ..form });
+        if form.vars == 0 {
+            return !form.one;
+        }
+
+        let p = 63 - form.vars.leading_zeros();
+        self.rows[p as usize] = form;
+        self.pivots |= 1 << p;
+        true
+    }
+
+    fn assume_all<I>(&mut self, equations: I) -> bool
+        where I: IntoIterator<Item = (Bit, bool)>
+    {
+        equations.into_iter().all(|(form, value)| self.assume(form, value))
+    }
+
+    // The smallest A of bit length `len` that satisfies the system, fixing
+    // bits from the top down to 0 wherever the system allows.
+    fn smallest(&self, len: u32) -> Option<u64> {
+        let mut system = self.clone();
+        let top = (len > 0).then(|| (var(len - 1), true));
+        if !system.assume_all((len..64).map(|i| (var(i), false)).chain(top)) {
+            return None;
+        }
+
+        let mut reg_a = 0;
+        for i in (0..64).rev() {
+            let form = system.reduce(var(i));
+            if form.vars == 0 {
+                reg_a |= (form.one as u64) << i;
+            } else {
+                system.assume(form, false);
+            }
+        }
+        Some(reg_a)
+    }
+}
+
+// One path through the program: A is the initial A shifted right by
+// `shift`, whose bit length lies in `len`, and `system` holds the choices
+// made to get here.
+#[derive(Clone)]
+struct State {
+    pc: usize,
+    shift: u32,
+    b: Word,
+    c: Word,
+    system: System,
+    len: (u32, u32),
+    outputs: usize,
+    steps: usize,
+}
+
+// Runs the program on an unknown A, one bit-vector per register. Every
+// operation is exact over GF(2) except shifts by a register and `jnz`,
+// which split the path on the value they depend on; each output digit adds
+// three equations, which prunes paths that can no longer match `target`.
+struct Symbolic<'a> {
+    compiled: Compiled,
+    target: &'a [u64],
+    best: Option<u64>,
+    steps: usize,
+    max_steps: usize,
+}
+
+impl Symbolic<'_> {
+    fn word(&self, state: &State, operand: Operand) -> Word {
+        match operand {
+            Operand::Lit(value) => constant(value),
+            Operand::A => a_word(state.shift),
+            Operand::B => state.b,
+            Operand::C => state.c,
+        }
+    }
+
+    // Every shift amount `operand` can take on this path, capped at 64
+    // since larger shifts all clear the register, with the system that
+    // selects it.
+    fn shifts(&self, state: &State, operand: Operand) -> Vec<(u32, System)> {
+        let word = self.word(state, operand);
+        let mut shifts = vec![];
+
+        let mut small = state.system.clone();
+        if small.assume_all(word[6..].iter().map(|bit| (*bit, false))) {
+            for value in 0..64 {
+                let mut system = small.clone();
+                if system.assume_all(word[..6].iter().enumerate().map(|(j, bit)| (*bit, value >> j & 1 == 1))) {
+                    shifts.push((value, system));
+                }
+            }
+        }
+
+        // split by the highest set bit above the low six
+        for i in 6..64 {
+            let mut system = state.system.clone();
+            if system.assume(word[i], true) && system.assume_all(word[i + 1..].iter().map(|bit| (*bit, false))) {
+                shifts.push((64, system));
+            }
+        }
+
+        shifts
+    }
+
+    // Follows one path until it ends or splits, pushing the branches on
+    // `paths`.
+    fn advance(&mut self, mut state: State, paths: &mut Vec<State>) -> Result<(), String> {
+        loop {
+            let Some(&instr) = self.compiled.instrs.get(state.pc).filter(|instr| **instr != Instr::Hlt) else {
+                if state.outputs == self.target.len() {
+                    let found = (state.len.0..=state.len.1).find_map(|len| state.system.smallest(len));
+                    self.best = match (self.best, found) {
+                        (Some(best), Some(found)) => Some(best.min(found)),
+                        (best, found) => best.or(found),
+                    };
+                }
+                return Ok(());
+            };
+
+            if state.steps >= MAX_STEPS {
+                return Ok(());
+            }
+            if self.steps >= self.max_steps {
+                return Err(format!("symbolic search gave up after {} steps", self.steps));
+            }
+            state.steps += 1;
+            self.steps += 1;
+
+            // A has at least `len.0` bits from here on
+            if let Some(best) = self.best {
+                if state.len.0 > 0 && 1 << (state.len.0 - 1) > best {
+                    return Ok(());
+                }
+            }
+
+            match instr {
+                Instr::Adv(operand) | Instr::Bdv(operand) | Instr::Cdv(operand) => {
+                    for (shift, system) in self.shifts(&state, operand) {
+                        let shift = (state.shift + shift).min(64);
+                        let mut next = State { pc: state.pc + 2, system, ..state.clone() };
+                        match instr {
+                            Instr::Adv(_) => next.shift = shift,
+                            Instr::Bdv(_) => next.b = a_word(shift),
+                            _ => next.c = a_word(shift),
+                        }
+                        paths.push(next);
+                    }
+                    return Ok(());
+                }
+                Instr::Bxl(operand) => {
+                    state.b.iter_mut().zip(constant(operand)).for_each(|(b, bit)| b.one ^= bit.one);
+                }
+                Instr::Bst(operand) => state.b = low_bits(&self.word(&state, operand)),
+                Instr::Bxc => {
+                    state.b.iter_mut().zip(state.c).for_each(|(b, c)| *b = Bit { vars: b.vars ^ c.vars, one: b.one ^ c.one });
+                }
+                Instr::Out(operand) => {
+                    let Some(digit) = self.target.get(state.outputs) else {
+                        return Ok(());
+                    };
+                    let word = self.word(&state, operand);
+                    if !state.system.assume_all((0..3).map(|j| (word[j], digit >> j & 1 == 1))) {
+                        return Ok(());
+                    }
+                    state.outputs += 1;
+                }
+                Instr::Jnz(target) => {
+                    // A != 0 once A keeps a bit at or above `shift`
+                    if state.shift < 64 && state.len.1 > state.shift {
+                        let len = (state.len.0.max(state.shift + 1), state.len.1);
+                        paths.push(State { pc: target, len, ..state.clone() });
+                    }
+
+                    if !state.system.assume_all((state.shift..64).map(|i| (var(i), false))) {
+                        return Ok(());
+                    }
+                    state.len.1 = state.len.1.min(state.shift);
+                    if state.len.0 > state.len.1 {
+                        return Ok(());
+                    }
+                }
+                Instr::Hlt => unreachable!(),
+            }
+
+            state.pc += 2;
+        }
+    }
+}
+
+// The smallest initial A whose output is exactly `target`, found by
+// symbolic execution over the bits of A. Fails if the search takes more
+// than `max_steps` symbolic steps in total.
+fn symbolic(regs: &Registers, programs: &[u8], target: &[u64], max_steps: usize) -> Result<Option<u64>, String> {
+    if target.iter().any(|digit| *digit > 7) {
+        return Ok(None);
+    }
+
+    let mut search = Symbolic { compiled: Compiled::new(programs), target, best: None, steps: 0, max_steps };
+    let mut paths = vec![State {
+        pc: 0,
+        shift: 0,
+        b: constant(regs.reg_b),
+        c: constant(regs.reg_c),
+        system: System::new(),
+        len: (0, 64),
+        outputs: 0,
+        steps: 0,
+    }];
+
+    while let Some(state) = paths.pop() {
+        search.advance(state, &mut paths)?;
+    }
+    Ok(search.best)
+}
+
+pub struct Runner {
+    compiled: Compiled,
+    regs: Registers,
+    budget: Budget,
+    output: Vec<u64>,
+}
+
+impl Runner {
+    pub fn new(regs: Registers, programs: &[u8]) -> Self {
+        Self {
+            compiled: Compiled::new(programs),
+            regs,
+            budget: Budget { max_steps: Some(MAX_STEPS), detect_cycles: true, ..Default::default() },
+            output: vec![],
+        }
+    }
+
+    // Runs with the given reg_a and reports whether the output equals
+    // `target`, giving up as soon as it diverges or the budget runs out.
+    pub fn matches(&mut self, reg_a: u64, target: &[u64]) -> bool {
+        let regs = Registers { reg_a, ..self.regs.clone() };
+        self.compiled.matches(regs, target, &mut self.output, &self.budget)
+    }
+}
+
+// Solves symbolically, and only scans `0..limit` when the symbolic search
+// runs out of steps.
+pub fn find_reg_a(regs: &Registers, programs: &[u8], target: &[u64], limit: u64) -> Result<(u64, Method), SearchError> {
+    match symbolic(regs, programs, target, MAX_SYMBOLIC_STEPS) {
+        Ok(reg_a) => reg_a.map(|reg_a| (reg_a, Method::Symbolic)).ok_or(SearchError::NoSolution),
+        Err(reason) => {
+            let runner = Runner::new(regs.clone(), programs);
+            runner.compiled.find_reg_a(regs, 0..limit, target, &runner.budget)
+                .map(|reg_a| (reg_a, Method::Bounded(limit)))
+                .ok_or(SearchError::NotSupported { reason, searched: limit })
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::search::*;
+
+    const INPUT: [u8; 16] = [2, 4, 1, 3, 7, 5, 1, 5, 0, 3, 4, 1, 5, 5, 3, 0];
+
+    fn regs() -> Registers {
+        Registers { reg_a: 0, reg_b: 0, reg_c: 0 }
+    }
+
+    fn quine(programs: &[u8]) -> Vec<u64> {
+        programs.iter().map(|x| *x as u64).collect()
+    }
+
+    fn brute_force(regs: &Registers, programs: &[u8], target: &[u64], limit: u64) -> Option<u64> {
+        let mut runner = Runner::new(regs.clone(), programs);
+        (0..limit).find(|reg_a| runner.matches(*reg_a, target))
+    }
+
+    #[test]
+    fn find_quine() -> Result<(), Box<dyn std::error::Error>> {
+        let programs = [0, 3, 5, 4, 3, 0];
+        assert_eq!((117440, Method::Symbolic), find_reg_a(&regs(), &programs, &quine(&programs), DEFAULT_LIMIT)?);
+
+        let (reg_a, method) = find_reg_a(&regs(), &INPUT, &[4, 6, 3, 5], DEFAULT_LIMIT)?;
+        assert_eq!(Method::Symbolic, method);
+        assert_eq!(Some(reg_a), brute_force(&regs(), &INPUT, &[4, 6, 3, 5], reg_a + 1));
+        Ok(())
+    }
+
+    struct Rng(u64);
+
+    impl Rng {
+        fn next(&mut self, n: u64) -> u64 {
+            self.0 ^= self.0 << 13;
+            self.0 ^= self.0 >> 7;
+            self.0 ^= self.0 << 17;
+            self.0 % n
+        }
+    }
+
+    fn check_against_brute_force(programs: &[u8], regs: &Registers, reg_a: u64) {
+        let budget = Budget { max_steps: Some(MAX_STEPS), detect_cycles: true, ..Default::default() };
+        let mut target = vec![];
+        if Compiled::new(programs).exec_with(&mut Registers { reg_a, ..regs.clone() }, &mut target, &budget).is_err() || target.len() > 8 {
+            return;
+        }
+
+        let expected = brute_force(regs, programs, &target, reg_a + 1);
+        assert!(expected.is_some());
+        assert_eq!(Ok(expected), symbolic(regs, programs, &target, MAX_SYMBOLIC_STEPS), "{:?} {:?}", programs, target);
+    }
+
+    #[test]
+    fn find_matches_brute_force() {
+        let programs: [&[u8]; 8] = [
+            // B accumulates across iterations
+            &[1, 3, 0, 1, 5, 5, 3, 0],
+            // A shifted twice per loop, once by a register
+            &[2, 4, 0, 5, 0, 1, 5, 4, 3, 0],
+            // C carried over and shifted by A
+            &[5, 6, 7, 4, 4, 0, 2, 6, 0, 2, 5, 5, 3, 0],
+            // jumps into the middle of an instruction
+            &[0, 1, 3, 3, 5, 4, 3, 0],
+            // two outputs per loop and a shift by A
+            &[5, 4, 6, 4, 5, 5, 0, 2, 3, 0],
+            // no loop
+            &[2, 4, 1, 5, 5, 5],
+            // out of a reserved operand halts
+            &[7, 1, 0, 3, 5, 6, 5, 7, 3, 0],
+            &INPUT,
+        ];
+        let regs = Registers { reg_a: 0, reg_b: 5, reg_c: 9 };
+
+        for programs in programs {
+            for reg_a in [0, 1, 6, 37, 300, 2024, 9999] {
+                check_against_brute_force(programs, &regs, reg_a);
+            }
+            assert_eq!(Ok(None), symbolic(&regs, programs, &[8], MAX_SYMBOLIC_STEPS));
+        }
+
+        let mut rng = Rng(0x2024_1217);
+        for _ in 0..300 {
+            let programs = (0..1 + rng.next(7)).flat_map(|_| [rng.next(8) as u8, rng.next(8) as u8]).collect::<Vec<_>>();
+            let regs = Registers { reg_a: 0, reg_b: rng.next(64), reg_c: rng.next(64) };
+            check_against_brute_force(&programs, &regs, rng.next(3000));
+        }
+    }
+
+    #[test]
+    fn find_unsupported() {
+        let programs = [1, 3, 0, 1, 5, 5, 3, 0];
+        assert_eq!(Ok((4, Method::Symbolic)), find_reg_a(&regs(), &programs, &[3, 0, 3], DEFAULT_LIMIT));
+        assert_eq!(Err("symbolic search gave up after 20 steps".to_string()), symbolic(&regs(), &INPUT, &quine(&INPUT), 20));
+
+        let err = SearchError::NotSupported { reason: "symbolic search gave up after 20 steps".into(), searched: 1000 };
+        assert_eq!("program not supported (symbolic search gave up after 20 steps) and no reg_a below 1000 matches", err.to_string());
+
+        // never halts for a non-zero A
+        assert_eq!(Err(SearchError::NoSolution), find_reg_a(&regs(), &[5, 4, 3, 0], &[1, 2], 100));
+        assert_eq!(Ok((0, Method::Symbolic)), find_reg_a(&regs(), &[5, 4, 3, 0], &[0], 100));
+
+        assert_eq!(Err(SearchError::NoSolution), find_reg_a(&regs(), &[0, 3, 5, 4, 3, 0], &[1], DEFAULT_LIMIT));
+        assert_eq!(Err(SearchError::NoSolution), find_reg_a(&regs(), &[0, 3, 5, 4, 3, 0], &[8], DEFAULT_LIMIT));
+    }
+}
@@ -0,0 +1,112 @@
+use std::path::Path;
+
+use aoc_grid::{Grid, Point};
+use aoc_input::{Input, ParseError};
+use aoc_search::bfs;
+
+pub fn solve<P>(path: P, (width, height): (usize, usize)) -> Result<Option<Point>, Box<dyn std::error::Error>>
+    where P: AsRef<Path>
+{
+    solve_parsed(read_file(path)?, (width, height))
+}
+
+pub fn solve_parsed(walls: Vec<Point>, (width, height): (usize, usize)) -> Result<Option<Point>, Box<dyn std::error::Error>> {
+    let mut left = 0;
+    let mut right = walls.len() -1;
+
+    while left <= right {
+        let mid = (left + right) / 2;
+
+        match solve_internal(&walls, (width, height), mid) {
+            Some(_) => left = mid + 1,
+            None => right = mid - 1,
+        }
+    }
+
+    Ok(Some(walls[right]))
+}
+
+pub fn solve_internal(walls: &[Point], (width, height): (usize, usize), limit: usize) -> Option<u64> {
+    let board = Board::new(width, height, walls, limit);
+
+    let p0 = (0, 0);
+    let goal = (width - 1, height - 1);
+
+    board.find_route(p0, goal)
+}
+
+#[derive(PartialEq, Clone, Debug)]
+pub enum Object {
+    Wall,
+    Road,
+}
+
+pub struct Board {
+    pub map: Grid<Object>,
+}
+
+impl Board {
+    pub fn new(width: usize, height: usize, roads: &[Point], limit: usize) -> Self {
+        let mut map = Grid::filled(width, height, Object::Road);
+
+        roads.into_iter()
+            .take(limit)
+            .for_each(|p| {
+                map[*p] = Object::Wall;
+            })
+        ;
+
+        Board {
+            map,
+        }
+    }
+    
+    pub fn find_route(&self, p0: Point, goal: Point) -> Option<u64> {
+        let result = bfs([p0], |p| {
+            self.map.neighbors4(*p)
+                .filter(|(_, p)| self.map[*p] == Object::Road)
+                .map(|(_, p)| p)
+                .collect::<Vec<_>>()
+        });
+
+        result.distance(&goal)
+    }
+
+    #[allow(unused)]
+    pub fn dump(&self) {
+        let map = self.map.render(|_, obj| match obj {
+            Object::Wall => '#',
+            Object::Road => '.',
+        });
+        eprintln!("{}", map);
+    }
+}
+
+pub fn read_file<P>(path: P) -> Result<Vec<Point>, ParseError> 
+    where P: AsRef<Path>
+{
+    let input = Input::read_file(path)?;
+    let mut map = vec![];
+
+    for line in input.lines() {
+        let (x, y) = line.split_once(line.text.trim(), ",")?;
+
+        map.push((line.parse::<usize>(x)?, line.parse::<usize>(y)?));
+    }
+
+    Ok(map)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::*;
+
+    #[test]
+    fn solve_example() -> Result<(), Box<dyn std::error::Error>> {
+        let walls = read_file("./aoc_input_example.txt")?;
+        assert_eq!(Some(24), solve_internal(&walls, (7, 7), 20));
+        assert_eq!(None, solve_internal(&walls, (7, 7), 21));
+        assert_eq!(Some((6, 1)), solve("./aoc_input_example.txt", (7, 7))?);
+        Ok(())
+    }
+}
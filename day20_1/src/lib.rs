@@ -0,0 +1,175 @@
+use std::path::Path;
+
+use aoc_grid::{Direction, Grid, Point};
+use aoc_input::{Input, ParseError, ParseErrorKind};
+use aoc_search::{bfs, SearchResult};
+
+pub fn solve<P>(path: P, threshold: u64) -> Result<usize, Box<dyn std::error::Error>>
+    where P: AsRef<Path>
+{
+    solve_parsed(read_file(path)?, threshold)
+}
+
+pub fn solve_parsed((p0, _, board): (Point, Point, Board), threshold: u64) -> Result<usize, Box<dyn std::error::Error>> {
+    let route = board.search(p0);
+
+    let total = board.map.points()
+        .filter_map(|p| board.apply_cheat(&route, p))
+        .flat_map(std::convert::identity)
+        .filter(|cheat| *cheat >= threshold)
+        .count()
+    ;
+
+    Ok(total)
+}
+
+#[derive(PartialEq, Clone, Debug)]
+pub enum Object {
+    Wall,
+    Road,
+}
+
+#[derive(Clone)]
+pub struct Board {
+    pub map: Grid<Object>,
+}
+
+impl Board {
+    pub fn find_route(&self, p0: Point, goal: Point) -> Option<u64> {
+        self.search(p0).distance(&goal)
+    }
+
+    pub fn search(&self, p0: Point) -> SearchResult<Point> {
+        bfs([p0], |p| {
+            self.map.neighbors4(*p)
+                .filter(|(_, p)| self.map[*p] == Object::Road)
+                .map(|(_, p)| p)
+                .collect::<Vec<_>>()
+        })
+    }
+
+    pub fn apply_cheat(&self, route: &SearchResult<Point>, p0: Point) -> Option<Vec<u64>> {
+        let mut cheats = vec![];
+        
+        let score0 = route.distance(&p0)?;
+
+        for d1 in Direction::iter() {
+            let Some(p1) = self.map.next(p0, d1) else {
+                continue;
+            };
+
+            let Object::Wall = self.map[p1] else {
+                continue;
+            };
+                    
+            for d2 in Direction::iter() {
+                let Some(p2) = self.map.next(p1, d2) else {
+                    continue;
+                };
+
+                let Some(score) = route.distance(&p2) else {
+                    continue;
+                };
+
+                if score > score0 {
+                    cheats.push(score - score0 - 2);
+                }
+            }
+        }
+
+        (cheats.len() > 0).then(|| cheats)
+    }
+
+
+
+}
+
+pub fn read_file<P>(path: P) -> Result<(Point, Point, Board), ParseError> 
+    where P: AsRef<Path>
+{
+    let input = Input::read_file(path)?;
+    let mut lines = input.lines();
+    let mut p0: Option<Point> = None;
+    let mut goal: Option<Point> = None;
+
+    let map = Grid::read_lines(&mut lines, |p, ch| match ch {
+        '#' => Some(Object::Wall),
+        'S' => {
+            p0 = p0.or(Some(p));
+            Some(Object::Road)
+        }
+        'E' => {
+            goal = goal.or(Some(p));
+            Some(Object::Road)
+        }
+        '.' => Some(Object::Road),
+        _ => None,
+    })?;
+
+    let board = Board {
+        map,
+    };
+
+    let (Some(p0), Some(goal)) = (p0, goal) else {
+        return Err(lines.error(ParseErrorKind::Expected("start 'S' and end 'E' in map".to_string())));
+    };
+
+    Ok((p0, goal, board))
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::*;
+
+    #[test]
+    fn solve_example() -> Result<(), Box<dyn std::error::Error>> {
+        assert_eq!(44, solve("./aoc_input_example.txt", 2)?);
+        Ok(())
+    }
+
+    #[test]
+    fn read_file_example() -> Result<(), Box<dyn std::error::Error>> {
+        let (p0, goal, board) = read_file("./aoc_input_example_small.txt")?;
+
+        assert_eq!(5, board.map.width);
+        assert_eq!(6, board.map.height);
+
+        assert_eq!((1, 4), p0);
+        assert_eq!((4, 4), goal);
+
+        let expect_map = vec![
+            Object::Wall, Object::Wall,       Object::Wall,       Object::Wall,       Object::Wall,       
+            Object::Wall, Object::Road, Object::Road, Object::Road, Object::Wall,       
+            Object::Wall, Object::Road, Object::Wall,       Object::Road, Object::Wall,       
+            Object::Wall, Object::Road, Object::Wall,       Object::Road, Object::Wall, 
+            Object::Wall, Object::Road, Object::Wall,       Object::Road, Object::Road,       
+            Object::Wall, Object::Wall,       Object::Wall,       Object::Wall,       Object::Wall,       
+        ];
+        assert_eq!(expect_map, board.map.cells);
+
+        Ok(())
+    }
+
+    #[test]
+    fn find_path_example() -> Result<(), Box<dyn std::error::Error>> {
+        let (p0, goal, board) = read_file("./aoc_input_example.txt")?;
+    
+        assert_eq!(Some(84), board.find_route(p0, goal));
+        
+        Ok(())
+    }
+
+    #[test]
+    fn apply_cheet_example() -> Result<(), Box<dyn std::error::Error>> {
+        let (p0, _, board) = read_file("./aoc_input_example.txt")?;
+        let route = board.search(p0);
+
+        assert_eq!(None, board.apply_cheat(&route, (3, 3)));
+        assert_eq!(Some(vec![0]), board.apply_cheat(&route, (2, 1)));
+        assert_eq!(Some(vec![12]), board.apply_cheat(&route, (7, 1)));
+        assert_eq!(Some(vec![40, 64]), board.apply_cheat(&route, (7, 7)));
+
+        Ok(())
+    }
+}
+
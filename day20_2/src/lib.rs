@@ -0,0 +1,146 @@
+use std::path::Path;
+
+use aoc_grid::{Grid, Point};
+use aoc_input::{Input, ParseError, ParseErrorKind};
+use aoc_search::{bfs, SearchResult};
+
+pub fn solve<P>(path: P, threshold: u64) -> Result<usize, Box<dyn std::error::Error>>
+    where P: AsRef<Path>
+{
+    solve_parsed(read_file(path)?, threshold)
+}
+
+pub fn solve_parsed((p0, _, board): (Point, Point, Board), threshold: u64) -> Result<usize, Box<dyn std::error::Error>> {
+    let route = board.search(p0);
+
+    let total = board.map.points()
+        .filter_map(|p| board.apply_cheat(&route, p))
+        .flat_map(std::convert::identity)
+        .filter(|cheat| *cheat >= threshold)
+        .count()
+    ;
+
+    Ok(total)
+}
+
+#[derive(PartialEq, Clone, Debug)]
+pub enum Object {
+    Wall,
+    Road,
+}
+
+#[derive(Clone)]
+pub struct Board {
+    pub map: Grid<Object>,
+}
+
+impl Board {
+    pub fn find_route(&self, p0: Point, goal: Point) -> Option<u64> {
+        self.search(p0).distance(&goal)
+    }
+
+    pub fn search(&self, p0: Point) -> SearchResult<Point> {
+        bfs([p0], |p| {
+            self.map.neighbors4(*p)
+                .filter(|(_, p)| self.map[*p] == Object::Road)
+                .map(|(_, p)| p)
+                .collect::<Vec<_>>()
+        })
+    }
+
+    pub fn apply_cheat(&self, route: &SearchResult<Point>, p0: Point) -> Option<Vec<u64>> {
+        let score0 = route.distance(&p0)?;
+
+        let cheats = self.enumerate_cheat_destination(p0, 20).into_iter()
+            .filter_map(|p| match route.distance(&p) {
+                Some(score) if score > score0 => {
+                    let distance = Board::distance(p0, p);
+                    Some(score - score0 - (distance as u64))
+                }
+                _ => None,
+            })
+            .collect::<Vec<_>>()
+        ;
+
+        (cheats.len() > 0).then(|| cheats)
+    }
+
+    pub fn enumerate_cheat_destination(&self, p0: Point, max_time: usize) -> Vec<Point> {
+        let reachable = bfs([p0], |p| {
+            self.map.neighbors4(*p)
+                .filter(|(_, p)| Board::distance(p0, *p) <= max_time)
+                .map(|(_, p)| p)
+                .collect::<Vec<_>>()
+        });
+
+        reachable.dist.into_keys()
+            .filter(|p| self.map[*p] == Object::Road && Board::distance(p0, *p) > 1)
+            .collect()
+    }
+
+    pub fn distance((x0, y0): Point, (x1, y1): Point) -> usize {
+        x0.abs_diff(x1) + y0.abs_diff(y1)
+    }
+}
+
+pub fn read_file<P>(path: P) -> Result<(Point, Point, Board), ParseError> 
+    where P: AsRef<Path>
+{
+    let input = Input::read_file(path)?;
+    let mut lines = input.lines();
+    let mut p0: Option<Point> = None;
+    let mut goal: Option<Point> = None;
+
+    let map = Grid::read_lines(&mut lines, |p, ch| match ch {
+        '#' => Some(Object::Wall),
+        'S' => {
+            p0 = p0.or(Some(p));
+            Some(Object::Road)
+        }
+        'E' => {
+            goal = goal.or(Some(p));
+            Some(Object::Road)
+        }
+        '.' => Some(Object::Road),
+        _ => None,
+    })?;
+
+    let board = Board {
+        map,
+    };
+
+    let (Some(p0), Some(goal)) = (p0, goal) else {
+        return Err(lines.error(ParseErrorKind::Expected("start 'S' and end 'E' in map".to_string())));
+    };
+
+    Ok((p0, goal, board))
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashSet;
+
+    use crate::*;
+
+    #[test]
+    fn solve_example() -> Result<(), Box<dyn std::error::Error>> {
+        assert_eq!(285, solve("./aoc_input_example.txt", 50)?);
+        Ok(())
+    }
+
+    #[test]
+    fn apply_cheet_dest_example() -> Result<(), Box<dyn std::error::Error>> {
+        let (_, _, board) = read_file("./aoc_input_example.txt")?;
+
+        let expect_dests = [
+            (2, 1), (3, 1),
+            (1, 2), (5, 2),
+            (1, 3), (5, 3), 
+        ];
+
+        assert_eq!(HashSet::from(expect_dests), HashSet::from_iter(board.enumerate_cheat_destination((3, 3), 3).into_iter()));
+
+        Ok(())
+    }
+}
+
@@ -0,0 +1,161 @@
+use std::{collections::{HashMap, HashSet}, path::Path};
+
+use aoc_input::{Input, Interner, ParseError, Symbol};
+
+pub fn solve<P>(path: P) -> Result<usize, Box<dyn std::error::Error>>
+    where P: AsRef<Path>
+{
+    let input = Input::read_file(path)?;
+    solve_parsed(parse(&input)?)
+}
+
+pub fn solve_parsed(network: Network<'_>) -> Result<usize, Box<dyn std::error::Error>> {
+    let connections = match_connection(&network.pairs);
+    let total = count_initial(&network.names, &connections, 't');
+
+    Ok(total)
+}
+
+#[derive(Clone, Debug)]
+pub struct Network<'a> {
+    pub names: Interner<'a>,
+    pub pairs: Vec<(Symbol, Symbol)>,
+}
+
+pub fn parse(input: &Input) -> Result<Network<'_>, ParseError> {
+    let mut names = Interner::new();
+
+    let pairs = input.lines()
+        .map(|line| {
+            let (lhs, rhs) = line.split_once(line.text.trim(), "-")?;
+            Ok((names.intern(lhs), names.intern(rhs)))
+        })
+        .collect::<Result<Vec<_>, ParseError>>()?
+    ;
+
+    Ok(Network { names, pairs })
+}
+
+#[derive(PartialEq, Eq, Hash, Debug)]
+pub struct Connection(pub Symbol, pub Symbol, pub Symbol);
+
+impl Connection {
+    pub fn new(name1: Symbol, name2: Symbol, name3: Symbol) -> Self {
+        let mut names = [name1, name2, name3];
+        names.sort();
+
+        Self(names[0], names[1], names[2])
+    }
+
+    pub fn has_initial(&self, names: &Interner, needle: char) -> bool {
+        let Self(name_1, name_2, name_3) = self;
+
+        [name_1, name_2, name_3].into_iter().any(|id| names.name(*id).starts_with(needle))
+    }
+}
+
+pub fn match_connection(pairs: &[(Symbol, Symbol)]) -> Vec<Connection> {
+    let mut connections = HashSet::new();
+
+    let mut lookup = HashMap::<Symbol, HashSet<Symbol>>::new();
+
+    for &(name1, name2) in pairs {
+        lookup.entry(name1).or_default().insert(name2);
+        lookup.entry(name2).or_default().insert(name1);
+    }
+
+    for &(name1, name2) in pairs {
+        if let Some((peers1, peers2)) = lookup.get(&name1).zip(lookup.get(&name2)) {
+            for &peer in peers1.intersection(peers2) {
+                connections.insert(Connection::new(name1, name2, peer));
+            }
+        }
+    }
+
+    connections.into_iter().collect()
+}
+
+pub fn count_initial(names: &Interner, connections: &[Connection], needle: char) -> usize {
+    connections.iter()
+        .filter(|conn| conn.has_initial(names, needle))
+        .count()
+}
+
+#[cfg(test)]
+mod tests {
+    use assert_unordered::assert_eq_unordered;
+
+    use crate::*;
+
+    #[test]
+    fn solve_example() -> Result<(), Box<dyn std::error::Error>> {
+        assert_eq!(7, solve("./aoc_input_example.txt")?);
+        Ok(())
+    }
+
+    const PAIRS: [(&str, &str); 32] = [
+        ("kh", "tc"), ("qp", "kh"), ("de", "cg"), ("ka", "co"), ("yn", "aq"), ("qp", "ub"), ("cg", "tb"), ("vc", "aq"), 
+        ("tb", "ka"), ("wh", "tc"), ("yn", "cg"), ("kh", "ub"), ("ta", "co"), ("de", "co"), ("tc", "td"), ("tb", "wq"), 
+        ("wh", "td"), ("ta", "ka"), ("td", "qp"), ("aq", "cg"), ("wq", "ub"), ("ub", "vc"), ("de", "ta"), ("wq", "aq"), 
+        ("wq", "vc"), ("wh", "yn"), ("ka", "de"), ("kh", "ta"), ("co", "tc"), ("wh", "qp"), ("tb", "vc"), ("td", "yn"), 
+    ];
+
+    const CONNECTIONS: [(&str, &str, &str); 12] = [
+        ("aq","cg","yn"), 
+        ("aq","vc","wq"), 
+        ("co","de","ka"), 
+        ("co","de","ta"), 
+        ("co","ka","ta"), 
+        ("de","ka","ta"), 
+        ("kh","qp","ub"), 
+        ("qp","td","wh"), 
+        ("tb","vc","wq"), 
+        ("tc","td","wh"), 
+        ("td","wh","yn"), 
+        ("ub","vc","wq"),             
+    ];
+
+    fn intern_connections(names: &mut Interner<'static>) -> Vec<Connection> {
+        CONNECTIONS.into_iter()
+            .map(|(name1, name2, name3)| Connection::new(names.intern(name1), names.intern(name2), names.intern(name3)))
+            .collect()
+    }
+
+    #[test]
+    fn parse_example() -> Result<(), Box<dyn std::error::Error>> {
+        let input = Input::read_file("./aoc_input_example.txt")?;
+        let network = parse(&input)?;
+
+        let pairs = network.pairs.iter()
+            .map(|(name1, name2)| (network.names.name(*name1), network.names.name(*name2)))
+            .collect::<Vec<_>>();
+
+        assert_eq!(PAIRS.to_vec(), pairs);
+        assert_eq!(16, network.names.len());
+        Ok(())
+    }
+
+    #[test]
+    fn match_connection_example() -> Result<(), Box<dyn std::error::Error>> {
+        let mut names = Interner::new();
+        let pairs = PAIRS.into_iter()
+            .map(|(name1, name2)| (names.intern(name1), names.intern(name2)))
+            .collect::<Vec<_>>();
+
+        let connections = match_connection(&pairs);
+        let expect_connections = intern_connections(&mut names);
+
+        assert_eq_unordered!(expect_connections, connections);
+        Ok(())
+    }
+
+    #[test]
+    fn count_t_example() -> Result<(), Box<dyn std::error::Error>> {
+        let mut names = Interner::new();
+        let connections = intern_connections(&mut names);
+        
+        assert_eq!(7, count_initial(&names, &connections, 't'));
+
+        Ok(())
+    }
+}
@@ -0,0 +1,166 @@
+use std::{collections::{BTreeSet, HashMap, HashSet}, path::Path};
+
+use aoc_input::{Input, Interner, ParseError, Symbol};
+
+pub fn solve<P>(path: P) -> Result<String, Box<dyn std::error::Error>>
+    where P: AsRef<Path>
+{
+    let input = Input::read_file(path)?;
+    solve_parsed(parse(&input)?)
+}
+
+pub fn solve_parsed(network: Network<'_>) -> Result<String, Box<dyn std::error::Error>> {
+    let connections = match_connection(&network.pairs);
+    let lans = find_p2p_all(&network.names, &connections);
+
+    Ok(lans[0].join(","))
+}
+
+#[derive(Clone, Debug)]
+pub struct Network<'a> {
+    pub names: Interner<'a>,
+    pub pairs: Vec<(Symbol, Symbol)>,
+}
+
+pub fn parse(input: &Input) -> Result<Network<'_>, ParseError> {
+    let mut names = Interner::new();
+
+    let pairs = input.lines()
+        .map(|line| {
+            let (lhs, rhs) = line.split_once(line.text.trim(), "-")?;
+            Ok((names.intern(lhs), names.intern(rhs)))
+        })
+        .collect::<Result<Vec<_>, ParseError>>()?
+    ;
+
+    Ok(Network { names, pairs })
+}
+
+#[derive(PartialEq, Eq, Hash, Debug)]
+pub struct Connection(pub Symbol, pub Symbol, pub Symbol);
+
+impl Connection {
+    pub fn new(name1: Symbol, name2: Symbol, name3: Symbol) -> Self {
+        let mut names = [name1, name2, name3];
+        names.sort();
+
+        Self(names[0], names[1], names[2])
+    }
+
+    pub fn has_initial(&self, names: &Interner, needle: char) -> bool {
+        let Self(name_1, name_2, name_3) = self;
+
+        [name_1, name_2, name_3].into_iter().any(|id| names.name(*id).starts_with(needle))
+    }
+}
+
+#[derive(PartialEq, Eq, Hash, Debug)]
+pub struct Star(pub BTreeSet<Symbol>);
+
+impl Star {
+    pub fn new(set: &BTreeSet<Symbol>) -> Self {
+        Self(set.clone())
+    }
+
+    pub fn is_p2p(&self, other: &BTreeSet<Symbol>) -> bool {
+        let Self(set) = self;
+
+        *set == *other
+    }
+}
+
+pub fn match_connection(pairs: &[(Symbol, Symbol)]) -> Vec<Connection> {
+    let mut connections = HashSet::new();
+
+    let mut lookup = HashMap::<Symbol, HashSet<Symbol>>::new();
+
+    for &(name1, name2) in pairs {
+        lookup.entry(name1).or_default().insert(name2);
+        lookup.entry(name2).or_default().insert(name1);
+    }
+
+    for &(name1, name2) in pairs {
+        if let Some((peers1, peers2)) = lookup.get(&name1).zip(lookup.get(&name2)) {
+            for &peer in peers1.intersection(peers2) {
+                connections.insert(Connection::new(name1, name2, peer));
+            }
+        }
+    }
+
+    connections.into_iter().collect()
+}
+
+pub fn find_p2p_all<'a>(names: &Interner<'a>, connections: &[Connection]) -> Vec<Vec<&'a str>> {
+    let mut lookup = HashMap::<Symbol, BTreeSet<Symbol>>::new();
+
+    for &Connection(p1, p2, p3) in connections {
+        for p in [p1, p2, p3] {
+            lookup.entry(p).or_default().extend([p1, p2, p3]);
+        }
+    }
+
+    let mut p2p_map = HashMap::<Star, BTreeSet<Symbol>>::new();
+
+    for (k, v) in lookup {
+        p2p_map.entry(Star::new(&v)).or_default().insert(k);
+    }
+
+    let mut p2p = p2p_map.into_iter()
+        .filter_map(|(star, v)| match star.is_p2p(&v) {
+            true => Some(v.into_iter().map(|id| names.name(id)).collect::<Vec<_>>()),
+            false => None
+        })
+        .map(|mut star| {
+            star.sort();
+            star
+        })
+        .collect::<Vec<_>>()
+    ;
+
+    p2p.sort();
+
+    p2p
+}
+
+#[allow(unused)]
+pub fn count_initial(names: &Interner, connections: &[Connection], needle: char) -> usize {
+    connections.iter()
+        .filter(|conn| conn.has_initial(names, needle))
+        .count()
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::*;
+
+    #[test]
+    fn solve_example() -> Result<(), Box<dyn std::error::Error>> {
+        assert_eq!("co,de,ka,ta", solve("./aoc_input_example.txt")?);
+        Ok(())
+    }
+
+    #[test]
+    fn find_conneced_all_example() -> Result<(), Box<dyn std::error::Error>> {
+        let mut names = Interner::new();
+        let connections = vec![
+            ("aq","cg","yn"), 
+            ("aq","vc","wq"), 
+            ("co","de","ka"), 
+            ("co","de","ta"), 
+            ("co","ka","ta"), 
+            ("de","ka","ta"), 
+            ("kh","qp","ub"), 
+            ("qp","td","wh"), 
+            ("tb","vc","wq"), 
+            ("tc","td","wh"), 
+            ("td","wh","yn"), 
+            ("ub","vc","wq"),             
+        ].into_iter().map(|(name1, name2, name3)| Connection::new(names.intern(name1), names.intern(name2), names.intern(name3))).collect::<Vec<_>>();
+
+        let connections = find_p2p_all(&names, &connections);
+        let expect_connections = vec!["co","de","ka","ta"];
+
+        assert_eq!(vec![expect_connections], connections);
+        Ok(())
+    }
+}
\ No newline at end of file
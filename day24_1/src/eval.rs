@@ -0,0 +1,288 @@
+use std::collections::VecDeque;
+
+use aoc_input::{Interner, Symbol};
+
+use crate::{Gate, Op};
+
+pub const LANES: usize = 64;
+
+#[derive(PartialEq, Debug)]
+pub enum EvalError {
+    Undriven(String),
+    MultipleDrivers(String),
+    Cycle(Vec<String>),
+}
+impl std::fmt::Display for EvalError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            EvalError::Undriven(wire) => write!(f, "Wire {} is not driven by any gate or input", wire),
+            EvalError::MultipleDrivers(wire) => write!(f, "Wire {} is driven more than once", wire),
+            EvalError::Cycle(wires) => write!(f, "Cycle through wires {}", wires.join(", ")),
+        }
+    }
+}
+impl std::error::Error for EvalError {}
+
+#[derive(Debug)]
+struct Node {
+    out: Symbol,
+    op: Op,
+    operands: Vec<Symbol>,
+}
+
+// A circuit with interned wires and gates in topological order. Every wire
+// holds a u64 of lanes, so one pass evaluates up to 64 input vectors.
+#[derive(Debug)]
+pub struct Evaluator<'a> {
+    names: Interner<'a>,
+    inputs: Vec<(Symbol, u8)>,
+    nodes: Vec<Node>,
+}
+
+impl<'a> Evaluator<'a> {
+    pub fn new(circuit: &'a [Gate]) -> Result<Self, EvalError> {
+        let mut names = Interner::new();
+        let mut inputs = vec![];
+        let mut nodes = vec![];
+
+        for gate in circuit {
+            match gate {
+                Gate::Input(name, value) => inputs.push((names.intern(name), *value)),
+                Gate::Logic(name, op, operands) => nodes.push(Node {
+                    out: names.intern(name),
+                    op: op.clone(),
+                    operands: operands.iter().map(|operand| names.intern(operand)).collect(),
+                }),
+            }
+        }
+
+        let mut driver = vec![None; names.len()];
+        for (i, wire) in inputs.iter().map(|(wire, _)| *wire).chain(nodes.iter().map(|node| node.out)).enumerate() {
+            if driver[wire.0 as usize].replace(i).is_some() {
+                return Err(EvalError::MultipleDrivers(names.name(wire).to_string()));
+            }
+        }
+        if let Some(wire) = driver.iter().position(|driver| driver.is_none()) {
+            return Err(EvalError::Undriven(names.name(Symbol(wire as u32)).to_string()));
+        }
+
+        let nodes = sort(&names, &inputs, nodes)?;
+
+        Ok(Self { names, inputs, nodes })
+    }
+
+    pub fn wire(&self, name: &str) -> Option<Symbol> {
+        self.names.get(name)
+    }
+
+    pub fn name(&self, wire: Symbol) -> &'a str {
+        self.names.name(wire)
+    }
+
+    pub fn wires(&self) -> usize {
+        self.names.len()
+    }
+
+    // Evaluates every wire, taking the lanes of each input wire from `input`,
+    // which also gets the value the circuit declares for it.
+    pub fn eval_with<F>(&self, input: F) -> Vec<u64>
+        where F: Fn(&str, u8) -> u64
+    {
+        let mut values = vec![0; self.names.len()];
+        for (wire, value) in &self.inputs {
+            values[wire.0 as usize] = input(self.names.name(*wire), *value);
+        }
+
+        self.propagate(&mut values);
+        values
+    }
+
+    // Evaluates once with the input values from the circuit, in lane 0.
+    pub fn eval(&self) -> Vec<u64> {
+        let mut values = vec![0; self.names.len()];
+        for (wire, value) in &self.inputs {
+            values[wire.0 as usize] = *value as u64;
+        }
+
+        self.propagate(&mut values);
+        values
+    }
+
+    fn propagate(&self, values: &mut [u64]) {
+        let mut inputs = vec![];
+
+        for node in &self.nodes {
+            inputs.clear();
+            inputs.extend(node.operands.iter().map(|operand| values[operand.0 as usize]));
+            values[node.out.0 as usize] = node.op.apply(&inputs);
+        }
+    }
+
+    // The wires `<prefix>00`, `<prefix>01`, ... up to the first missing bit.
+    pub fn bus(&self, prefix: char) -> Vec<Symbol> {
+        (0..64)
+            .map_while(|bit| self.names.get(&format!("{}{:02}", prefix, bit)))
+            .collect()
+    }
+
+    // Reads the number on `bus` in lane `lane` of `values`.
+    pub fn number(&self, values: &[u64], bus: &[Symbol], lane: usize) -> u64 {
+        bus.iter().enumerate()
+            .fold(0, |acc, (bit, wire)| acc | ((values[wire.0 as usize] >> lane) & 1) << bit)
+    }
+
+    // Evaluates every wire with up to 64 `(x, y)` pairs on the `x`/`y`
+    // inputs, one per lane. Other inputs keep their declared value.
+    pub fn eval_pairs(&self, pairs: &[(u64, u64)]) -> Vec<u64> {
+        assert!(pairs.len() <= LANES);
+
+        self.eval_with(|name, value| {
+            let bus = match name.split_at(1) {
+                ("x", bit) => bit.parse::<u32>().ok().map(|bit| (bit, true)),
+                ("y", bit) => bit.parse::<u32>().ok().map(|bit| (bit, false)),
+                _ => None,
+            };
+            let Some((bit, is_x)) = bus else {
+                return if value == 0 { 0 } else { u64::MAX };
+            };
+
+            pairs.iter().enumerate().fold(0, |acc, (lane, (x, y))| {
+                let value = if is_x { *x } else { *y };
+                acc | (value.checked_shr(bit).unwrap_or(0) & 1) << lane
+            })
+        })
+    }
+
+    // Feeds each `(x, y)` pair to the `x`/`y` inputs and returns the number
+    // on the `z` outputs, 64 pairs per pass.
+    pub fn eval_numbers(&self, vectors: &[(u64, u64)]) -> Vec<u64> {
+        let outputs = self.bus('z');
+
+        vectors.chunks(LANES)
+            .flat_map(|chunk| {
+                let values = self.eval_pairs(chunk);
+                (0..chunk.len()).map(|lane| self.number(&values, &outputs, lane)).collect::<Vec<_>>()
+            })
+            .collect()
+    }
+}
+
+// Kahn's algorithm over wires; whatever is left unsorted lies on or behind a cycle.
+fn sort(names: &Interner<'_>, inputs: &[(Symbol, u8)], nodes: Vec<Node>) -> Result<Vec<Node>, EvalError> {
+    let mut consumers = vec![vec![]; names.len()];
+    let mut pending = nodes.iter().map(|node| node.operands.len()).collect::<Vec<_>>();
+
+    for (i, node) in nodes.iter().enumerate() {
+        for operand in &node.operands {
+            consumers[operand.0 as usize].push(i);
+        }
+    }
+
+    let mut order = (0..nodes.len()).filter(|i| pending[*i] == 0).collect::<Vec<_>>();
+    let mut ready = inputs.iter().map(|(wire, _)| *wire)
+        .chain(order.iter().map(|i| nodes[*i].out))
+        .collect::<VecDeque<_>>();
+
+    while let Some(wire) = ready.pop_front() {
+        for &i in &consumers[wire.0 as usize] {
+            pending[i] -= 1;
+            if pending[i] == 0 {
+                order.push(i);
+                ready.push_back(nodes[i].out);
+            }
+        }
+    }
+
+    if order.len() < nodes.len() {
+        let mut wires = pending.iter().zip(&nodes)
+            .filter(|(pending, _)| **pending > 0)
+            .map(|(_, node)| names.name(node.out).to_string())
+            .collect::<Vec<_>>();
+        wires.sort();
+        return Err(EvalError::Cycle(wires));
+    }
+
+    let mut nodes = nodes.into_iter().map(Some).collect::<Vec<_>>();
+    Ok(order.into_iter().filter_map(|i| nodes[i].take()).collect())
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::eval::*;
+    use crate::read_file;
+
+    fn logic(out: &str, op: Op, lhs: &str, rhs: &str) -> Gate {
+        Gate::Logic(out.into(), op, vec![lhs.into(), rhs.into()])
+    }
+
+    #[test]
+    fn eval_example() -> Result<(), Box<dyn std::error::Error>> {
+        let (circuit, _) = read_file("./aoc_input_example_2.txt")?;
+        let evaluator = Evaluator::new(&circuit)?;
+
+        assert_eq!(2024, evaluator.number(&evaluator.eval(), &evaluator.bus('z'), 0));
+        Ok(())
+    }
+
+    #[test]
+    fn eval_numbers_lanes() -> Result<(), Box<dyn std::error::Error>> {
+        let circuit = vec![
+            Gate::Input("x00".into(), 0), Gate::Input("x01".into(), 0),
+            Gate::Input("y00".into(), 0), Gate::Input("y01".into(), 0),
+            logic("z01", Op::XOr, "x01", "y01"),
+            logic("z00", Op::And, "x00", "y00"),
+        ];
+        let evaluator = Evaluator::new(&circuit)?;
+
+        let vectors = (0..100).map(|i| (i % 4, i / 4 % 4)).collect::<Vec<_>>();
+        let expected = vectors.iter().map(|(x, y)| (x & y & 1) | ((x ^ y) & 2)).collect::<Vec<_>>();
+        assert_eq!(expected, evaluator.eval_numbers(&vectors));
+        Ok(())
+    }
+
+    #[test]
+    fn eval_numbers_constant_input() -> Result<(), Box<dyn std::error::Error>> {
+        let circuit = vec![
+            Gate::Input("x00".into(), 0), Gate::Input("y00".into(), 0), Gate::Input("c".into(), 1),
+            logic("z00", Op::XOr, "x00", "y00"),
+            logic("z01", Op::And, "x00", "c"),
+            logic("z02", Op::Or, "c", "c"),
+        ];
+        let evaluator = Evaluator::new(&circuit)?;
+
+        assert_eq!(vec![4, 7, 5, 6], evaluator.eval_numbers(&[(0, 0), (1, 0), (0, 1), (1, 1)]));
+        Ok(())
+    }
+
+    #[test]
+    fn eval_errors() {
+        let circuit = vec![Gate::Input("x00".into(), 1), logic("z00", Op::And, "x00", "y00")];
+        assert_eq!(EvalError::Undriven("y00".into()), Evaluator::new(&circuit).unwrap_err());
+
+        let circuit = vec![Gate::Input("x00".into(), 1), logic("x00", Op::Or, "x00", "x00")];
+        assert_eq!(EvalError::MultipleDrivers("x00".into()), Evaluator::new(&circuit).unwrap_err());
+
+        let circuit = vec![
+            Gate::Input("x00".into(), 1),
+            logic("a", Op::And, "x00", "b"),
+            logic("b", Op::Or, "x00", "a"),
+            logic("z00", Op::XOr, "a", "x00"),
+        ];
+        assert_eq!(EvalError::Cycle(vec!["a".into(), "b".into(), "z00".into()]), Evaluator::new(&circuit).unwrap_err());
+    }
+
+    #[test]
+    fn eval_deep_chain() -> Result<(), Box<dyn std::error::Error>> {
+        let mut circuit = vec![Gate::Input("x00".into(), 1), Gate::Input("y00".into(), 1)];
+        circuit.push(logic("w0", Op::XOr, "x00", "y00"));
+        for i in 1..200_000 {
+            circuit.push(logic(&format!("w{}", i), Op::XOr, &format!("w{}", i - 1), "x00"));
+        }
+        circuit.push(logic("z00", Op::Or, "w199999", "w199999"));
+        circuit.reverse();
+
+        let evaluator = Evaluator::new(&circuit)?;
+        assert_eq!(1, evaluator.number(&evaluator.eval(), &evaluator.bus('z'), 0));
+        Ok(())
+    }
+}
@@ -0,0 +1,160 @@
+use crate::{Gate, Op};
+
+fn color(op: &Op) -> &'static str {
+    match op {
+        Op::And => "lightblue",
+        Op::Or => "palegreen",
+        Op::XOr => "lightsalmon",
+        Op::Not => "lightgray",
+        Op::Nand => "lightcyan",
+        Op::Nor => "honeydew",
+        Op::XNor => "mistyrose",
+    }
+}
+
+fn wires_with_prefix(circuit: &[Gate], prefix: char) -> Vec<&str> {
+    let mut wires = circuit.iter()
+        .flat_map(|gate| match gate {
+            Gate::Logic(name, _, operands) => [vec![name.as_str()], operands.iter().map(|s| s.as_str()).collect()].concat(),
+            Gate::Input(name, _) => vec![name.as_str()],
+        })
+        .filter(|name| name.starts_with(prefix))
+        .collect::<Vec<_>>();
+
+    wires.sort();
+    wires.dedup();
+    wires
+}
+
+fn rank(kind: &str, wires: &[&str]) -> Option<String> {
+    if wires.is_empty() {
+        return None;
+    }
+
+    let wires = wires.iter().map(|wire| format!("{:?};", wire)).collect::<Vec<_>>();
+    Some(format!("    {{ rank={}; {} }}", kind, wires.join(" ")))
+}
+
+pub fn to_dot(circuit: &[Gate]) -> String {
+    let mut lines = vec![
+        "digraph circuit {".to_string(),
+        "    rankdir=LR;".to_string(),
+        "    node [shape=box, style=filled, fillcolor=white];".to_string(),
+    ];
+
+    for gate in circuit {
+        match gate {
+            Gate::Input(name, value) => {
+                lines.push(format!("    {:?} [shape=circle, label=\"{}\\n{}\"];", name, name, value));
+            }
+            Gate::Logic(name, op, _) => {
+                lines.push(format!("    {:?} [label=\"{}\\n{}\", fillcolor={}];", name, name, op, color(op)));
+            }
+        }
+    }
+
+    for gate in circuit {
+        if let Gate::Logic(name, _, operands) = gate {
+            for operand in operands {
+                lines.push(format!("    {:?} -> {:?};", operand, name));
+            }
+        }
+    }
+
+    let inputs = [wires_with_prefix(circuit, 'x'), wires_with_prefix(circuit, 'y')].concat();
+    lines.extend(rank("source", &inputs));
+    lines.extend(rank("sink", &wires_with_prefix(circuit, 'z')));
+
+    lines.push("}".to_string());
+    lines.push(String::new());
+    lines.join("\n")
+}
+
+pub fn to_json(circuit: &[Gate]) -> String {
+    let mut inputs = vec![];
+    let mut gates = vec![];
+
+    for gate in circuit {
+        match gate {
+            Gate::Input(name, value) => {
+                inputs.push(format!("    {{\"name\": {:?}, \"value\": {}}}", name, value));
+            }
+            Gate::Logic(name, op, operands) => {
+                let operands = operands.iter().map(|operand| format!("{:?}", operand)).collect::<Vec<_>>();
+                gates.push(format!("    {{\"output\": {:?}, \"op\": \"{}\", \"inputs\": [{}]}}", name, op, operands.join(", ")));
+            }
+        }
+    }
+
+    let outputs = wires_with_prefix(circuit, 'z').iter()
+        .map(|wire| format!("{:?}", wire))
+        .collect::<Vec<_>>();
+
+    let list = |items: Vec<String>| match items.is_empty() {
+        true => "[]".to_string(),
+        false => format!("[\n{}\n  ]", items.join(",\n")),
+    };
+
+    format!("{{\n  \"inputs\": {},\n  \"gates\": {},\n  \"outputs\": [{}]\n}}\n", list(inputs), list(gates), outputs.join(", "))
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::export::*;
+    use crate::read_file;
+
+    #[test]
+    fn dot_example() -> Result<(), Box<dyn std::error::Error>> {
+        let (circuit, _) = read_file("./aoc_input_example_1.txt")?;
+
+        assert_eq!(r#"digraph circuit {
+    rankdir=LR;
+    node [shape=box, style=filled, fillcolor=white];
+    "x00" [shape=circle, label="x00\n1"];
+    "x01" [shape=circle, label="x01\n1"];
+    "x02" [shape=circle, label="x02\n1"];
+    "y00" [shape=circle, label="y00\n0"];
+    "y01" [shape=circle, label="y01\n1"];
+    "y02" [shape=circle, label="y02\n0"];
+    "z00" [label="z00\nAND", fillcolor=lightblue];
+    "z01" [label="z01\nXOR", fillcolor=lightsalmon];
+    "z02" [label="z02\nOR", fillcolor=palegreen];
+    "x00" -> "z00";
+    "y00" -> "z00";
+    "x01" -> "z01";
+    "y01" -> "z01";
+    "x02" -> "z02";
+    "y02" -> "z02";
+    { rank=source; "x00"; "x01"; "x02"; "y00"; "y01"; "y02"; }
+    { rank=sink; "z00"; "z01"; "z02"; }
+}
+"#, to_dot(&circuit));
+        Ok(())
+    }
+
+    #[test]
+    fn json_example() -> Result<(), Box<dyn std::error::Error>> {
+        let (circuit, _) = read_file("./aoc_input_example_1.txt")?;
+
+        assert_eq!(r#"{
+  "inputs": [
+    {"name": "x00", "value": 1},
+    {"name": "x01", "value": 1},
+    {"name": "x02", "value": 1},
+    {"name": "y00", "value": 0},
+    {"name": "y01", "value": 1},
+    {"name": "y02", "value": 0}
+  ],
+  "gates": [
+    {"output": "z00", "op": "AND", "inputs": ["x00", "y00"]},
+    {"output": "z01", "op": "XOR", "inputs": ["x01", "y01"]},
+    {"output": "z02", "op": "OR", "inputs": ["x02", "y02"]}
+  ],
+  "outputs": ["z00", "z01", "z02"]
+}
+"#, to_json(&circuit));
+
+        assert_eq!("{\n  \"inputs\": [],\n  \"gates\": [],\n  \"outputs\": []\n}\n", to_json(&[]));
+        Ok(())
+    }
+}
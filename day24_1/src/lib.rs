@@ -0,0 +1,242 @@
+pub mod eval;
+pub mod export;
+pub mod netlist;
+
+use std::path::Path;
+
+use aoc_input::{Input, Lines, ParseError};
+
+use crate::eval::{EvalError, Evaluator};
+
+pub fn solve<P>(path: P) -> Result<u64, Box<dyn std::error::Error>>
+    where P: AsRef<Path>
+{
+    solve_parsed(read_file(path)?)
+}
+
+pub fn solve_parsed((circuit, mut outputs): (Vec<Gate>, Vec<Output>)) -> Result<u64, Box<dyn std::error::Error>> {
+    run(&circuit, &mut outputs)?;
+
+    let output = outputs.into_iter()
+        .fold(0u64, |acc, output| {
+            match output.value {
+                Some(value) => {
+                    let value = (value as u64) << output.order;
+                    acc + value
+                }
+                None => acc
+            }
+        })
+    ;
+
+    Ok(output)
+}
+
+#[derive(Debug)]
+pub enum PatternError {
+    UnexpectedToken(String),
+}
+impl std::fmt::Display for PatternError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            PatternError::UnexpectedToken(token) => write!(f, "Unexpected token: {}", token),
+        }
+    }
+}
+impl std::error::Error for PatternError {}
+
+#[derive(PartialEq, Clone, Debug)]
+pub enum Op {
+    And, Or, XOr, Not, Nand, Nor, XNor
+}
+impl std::str::FromStr for Op {
+    type Err = PatternError;
+
+    fn from_str(token: &str) -> Result<Self, Self::Err> {
+        match token {
+            "AND" => Ok(Op::And),
+            "OR" => Ok(Op::Or),
+            "XOR" => Ok(Op::XOr),
+            "NOT" => Ok(Op::Not),
+            "NAND" => Ok(Op::Nand),
+            "NOR" => Ok(Op::Nor),
+            "XNOR" => Ok(Op::XNor),
+            _ => Err(PatternError::UnexpectedToken(token.into())),
+        }
+    }
+}
+impl std::fmt::Display for Op {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Op::And => write!(f, "AND"),
+            Op::Or => write!(f, "OR"),
+            Op::XOr => write!(f, "XOR"),
+            Op::Not => write!(f, "NOT"),
+            Op::Nand => write!(f, "NAND"),
+            Op::Nor => write!(f, "NOR"),
+            Op::XNor => write!(f, "XNOR"),
+        }
+    }
+}
+impl Op {
+    pub fn try_from(token: &str) -> Result<Self, Box<dyn std::error::Error>> {
+        Ok(token.parse::<Op>()?)
+    }
+
+    // Works on 64 lanes at once; `NOT` only looks at its first input.
+    pub fn apply(&self, inputs: &[u64]) -> u64 {
+        let reduce = |f: fn(u64, u64) -> u64| inputs.iter().copied().reduce(f).unwrap_or(0);
+
+        match self {
+            Op::And => reduce(|lhs, rhs| lhs & rhs),
+            Op::Or => reduce(|lhs, rhs| lhs | rhs),
+            Op::XOr => reduce(|lhs, rhs| lhs ^ rhs),
+            Op::Not => !inputs.first().copied().unwrap_or(0),
+            Op::Nand => !Op::And.apply(inputs),
+            Op::Nor => !Op::Or.apply(inputs),
+            Op::XNor => !Op::XOr.apply(inputs),
+        }
+    }
+
+    pub fn evaluate(&self, inputs: &[u8]) -> u8 {
+        let inputs = inputs.iter().map(|input| *input as u64).collect::<Vec<_>>();
+        (self.apply(&inputs) & 1) as u8
+    }
+}
+
+#[derive(PartialEq, Clone, Debug)]
+pub enum Gate {
+    Logic(String, Op, Vec<String>),
+    Input(String, u8),
+}
+
+#[derive(PartialEq, Clone, Debug)]
+pub struct Output {
+    pub name: String,
+    pub order: u8,
+    pub value: Option<u8>,
+}
+
+pub fn read_file<P>(path: P) -> Result<(Vec<Gate>, Vec<Output>), ParseError> 
+    where P: AsRef<Path>
+{
+    let input = Input::read_file(path)?;
+    let mut lines = input.lines();
+    let mut circuit = vec![];
+    let mut outputs = vec![];
+
+    read_input(&mut lines, &mut circuit)?;
+    read_logic(&mut lines, &mut circuit, &mut outputs)?;
+
+    Ok((circuit, outputs))
+}
+
+pub fn read_input(lines: &mut Lines<'_>, circuit: &mut Vec<Gate>) -> Result<(), ParseError> {
+    for line in lines.block() {
+        let text = netlist::strip_comment(line.text);
+        if text.is_empty() {
+            continue;
+        }
+
+        circuit.extend(netlist::parse_input(&line, text)?);
+    }
+
+    Ok(())
+}
+
+pub fn read_logic(lines: &mut Lines<'_>, circuit: &mut Vec<Gate>, outputs: &mut Vec<Output>) -> Result<(), ParseError> {
+    for line in lines {
+        let text = netlist::strip_comment(line.text);
+        if text.is_empty() {
+            continue;
+        }
+
+        for gate in netlist::parse_gate(&line, text)? {
+            if let Gate::Logic(out, _, _) = &gate {
+                if let Some(order) = out.strip_prefix('z').and_then(|order| order.parse::<u8>().ok()) {
+                    outputs.push(Output{ name: out.clone(), order, value: None })
+                }
+            }
+            circuit.push(gate);
+        }
+    }
+    Ok(())
+}
+
+pub fn run(circuit: &[Gate], outputs: &mut [Output]) -> Result<(), EvalError> {
+    let evaluator = Evaluator::new(circuit)?;
+    let values = evaluator.eval();
+
+    for output in outputs {
+        output.value = evaluator.wire(&output.name).map(|wire| (values[wire.0 as usize] & 1) as u8);
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::*;
+
+    #[test]
+    fn solve_example() -> Result<(), Box<dyn std::error::Error>> {
+        assert_eq!(4, solve("./aoc_input_example_1.txt")?);
+        assert_eq!(2024, solve("./aoc_input_example_2.txt")?);
+        Ok(())
+    }
+
+    #[test]
+    fn read_file_example() -> Result<(), Box<dyn std::error::Error>> {
+        let (circuit, outputs) = read_file("./aoc_input_example_1.txt")?;
+
+        let expect_circuit = vec![
+            Gate::Input("x00".into(), 1), Gate::Input("x01".into(), 1), Gate::Input("x02".into(), 1), 
+            Gate::Input("y00".into(), 0), Gate::Input("y01".into(), 1), Gate::Input("y02".into(), 0), 
+            Gate::Logic("z00".into(), Op::And, vec!["x00".into(), "y00".into()]),
+            Gate::Logic("z01".into(), Op::XOr, vec!["x01".into(), "y01".into()]),
+            Gate::Logic("z02".into(), Op::Or, vec!["x02".into(), "y02".into()]),
+        ];
+        assert_eq!(expect_circuit, circuit);
+
+        let expect_outputs = vec![
+            Output{name:"z00".into(), order: 0, value: None },
+            Output{name:"z01".into(), order: 1, value: None },
+            Output{name:"z02".into(), order: 2, value: None },
+        ];
+        assert_eq!(expect_outputs, outputs);
+
+        Ok(())
+    }
+
+    #[test]
+    fn read_logic_error() {
+        let input = Input::new("example.txt", "x00 AND y00 -> z00\nx01 XAND y01 -> z01\n");
+        let err = read_logic(&mut input.lines(), &mut vec![], &mut vec![]).unwrap_err();
+
+        assert_eq!((2, 5, 4), (err.line, err.col, err.len));
+        assert_eq!("invalid value \"XAND\": Unexpected token: XAND", err.kind.to_string());
+
+        let input = Input::new("example.txt", "x00 AND y00 z00\n");
+        let err = read_logic(&mut input.lines(), &mut vec![], &mut vec![]).unwrap_err();
+
+        assert_eq!((1, 16), (err.line, err.col));
+        assert_eq!("expected \"->\"", err.kind.to_string());
+    }
+
+    #[test]
+    fn run_example() -> Result<(), Box<dyn std::error::Error>> {
+        let (circuit, mut outputs) = read_file("./aoc_input_example_1.txt")?;
+
+        let expect_outputs = vec![
+            Output{name:"z00".into(), order: 0, value: Some(0) },
+            Output{name:"z01".into(), order: 1, value: Some(0) },
+            Output{name:"z02".into(), order: 2, value: Some(1) },        
+        ];
+
+        run(&circuit, &mut outputs)?;
+
+        assert_eq!(expect_outputs, outputs);
+
+        Ok(())
+    }
+}
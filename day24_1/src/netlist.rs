@@ -0,0 +1,261 @@
+use aoc_input::{Line, ParseError, ParseErrorKind};
+
+use crate::{Gate, Op};
+
+// Netlist format, a superset of the puzzle input. Inputs come first, as one
+// block ended by a blank line; gates follow. `#` starts a comment.
+//
+//   x00: 1                          single input wire
+//   x[0..3]: 11                     bus input: x00..x03 hold the bits of 11
+//   y[0..3]: 0b0110                 ... also written in binary
+//
+//   x00 AND y00 -> z00              binary gate, as in the puzzle input
+//   NOT x01 -> n01                  unary gate
+//   NAND(a, b, c) -> d              any number of inputs: AND OR XOR NAND NOR XNOR
+//   OR(x[0..3]) -> any              a bus operand stands for all of its wires
+//   x[0..3] XOR y[0..3] -> s[0..3]  bus output: one gate per bit, single wires shared
+//
+// `x[3]` names the wire `x03`, so buses line up with the puzzle's numbering.
+
+#[derive(PartialEq, Debug)]
+pub enum Wires {
+    Wire(String),
+    Bus(Vec<String>),
+}
+
+impl Wires {
+    pub fn names(&self) -> Vec<String> {
+        match self {
+            Wires::Wire(name) => vec![name.clone()],
+            Wires::Bus(names) => names.clone(),
+        }
+    }
+}
+
+pub fn bus_wire(name: &str, index: usize) -> String {
+    format!("{}{:02}", name, index)
+}
+
+pub fn strip_comment(text: &str) -> &str {
+    text.split('#').next().unwrap_or("").trim()
+}
+
+fn is_name(s: &str) -> bool {
+    !s.is_empty() && s.chars().all(|ch| ch.is_ascii_alphanumeric() || ch == '_')
+}
+
+pub fn parse_wires<'a>(line: &Line<'a>, s: &'a str) -> Result<Wires, ParseError> {
+    let invalid = |msg: &str| line.error(s, ParseErrorKind::Invalid(format!("{} {:?}", msg, s)));
+
+    let Some((name, range)) = s.split_once('[') else {
+        return match is_name(s) {
+            true => Ok(Wires::Wire(s.to_string())),
+            false => Err(invalid("invalid wire name")),
+        };
+    };
+    let Some(range) = range.strip_suffix(']').filter(|_| is_name(name)) else {
+        return Err(invalid("invalid bus"));
+    };
+
+    let (lo, hi) = match range.split_once("..") {
+        Some((lo, hi)) => (line.parse::<usize>(lo.trim())?, line.parse::<usize>(hi.trim())?),
+        None => {
+            let index = line.parse::<usize>(range.trim())?;
+            return Ok(Wires::Wire(bus_wire(name, index)));
+        }
+    };
+    if lo > hi {
+        return Err(invalid("empty bus"));
+    }
+
+    Ok(Wires::Bus((lo..=hi).map(|index| bus_wire(name, index)).collect()))
+}
+
+fn parse_value(line: &Line<'_>, s: &str) -> Result<u64, ParseError> {
+    let parsed = match s.strip_prefix("0b") {
+        Some(bits) => u64::from_str_radix(bits, 2),
+        None => s.parse::<u64>(),
+    };
+
+    parsed.map_err(|err| line.error(s, ParseErrorKind::InvalidValue { value: s.to_string(), reason: err.to_string() }))
+}
+
+pub fn parse_input<'a>(line: &Line<'a>, s: &'a str) -> Result<Vec<Gate>, ParseError> {
+    let (name, value) = line.split_once(s, ":")?;
+    let value = value.trim();
+
+    match parse_wires(line, name.trim())? {
+        Wires::Wire(name) => match line.parse::<u8>(value)? {
+            bit @ (0 | 1) => Ok(vec![Gate::Input(name, bit)]),
+            bit => Err(line.error(value, ParseErrorKind::Invalid(format!("wire value {} is not 0 or 1", bit)))),
+        },
+        Wires::Bus(names) => {
+            if names.len() > 64 {
+                return Err(line.error(name.trim(), ParseErrorKind::Invalid(format!("input bus of {} wires is wider than 64", names.len()))));
+            }
+            let bits = parse_value(line, value)?;
+            if names.len() < 64 && bits >> names.len() != 0 {
+                return Err(line.error(value, ParseErrorKind::Invalid(format!("{} does not fit in {} wires", bits, names.len()))));
+            }
+
+            Ok(names.into_iter().enumerate()
+                .map(|(i, name)| Gate::Input(name, ((bits >> i) & 1) as u8))
+                .collect())
+        }
+    }
+}
+
+pub fn parse_gate<'a>(line: &Line<'a>, s: &'a str) -> Result<Vec<Gate>, ParseError> {
+    let (expr, out) = line.split_once(s, "->")?;
+    let expr = expr.trim();
+
+    let (op, operands) = match expr.split_once('(') {
+        Some((op, args)) => {
+            let Some(args) = args.trim_end().strip_suffix(')') else {
+                return Err(line.expected_after(expr, ")"));
+            };
+            (op.trim(), args.split(',').map(|arg| arg.trim()).collect::<Vec<_>>())
+        }
+        None => match *expr.split_ascii_whitespace().collect::<Vec<_>>().as_slice() {
+            [op, operand] => (op, vec![operand]),
+            [lhs, op, rhs] => (op, vec![lhs, rhs]),
+            _ => return Err(line.error(expr, ParseErrorKind::Expected("\"<wire> <op> <wire>\"".to_string()))),
+        },
+    };
+
+    let op = line.parse::<Op>(op)?;
+    let operands = operands.into_iter()
+        .map(|operand| parse_wires(line, operand))
+        .collect::<Result<Vec<_>, _>>()?;
+
+    let gates = match parse_wires(line, out.trim())? {
+        Wires::Wire(out) => {
+            let inputs = operands.iter().flat_map(|operand| operand.names()).collect();
+            vec![Gate::Logic(out, op.clone(), inputs)]
+        }
+        Wires::Bus(outs) => {
+            if let Some(Wires::Bus(names)) = operands.iter().find(|operand| matches!(operand, Wires::Bus(names) if names.len() != outs.len())) {
+                return Err(line.error(out.trim(), ParseErrorKind::Invalid(format!("bus width {} does not match {}", outs.len(), names.len()))));
+            }
+
+            outs.into_iter().enumerate()
+                .map(|(i, out)| {
+                    let inputs = operands.iter()
+                        .map(|operand| match operand {
+                            Wires::Wire(name) => name.clone(),
+                            Wires::Bus(names) => names[i].clone(),
+                        })
+                        .collect();
+                    Gate::Logic(out, op.clone(), inputs)
+                })
+                .collect()
+        }
+    };
+
+    for gate in &gates {
+        if let Gate::Logic(_, op, inputs) = gate {
+            match (op, inputs.len()) {
+                (Op::Not, 1) => {}
+                (Op::Not, _) => return Err(line.error(expr, ParseErrorKind::Invalid("NOT takes exactly one input".to_string()))),
+                (_, 0..=1) => return Err(line.error(expr, ParseErrorKind::Invalid(format!("{} needs at least two inputs", op)))),
+                _ => {}
+            }
+        }
+    }
+
+    Ok(gates)
+}
+
+#[cfg(test)]
+mod tests {
+    use aoc_input::Input;
+
+    use crate::netlist::*;
+    use crate::{eval::Evaluator, read_input, read_logic};
+
+    const NETLIST: &str = "\
+# 4-bit inputs
+x[0..3]: 11   # 0b1011
+y[0..3]: 0b0110
+c: 1
+
+x[0..3] XOR y[0..3] -> s[0..3]
+NOT s[0..3] -> n[0..3]
+
+NAND(x00, y01, c) -> a      # 3-input gate
+OR(s[0..3]) -> any
+x[3] XNOR y[3] -> same
+x00 NOR y00 -> none
+";
+
+    fn logic(out: &str, op: Op, inputs: &[&str]) -> Gate {
+        Gate::Logic(out.into(), op, inputs.iter().map(|input| input.to_string()).collect())
+    }
+
+    #[test]
+    fn read_netlist() -> Result<(), Box<dyn std::error::Error>> {
+        let input = Input::new("netlist.txt", NETLIST);
+        let mut lines = input.lines();
+        let (mut circuit, mut outputs) = (vec![], vec![]);
+
+        read_input(&mut lines, &mut circuit)?;
+        read_logic(&mut lines, &mut circuit, &mut outputs)?;
+
+        assert_eq!(&[
+            Gate::Input("x00".into(), 1), Gate::Input("x01".into(), 1), Gate::Input("x02".into(), 0), Gate::Input("x03".into(), 1),
+            Gate::Input("y00".into(), 0), Gate::Input("y01".into(), 1), Gate::Input("y02".into(), 1), Gate::Input("y03".into(), 0),
+            Gate::Input("c".into(), 1),
+        ], &circuit[..9]);
+        assert_eq!(&[
+            logic("s00", Op::XOr, &["x00", "y00"]),
+            logic("s01", Op::XOr, &["x01", "y01"]),
+        ], &circuit[9..11]);
+        assert_eq!(logic("n03", Op::Not, &["s03"]), circuit[16]);
+        assert_eq!(&[
+            logic("a", Op::Nand, &["x00", "y01", "c"]),
+            logic("any", Op::Or, &["s00", "s01", "s02", "s03"]),
+            logic("same", Op::XNor, &["x03", "y03"]),
+            logic("none", Op::Nor, &["x00", "y00"]),
+        ], &circuit[17..]);
+        assert!(outputs.is_empty());
+
+        let evaluator = Evaluator::new(&circuit)?;
+        let values = evaluator.eval();
+        assert_eq!(0b1101, evaluator.number(&values, &evaluator.bus('s'), 0));
+        assert_eq!(0b0010, evaluator.number(&values, &evaluator.bus('n'), 0) & 0b1111);
+
+        let wire = |name: &str| values[evaluator.wire(name).unwrap().0 as usize] & 1;
+        assert_eq!((0, 1, 0, 0), (wire("a"), wire("any"), wire("same"), wire("none")));
+        Ok(())
+    }
+
+    #[test]
+    fn netlist_errors() {
+        let parse = |text: &str| {
+            let input = Input::new("netlist.txt", text);
+            let line = input.lines().next().unwrap();
+            parse_gate(&line, strip_comment(line.text)).map_err(|err| (err.col, err.kind.to_string()))
+        };
+
+        assert_eq!(Err((1, "NOT takes exactly one input".into())), parse("NOT(a, b) -> c"));
+        assert_eq!(Err((1, "AND needs at least two inputs".into())), parse("AND(a) -> c"));
+        assert_eq!(Err((17, "bus width 3 does not match 4".into())), parse("a[0..3] OR b -> c[0..2]"));
+        assert_eq!(Err((1, "invalid bus \"a[0..3\"".into())), parse("a[0..3 OR b -> c"));
+        assert_eq!(Err((9, "expected \")\"".into())), parse("AND(a, b -> c"));
+        assert!(parse("a OR b -> c # OR(d) -> e").is_ok());
+    }
+
+    #[test]
+    fn input_errors() {
+        let parse = |text: &str| {
+            let input = Input::new("netlist.txt", text);
+            let line = input.lines().next().unwrap();
+            parse_input(&line, strip_comment(line.text)).map_err(|err| (err.col, err.kind.to_string()))
+        };
+
+        assert_eq!(Err((6, "wire value 2 is not 0 or 1".into())), parse("x00: 2"));
+        assert_eq!(Err((10, "16 does not fit in 4 wires".into())), parse("x[0..3]: 16"));
+        assert_eq!(Err((1, "input bus of 65 wires is wider than 64".into())), parse("x[0..64]: 1"));
+        assert_eq!(Ok(Gate::Input("x63".into(), 1)), parse("x[0..63]: 18446744073709551615").map(|gates| gates[63].clone()));
+    }
+}
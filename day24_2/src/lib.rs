@@ -0,0 +1,303 @@
+pub mod property;
+
+use std::{collections::HashMap, path::Path};
+
+pub use day24_1::read_file;
+
+use day24_1::{eval::{EvalError, Evaluator}, Gate, Op, Output};
+
+pub fn solve<P>(path: P) -> Result<String, Box<dyn std::error::Error>>
+    where P: AsRef<Path>
+{
+    solve_parsed(read_file(path)?)
+}
+
+pub fn solve_parsed((circuit, _): (Vec<Gate>, Vec<Output>)) -> Result<String, Box<dyn std::error::Error>> {
+    Ok(swapped_wires(&circuit).join(","))
+}
+
+#[derive(PartialEq, Clone, Copy, Debug)]
+pub enum FaultKind {
+    OutputNotXor,
+    LastOutputNotOr,
+    SumNotToOutput,
+    HalfSumNotToXor,
+    AndNotToOr,
+    CarryNotToXor,
+}
+impl std::fmt::Display for FaultKind {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            FaultKind::OutputNotXor => write!(f, "z output is not driven by XOR"),
+            FaultKind::LastOutputNotOr => write!(f, "final carry output is not driven by OR"),
+            FaultKind::SumNotToOutput => write!(f, "sum XOR does not drive a z output"),
+            FaultKind::HalfSumNotToXor => write!(f, "x XOR y does not feed a sum XOR"),
+            FaultKind::AndNotToOr => write!(f, "AND does not feed a carry OR"),
+            FaultKind::CarryNotToXor => write!(f, "carry OR does not feed a sum XOR"),
+        }
+    }
+}
+
+#[derive(PartialEq, Debug)]
+pub enum AdderError {
+    Eval(EvalError),
+    WrongSum(u8),
+}
+impl std::fmt::Display for AdderError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            AdderError::Eval(err) => write!(f, "{}", err),
+            AdderError::WrongSum(bit) => write!(f, "wrong sum at bit {}", bit),
+        }
+    }
+}
+impl std::error::Error for AdderError {}
+
+impl From<EvalError> for AdderError {
+    fn from(err: EvalError) -> Self {
+        AdderError::Eval(err)
+    }
+}
+
+pub type Swap = (String, String);
+
+#[derive(PartialEq, Debug)]
+pub struct Fault {
+    pub wire: String,
+    pub bit: Option<u8>,
+    pub kind: FaultKind,
+}
+
+fn bit_of(wire: &str) -> Option<u8> {
+    wire.strip_prefix(['x', 'y', 'z']).and_then(|bit| bit.parse().ok())
+}
+
+fn is_input(wire: &str) -> bool {
+    wire.starts_with('x') || wire.starts_with('y')
+}
+
+pub fn input_bits(circuit: &[Gate]) -> u8 {
+    circuit.iter()
+        .filter(|gate| matches!(gate, Gate::Input(name, _) if name.starts_with('x')))
+        .count() as u8
+}
+
+// Checks every gate against its role in a ripple-carry adder:
+//   z_i = (x_i XOR y_i) XOR c_{i-1}
+//   c_i = (x_i AND y_i) OR ((x_i XOR y_i) AND c_{i-1})
+// with bit 0 a half adder and the last z driven by the final carry.
+pub fn find_faults(circuit: &[Gate]) -> Vec<Fault> {
+    let last = format!("z{:02}", input_bits(circuit));
+
+    let mut consumers = HashMap::<&str, Vec<&Op>>::new();
+    for gate in circuit {
+        if let Gate::Logic(_, op, operands) = gate {
+            for operand in operands {
+                consumers.entry(operand.as_str()).or_default().push(op);
+            }
+        }
+    }
+    let feeds = |wire: &str, op: Op| consumers.get(wire).is_some_and(|ops| ops.contains(&&op));
+    let feeds_only = |wire: &str, op: Op| consumers.get(wire).is_some_and(|ops| ops.iter().all(|o| **o == op));
+
+    let mut faults = vec![];
+
+    for gate in circuit {
+        let Gate::Logic(out, op, operands) = gate else {
+            continue;
+        };
+        let from_inputs = operands.iter().all(|operand| is_input(operand));
+        let first = from_inputs && operands.iter().all(|operand| bit_of(operand) == Some(0));
+        let bit = bit_of(out).or_else(|| operands.iter().find_map(|operand| bit_of(operand)));
+
+        let kind = match op {
+            _ if *out == last => (*op != Op::Or).then_some(FaultKind::LastOutputNotOr),
+            _ if out.starts_with('z') && *op != Op::XOr => Some(FaultKind::OutputNotXor),
+            Op::XOr if !from_inputs && !out.starts_with('z') => Some(FaultKind::SumNotToOutput),
+            Op::XOr if from_inputs && !first && !feeds(out, Op::XOr) => Some(FaultKind::HalfSumNotToXor),
+            Op::And if !first && !feeds_only(out, Op::Or) => Some(FaultKind::AndNotToOr),
+            Op::Or if !feeds(out, Op::XOr) => Some(FaultKind::CarryNotToXor),
+            _ => None,
+        };
+
+        if let Some(kind) = kind {
+            faults.push(Fault { wire: out.clone(), bit, kind });
+        }
+    }
+
+    faults
+}
+
+pub fn swapped_wires(circuit: &[Gate]) -> Vec<String> {
+    let mut wires = find_faults(circuit).into_iter()
+        .map(|fault| fault.wire)
+        .collect::<Vec<_>>();
+
+    wires.sort();
+    wires.dedup();
+    wires
+}
+
+pub fn apply_swaps(circuit: &[Gate], swaps: &[Swap]) -> Vec<Gate> {
+    let renames = swaps.iter()
+        .flat_map(|(a, b)| [(a, b), (b, a)])
+        .collect::<HashMap<_, _>>();
+
+    circuit.iter()
+        .map(|gate| match gate {
+            Gate::Logic(out, op, operands) => {
+                let out = renames.get(out).map_or(out, |other| *other);
+                Gate::Logic(out.clone(), op.clone(), operands.clone())
+            }
+            gate => gate.clone(),
+        })
+        .collect()
+}
+
+pub fn add(circuit: &[Gate], x: u64, y: u64) -> Result<u64, EvalError> {
+    Ok(Evaluator::new(circuit)?.eval_numbers(&[(x, y)])[0])
+}
+
+// Adds single-bit and carry-propagating operands at every position and
+// returns the lowest operand bit for which the sum comes out wrong. Bits
+// past `property::MAX_WIDTH` go unchecked, as their sums overflow a u64.
+pub fn check_adder(circuit: &[Gate]) -> Result<(), AdderError> {
+    let evaluator = Evaluator::new(circuit)?;
+
+    let vectors = (0..input_bits(circuit).min(property::MAX_WIDTH))
+        .flat_map(|bit| {
+            let one = 1u64 << bit;
+            let (below, mask) = (one - 1, (one << 1) - 1);
+
+            [(one, 0), (0, one), (one, one), (one | below, 1), (below, one | 1)]
+                .map(|(x, y)| (bit, x & mask, y & mask))
+        })
+        .collect::<Vec<_>>();
+
+    let sums = evaluator.eval_numbers(&vectors.iter().map(|(_, x, y)| (*x, *y)).collect::<Vec<_>>());
+
+    match vectors.iter().zip(sums).find(|((_, x, y), sum)| x + y != *sum) {
+        Some(((bit, _, _), _)) => Err(AdderError::WrongSum(*bit)),
+        None => Ok(()),
+    }
+}
+
+// Pairs up the suspect wires so that the swapped circuit passes
+// `check_adder`, trying every perfect matching.
+pub fn pair_swaps(circuit: &[Gate], wires: &[String]) -> Option<Vec<Swap>> {
+    fn pairings(wires: &[String], pairs: &mut Vec<Swap>, f: &mut dyn FnMut(&[Swap]) -> bool) -> bool {
+        let Some((first, rest)) = wires.split_first() else {
+            return f(pairs);
+        };
+
+        for i in 0..rest.len() {
+            let remaining = rest.iter().enumerate()
+                .filter(|(j, _)| *j != i)
+                .map(|(_, wire)| wire.clone())
+                .collect::<Vec<_>>();
+
+            pairs.push((first.clone(), rest[i].clone()));
+            if pairings(&remaining, pairs, f) {
+                return true;
+            }
+            pairs.pop();
+        }
+
+        false
+    }
+
+    if !wires.len().is_multiple_of(2) {
+        return None;
+    }
+
+    let mut pairs = vec![];
+    let mut found = None;
+    pairings(wires, &mut pairs, &mut |pairs| {
+        let ok = check_adder(&apply_swaps(circuit, pairs)).is_ok();
+        if ok {
+            found = Some(pairs.to_vec());
+        }
+        ok
+    });
+
+    found
+}
+
+fn carry_name(bit: u8, bits: u8) -> String {
+    match bit + 1 == bits {
+        true => format!("z{:02}", bits),
+        false => format!("c{:02}", bit),
+    }
+}
+
+// A textbook ripple-carry adder over `bits`-wide x/y buses, for tests and
+// as a reference when reading the puzzle circuit.
+pub fn ripple_carry(bits: u8) -> Vec<Gate> {
+    let mut circuit = vec![];
+    for bit in 0..bits {
+        circuit.push(Gate::Input(format!("x{:02}", bit), 0));
+        circuit.push(Gate::Input(format!("y{:02}", bit), 0));
+    }
+
+    let logic = |out: String, op: Op, lhs: String, rhs: String| Gate::Logic(out, op, vec![lhs, rhs]);
+    circuit.push(logic("z00".into(), Op::XOr, "x00".into(), "y00".into()));
+    circuit.push(logic(carry_name(0, bits), Op::And, "x00".into(), "y00".into()));
+
+    for bit in 1..bits {
+        let (x, y) = (format!("x{:02}", bit), format!("y{:02}", bit));
+        let carry_in = format!("c{:02}", bit - 1);
+        let carry_out = carry_name(bit, bits);
+
+        circuit.push(logic(format!("s{:02}", bit), Op::XOr, x.clone(), y.clone()));
+        circuit.push(logic(format!("z{:02}", bit), Op::XOr, format!("s{:02}", bit), carry_in.clone()));
+        circuit.push(logic(format!("a{:02}", bit), Op::And, x, y));
+        circuit.push(logic(format!("b{:02}", bit), Op::And, format!("s{:02}", bit), carry_in));
+        circuit.push(logic(carry_out, Op::Or, format!("a{:02}", bit), format!("b{:02}", bit)));
+    }
+
+    circuit
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::*;
+
+    fn swaps(pairs: &[(&str, &str)]) -> Vec<Swap> {
+        pairs.iter().map(|(a, b)| (a.to_string(), b.to_string())).collect()
+    }
+
+    #[test]
+    fn solve_example() -> Result<(), Box<dyn std::error::Error>> {
+        assert_eq!("a05,a10,b03,c07,s05,z03,z07,z10", solve("./aoc_input_example.txt")?);
+        Ok(())
+    }
+
+    #[test]
+    fn correct_adder() {
+        let circuit = ripple_carry(8);
+
+        assert_eq!(Vec::<Fault>::new(), find_faults(&circuit));
+        assert_eq!(Ok(()), check_adder(&circuit));
+        assert_eq!(Ok(200 + 99), add(&circuit, 200, 99));
+        assert_eq!(Ok(()), check_adder(&ripple_carry(64)));
+    }
+
+    #[test]
+    fn find_swapped_outputs() {
+        let swapped = swaps(&[("z03", "b03"), ("s05", "a05"), ("c06", "z06")]);
+        let circuit = apply_swaps(&ripple_carry(8), &swapped);
+
+        assert_eq!(Err(AdderError::WrongSum(2)), check_adder(&circuit));
+        let err = check_adder(&apply_swaps(&circuit, &swaps(&[("c02", "z03")]))).unwrap_err();
+        assert!(matches!(err, AdderError::Eval(EvalError::Cycle(_))), "{}", err);
+        assert_eq!(vec!["a05", "b03", "c06", "s05", "z03", "z06"], swapped_wires(&circuit));
+
+        let faults = find_faults(&circuit);
+        assert!(faults.contains(&Fault { wire: "z03".into(), bit: Some(3), kind: FaultKind::OutputNotXor }), "{:?}", faults);
+        assert!(faults.contains(&Fault { wire: "s05".into(), bit: Some(5), kind: FaultKind::AndNotToOr }), "{:?}", faults);
+
+        let pairs = pair_swaps(&circuit, &swapped_wires(&circuit)).unwrap();
+        assert_eq!(swaps(&[("a05", "s05"), ("b03", "z03"), ("c06", "z06")]), pairs);
+        assert_eq!(Ok(()), check_adder(&apply_swaps(&circuit, &pairs)));
+    }
+}
@@ -0,0 +1,30 @@
+use day24_2::{find_faults, property::AdderFn, pair_swaps, read_file, solve, swapped_wires};
+
+fn main() -> Result<(), Box<dyn std::error::Error>> {
+    match std::env::args().nth(1).as_deref() {
+        Some("--faults") => {
+            let (circuit, _) = read_file("./aoc_input.txt")?;
+
+            for fault in find_faults(&circuit) {
+                let bit = fault.bit.map_or("?".to_string(), |bit| bit.to_string());
+                println!("{:<4} bit {:<3} {}", fault.wire, bit, fault.kind);
+            }
+            match pair_swaps(&circuit, &swapped_wires(&circuit)) {
+                Some(pairs) => println!("swaps: {:?}", pairs),
+                None => println!("swaps: no pairing repairs the adder"),
+            }
+        }
+        Some("--check") => {
+            let (circuit, outputs) = read_file("./aoc_input.txt")?;
+            let adder = AdderFn::new(&circuit, &outputs)?;
+
+            match adder.check_exhaustive(4).and_then(|_| adder.check_random(10_000, 24)) {
+                Ok(()) => println!("adds correctly on all {}-bit inputs tried", adder.width),
+                Err(err) => println!("{}", err),
+            }
+        }
+        _ => println!("total: {:?}", solve("./aoc_input.txt")?),
+    }
+
+    Ok(())
+}
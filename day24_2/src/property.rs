@@ -0,0 +1,208 @@
+use day24_1::{eval::{EvalError, Evaluator, LANES}, Gate, Output};
+
+use crate::input_bits;
+
+#[derive(PartialEq, Debug)]
+pub struct Counterexample {
+    pub x: u64,
+    pub y: u64,
+    pub expected: u64,
+    pub actual: u64,
+}
+
+impl Counterexample {
+    pub fn first_wrong_bit(&self) -> u32 {
+        (self.expected ^ self.actual).trailing_zeros()
+    }
+}
+
+#[derive(PartialEq, Debug)]
+pub struct PropertyError(pub Counterexample);
+
+impl std::fmt::Display for PropertyError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let cex = &self.0;
+        write!(f, "{} + {} gave {} instead of {} (first wrong bit {})",
+            cex.x, cex.y, cex.actual, cex.expected, cex.first_wrong_bit())
+    }
+}
+impl std::error::Error for PropertyError {}
+
+// Operands are capped so that their sum still fits in a u64.
+pub const MAX_WIDTH: u8 = 63;
+
+// The circuit seen as a function from the x/y buses to the z bus.
+pub struct AdderFn<'a> {
+    evaluator: Evaluator<'a>,
+    // wire index and bit of every output the circuit drives
+    outputs: Vec<(usize, u32)>,
+    pub width: u8,
+}
+
+impl<'a> AdderFn<'a> {
+    pub fn new(circuit: &'a [Gate], outputs: &[Output]) -> Result<Self, EvalError> {
+        let evaluator = Evaluator::new(circuit)?;
+        let outputs = outputs.iter()
+            .filter_map(|output| Some((evaluator.wire(&output.name)?.0 as usize, output.order as u32)))
+            .collect();
+
+        Ok(Self { evaluator, outputs, width: input_bits(circuit).min(MAX_WIDTH) })
+    }
+
+    pub fn call(&self, x: u64, y: u64) -> u64 {
+        self.call_lanes(&[(x, y)])[0]
+    }
+
+    // Up to `LANES` pairs in one pass through the circuit.
+    fn call_lanes(&self, pairs: &[(u64, u64)]) -> Vec<u64> {
+        let values = self.evaluator.eval_pairs(pairs);
+
+        (0..pairs.len())
+            .map(|lane| self.outputs.iter()
+                .filter_map(|(wire, order)| ((values[*wire] >> lane) & 1).checked_shl(*order))
+                .fold(0, |acc, bit| acc | bit))
+            .collect()
+    }
+
+    // The first pair in `pairs` whose sum comes out wrong.
+    fn check(&self, pairs: &[(u64, u64)]) -> Option<Counterexample> {
+        pairs.iter().zip(self.call_lanes(pairs))
+            .map(|(&(x, y), actual)| Counterexample { x, y, expected: x + y, actual })
+            .find(|cex| cex.actual != cex.expected)
+    }
+
+    // Clears set bits of x and y, highest first, for as long as the sum
+    // stays wrong, so the reported case is as small as the failure allows.
+    pub fn shrink(&self, mut cex: Counterexample) -> Counterexample {
+        loop {
+            let mut shrunk = false;
+
+            for bit in (0..64).rev() {
+                let mask = !(1u64 << bit);
+                for (x, y) in [(mask, u64::MAX), (u64::MAX, mask)] {
+                    let (x, y) = (cex.x & x, cex.y & y);
+                    if (x, y) == (cex.x, cex.y) {
+                        continue;
+                    }
+                    if let Some(smaller) = self.check(&[(x, y)]) {
+                        cex = smaller;
+                        shrunk = true;
+                    }
+                }
+            }
+
+            if !shrunk {
+                return cex;
+            }
+        }
+    }
+
+    // Checks `pairs` a full batch of lanes at a time, stopping at the first failure.
+    fn check_all<I>(&self, pairs: I) -> Result<(), PropertyError>
+        where I: Iterator<Item = (u64, u64)>
+    {
+        let mut pairs = pairs.peekable();
+
+        while pairs.peek().is_some() {
+            let batch = pairs.by_ref().take(LANES).collect::<Vec<_>>();
+            if let Some(cex) = self.check(&batch) {
+                return Err(PropertyError(self.shrink(cex)));
+            }
+        }
+
+        Ok(())
+    }
+
+    // Every pair of `width`-bit operands; the upper input bits stay zero.
+    pub fn check_exhaustive(&self, width: u8) -> Result<(), PropertyError> {
+        let end = 1u64 << width.min(self.width).min(MAX_WIDTH);
+
+        self.check_all((0..end).flat_map(|x| (0..end).map(move |y| (x, y))))
+    }
+
+    pub fn check_random(&self, cases: usize, seed: u64) -> Result<(), PropertyError> {
+        let mask = (1u64 << self.width.min(MAX_WIDTH)) - 1;
+        let mut state = seed.max(1);
+        let mut next = move || {
+            state ^= state << 13;
+            state ^= state >> 7;
+            state ^= state << 17;
+            state & mask
+        };
+
+        self.check_all((0..cases).map(|_| (next(), next())))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::property::*;
+    use crate::{apply_swaps, ripple_carry};
+
+    fn outputs(circuit: &[Gate]) -> Vec<Output> {
+        circuit.iter()
+            .filter_map(|gate| match gate {
+                Gate::Logic(name, _, _) => {
+                    let order = name.strip_prefix('z')?.parse().ok()?;
+                    Some(Output { name: name.clone(), order, value: None })
+                }
+                Gate::Input(_, _) => None,
+            })
+            .collect()
+    }
+
+    #[test]
+    fn correct_adder_passes() -> Result<(), Box<dyn std::error::Error>> {
+        let circuit = ripple_carry(6);
+        let adder = AdderFn::new(&circuit, &outputs(&circuit))?;
+
+        assert_eq!(6, adder.width);
+        assert_eq!(45 + 27, adder.call(45, 27));
+        adder.check_exhaustive(6)?;
+        adder.check_random(500, 24)?;
+        Ok(())
+    }
+
+    #[test]
+    fn swapped_adder_counterexample() -> Result<(), Box<dyn std::error::Error>> {
+        let circuit = apply_swaps(&ripple_carry(6), &[("z03".into(), "b03".into())]);
+        let adder = AdderFn::new(&circuit, &outputs(&circuit))?;
+
+        let expected = PropertyError(Counterexample { x: 0, y: 8, expected: 8, actual: 16 });
+        assert_eq!(Err(expected), adder.check_exhaustive(4));
+
+        let Err(PropertyError(cex)) = adder.check_random(500, 24) else {
+            panic!("random inputs should hit the swapped carry");
+        };
+        assert_eq!(3, cex.first_wrong_bit());
+        assert!(cex.x.count_ones() + cex.y.count_ones() <= 2, "{:?}", cex);
+        Ok(())
+    }
+
+    #[test]
+    fn wide_adder() -> Result<(), Box<dyn std::error::Error>> {
+        let circuit = ripple_carry(64);
+        let adder = AdderFn::new(&circuit, &outputs(&circuit))?;
+
+        assert_eq!(MAX_WIDTH, adder.width);
+        assert_eq!(u64::MAX - 1, adder.call(u64::MAX >> 1, u64::MAX >> 1));
+        adder.check_exhaustive(3)?;
+        adder.check_random(100, 24)?;
+        Ok(())
+    }
+
+    #[test]
+    fn example_adder() -> Result<(), Box<dyn std::error::Error>> {
+        let (circuit, outputs) = day24_1::read_file("./aoc_input_example.txt")?;
+
+        let expected = PropertyError(Counterexample { x: 1 << 3, y: 0, expected: 1 << 3, actual: 1 << 4 });
+        assert_eq!(Err(expected), AdderFn::new(&circuit, &outputs)?.check_random(200, 24));
+
+        let pairs = crate::pair_swaps(&circuit, &crate::swapped_wires(&circuit)).unwrap();
+        let fixed = apply_swaps(&circuit, &pairs);
+        let adder = AdderFn::new(&fixed, &outputs)?;
+        adder.check_exhaustive(3)?;
+        adder.check_random(200, 24)?;
+        Ok(())
+    }
+}
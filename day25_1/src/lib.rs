@@ -0,0 +1,138 @@
+use std::path::Path;
+
+use aoc_input::{Input, Line, ParseError, ParseErrorKind};
+
+pub fn solve<P>(path: P) -> Result<usize, Box<dyn std::error::Error>>
+    where P: AsRef<Path>
+{
+    solve_parsed(read_file(path)?)
+}
+
+pub fn solve_parsed((locks, keys): (Vec<Vec<u8>>, Vec<Vec<u8>>)) -> Result<usize, Box<dyn std::error::Error>> {
+    let total = match_key(&locks, &keys).into_iter()
+        .count()
+    ;
+
+    Ok(total)
+}
+
+pub fn read_file<P>(path: P) -> Result<(Vec<Vec<u8>>, Vec<Vec<u8>>), ParseError> 
+    where P: AsRef<Path>
+{
+    let width: usize = 5;
+    let height: usize = 7;
+
+    let input = Input::read_file(path)?;
+    let mut lines = input.lines();
+
+    let mut locks = vec![];
+    let mut keys = vec![];
+
+    while let Some(line) = lines.next() {
+        if line.is_empty() {
+            continue;
+        }
+
+        let rows = std::iter::once(line).chain(lines.block()).collect::<Vec<_>>();
+        if rows.len() != height {
+            let kind = ParseErrorKind::Invalid(format!("expected {} rows, got {}", height, rows.len()));
+            return Err(line.error(line.text, kind));
+        }
+
+        match line.text.starts_with("#####") {
+            true => locks.push(parse_lines(&rows, width)?),
+            false => keys.push(parse_lines(&rows, width)?),
+        }
+    }
+
+    Ok((locks, keys))
+}
+
+pub fn parse_lines(rows: &[Line], width: usize) -> Result<Vec<u8>, ParseError> {
+    let mut key = vec![0u8; width];
+
+    for (y, row) in rows.iter().enumerate() {
+        let len = row.text.chars().count();
+        if len != width {
+            let kind = ParseErrorKind::Invalid(format!("expected row width {}, got {}", width, len));
+            return Err(row.error_at(width.min(len), width.abs_diff(len), kind));
+        }
+
+        for (i, ch) in row.text.chars().enumerate() {
+            match ch {
+                '#' if (1..rows.len() - 1).contains(&y) => key[i] += 1,
+                '#' | '.' => {},
+                _ => return Err(row.error_at(i, 1, ParseErrorKind::UnexpectedChar(ch))),
+            }
+        }
+    }
+
+    Ok(key)
+}
+
+pub fn match_key(locks: &[Vec<u8>], keys: &[Vec<u8>]) -> Vec<(usize, usize)> {
+    let mut matched = vec![];
+
+    for i in 0..locks.len() {
+        for j in 0..keys.len() {
+            matched.push(match_key_internal(&locks[i], &keys[j], i, j));
+        }
+    }
+
+    matched.into_iter().filter_map(std::convert::identity).collect()
+}
+
+pub fn match_key_internal(lock: &[u8], key: &[u8], lock_index: usize, key_index: usize) -> Option<(usize, usize)> {
+   let matched =  lock.iter().zip(key)
+        .map(|(lhs, rhs)| lhs + rhs)
+        .all(|m| m < 6)
+    ;
+
+    matched.then(|| (lock_index, key_index))
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::*;
+
+    #[test]
+    #[ignore = "reason"]
+    fn solve_example() -> Result<(), Box<dyn std::error::Error>> {
+        assert_eq!(4, solve("./aoc_input_example_1.txt")?);
+        assert_eq!(2024, solve("./aoc_input_example_2.txt")?);
+        Ok(())
+    }
+
+    #[test]
+    fn read_file_example() -> Result<(), Box<dyn std::error::Error>> {
+        let (locks, keys) = read_file("./aoc_input_example.txt")?;
+        let expect_locks = vec![
+            vec![0, 5, 3, 4, 3],
+            vec![1, 2, 0, 5, 3],
+        ];
+        let expect_keys = vec![
+            vec![5, 0, 2, 1, 3],
+            vec![4, 3, 4, 0, 2],
+            vec![3, 0, 2, 0, 1],
+        ];
+        assert_eq!(expect_locks, locks);
+        assert_eq!(expect_keys, keys);
+        Ok(())
+    }
+
+    #[test]
+    fn match_key_example() -> Result<(), Box<dyn std::error::Error>> {
+        let locks = vec![
+            vec![0,5,3,4,3],
+            vec![1,2,0,5,3],
+        ];
+        let keys = vec![
+            vec![5,0,2,1,3],
+            vec![4,3,4,0,2],
+            vec![3,0,2,0,1],
+        ];
+
+        assert_eq!(vec![(0, 2), (1, 1), (1, 2)], match_key(&locks, &keys));
+        Ok(())
+    }
+}
\ No newline at end of file